@@ -0,0 +1,53 @@
+//! Measures how long browsing a recorded catalog takes, to compare the serde_json and simd-json
+//! parsing backends. Record the responses once with `CrunchyrollBuilder::record_responses` while
+//! browsing (with the `en-US` locale), then run this example with and without the `simd-json`
+//! feature:
+//!
+//! ```text
+//! cargo run --release --example decode-benchmark --features replay -- <recording directory>
+//! cargo run --release --example decode-benchmark --features replay,simd-json -- <recording directory>
+//! ```
+
+use anyhow::Result;
+use crunchyroll_rs::common::StreamExt;
+use crunchyroll_rs::search::BrowseOptions;
+use crunchyroll_rs::{Crunchyroll, Locale};
+use std::env;
+use std::time::Instant;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let directory = env::args()
+        .nth(1)
+        .expect("the recording directory must be given as argument");
+    let runs = 10;
+
+    let crunchyroll = Crunchyroll::builder()
+        .locale(Locale::en_US)
+        .login_with_recording(directory)
+        .await?;
+
+    let start = Instant::now();
+    let mut items = 0;
+    for _ in 0..runs {
+        let mut browse_result = crunchyroll.browse(BrowseOptions::default());
+        while let Some(item) = browse_result.next().await {
+            // the end of the recording is reached
+            if item.is_err() {
+                break;
+            }
+            items += 1
+        }
+    }
+    println!(
+        "Parsed {items} items in {:?} ({} backend)",
+        start.elapsed(),
+        if cfg!(feature = "simd-json") {
+            "simd-json"
+        } else {
+            "serde_json"
+        }
+    );
+
+    Ok(())
+}
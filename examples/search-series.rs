@@ -1,5 +1,5 @@
 use anyhow::Result;
-use crunchyroll_rs::common::StreamExt;
+use crunchyroll_rs::common::{StreamExt, TryStreamExt};
 use crunchyroll_rs::Crunchyroll;
 use std::env;
 
@@ -20,7 +20,7 @@ async fn main() -> Result<()> {
             "Queried series {} which has {} seasons",
             series.title, series.season_count
         );
-        let seasons = series.seasons().await?;
+        let seasons = series.seasons().try_collect::<Vec<_>>().await?;
         for season in seasons {
             println!(
                 "Found season {} with audio locale(s) {}",
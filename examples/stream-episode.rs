@@ -13,7 +13,7 @@ async fn main() -> Result<()> {
         .await?;
 
     let episode: Episode = crunchyroll.media_from_id("GRDKJZ81Y").await?;
-    let stream = episode.stream_maybe_without_drm().await?;
+    let stream = episode.stream().await?;
     let (mut video_streams, _audio_streams) = stream.stream_data(None).await?.unwrap();
     // sort after resolutions; best to worst
     video_streams.sort_by(|a, b| {
@@ -57,9 +57,9 @@ pub struct Account {
     pub email_verified: bool,
     pub has_password: bool,
 
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     crleg_email_verified: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     extended_maturity_rating: crate::StrictValue,
 }
 
@@ -0,0 +1,115 @@
+//! Synchronous wrapper around the async api.
+
+use crate::common::{Pagination, Request};
+use crate::crunchyroll::CrunchyrollBuilder;
+use crate::error::Error;
+use crate::media::Media;
+use crate::{MediaCollection, Result};
+use futures_util::TryStreamExt;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Synchronous version of [`crate::Crunchyroll`]. It contains an internal runtime which drives all
+/// requests. Only the most common functions are wrapped directly, use [`Crunchyroll::run`] to call
+/// every other (async) function of the library.
+///
+/// ```no_run
+/// use crunchyroll_rs::blocking::Crunchyroll;
+/// use crunchyroll_rs::common::TryStreamExt;
+/// use crunchyroll_rs::Series;
+///
+/// # fn main() -> Result<(), crunchyroll_rs::error::Error> {
+/// let crunchyroll = Crunchyroll::login_anonymously()?;
+/// let series: Series = crunchyroll.media_from_id("GY8VEQ95Y")?;
+/// let seasons = crunchyroll.block_on(series.seasons().try_collect::<Vec<_>>())?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+#[derive(Clone)]
+pub struct Crunchyroll {
+    crunchyroll: crate::Crunchyroll,
+    runtime: Arc<Runtime>,
+}
+
+impl Crunchyroll {
+    /// Logs in with the given login function. The function receives a fresh
+    /// [`CrunchyrollBuilder`] which can be configured before calling one of its login functions.
+    pub fn login<F, Fut>(login: F) -> Result<Self>
+    where
+        F: FnOnce(CrunchyrollBuilder) -> Fut,
+        Fut: Future<Output = Result<crate::Crunchyroll>>,
+    {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| Error::Internal {
+                message: format!("failed to create runtime: {e}"),
+            })?;
+        let crunchyroll = runtime.block_on(login(crate::Crunchyroll::builder()))?;
+        Ok(Self {
+            crunchyroll,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Logs in anonymously. See [`CrunchyrollBuilder::login_anonymously`].
+    pub fn login_anonymously() -> Result<Self> {
+        Self::login(|builder| builder.login_anonymously())
+    }
+
+    /// Logs in with credentials. See [`CrunchyrollBuilder::login_with_credentials`].
+    pub fn login_with_credentials<S: AsRef<str>>(email: S, password: S) -> Result<Self> {
+        Self::login(|builder| builder.login_with_credentials(email, password))
+    }
+
+    /// Logs in with a refresh token. See [`CrunchyrollBuilder::login_with_refresh_token`].
+    pub fn login_with_refresh_token<S: AsRef<str>>(refresh_token: S) -> Result<Self> {
+        Self::login(|builder| builder.login_with_refresh_token(refresh_token))
+    }
+
+    /// Returns the underlying async client.
+    pub fn inner(&self) -> &crate::Crunchyroll {
+        &self.crunchyroll
+    }
+
+    /// Runs the given future to completion on the internal runtime. Use this to call functions of
+    /// structs which were returned by this client, e.g. [`crate::Series::seasons`].
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Runs an async function of the underlying [`crate::Crunchyroll`] client to completion.
+    pub fn run<'a, F, Fut>(&'a self, f: F) -> Fut::Output
+    where
+        F: FnOnce(&'a crate::Crunchyroll) -> Fut,
+        Fut: Future,
+    {
+        self.runtime.block_on(f(&self.crunchyroll))
+    }
+
+    /// Collects all items of a pagination.
+    pub fn collect<T: Default + DeserializeOwned + Request>(
+        &self,
+        pagination: Pagination<T>,
+    ) -> Result<Vec<T>> {
+        self.runtime.block_on(pagination.try_collect())
+    }
+
+    /// Check if the current used account has premium.
+    pub fn premium(&self) -> bool {
+        self.run(|crunchyroll| crunchyroll.premium())
+    }
+
+    /// See [`crate::Crunchyroll::media_from_id`].
+    pub fn media_from_id<M: Media>(&self, id: impl AsRef<str> + Send) -> Result<M> {
+        self.run(|crunchyroll| crunchyroll.media_from_id(id))
+    }
+
+    /// See [`crate::Crunchyroll::media_collection_from_id`].
+    pub fn media_collection_from_id<S: AsRef<str>>(&self, id: S) -> Result<MediaCollection> {
+        self.run(|crunchyroll| crunchyroll.media_collection_from_id(id))
+    }
+}
@@ -0,0 +1,364 @@
+//! Caching of api responses.
+//!
+//! The cache is opt-in and can be enabled with [`crate::crunchyroll::CrunchyrollBuilder::cache`].
+//! Only successful `GET` requests are cached. Cached responses are revalidated with their `ETag` /
+//! `Last-Modified` validators and the `Cache-Control` header of a response decides how long it can
+//! be used without asking Crunchyroll again.
+
+use crate::internal::fs;
+use crate::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use reqwest::header::{
+    HeaderMap, CACHE_CONTROL, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// A cached api response.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CachedResponse {
+    /// The raw response body.
+    pub body: Vec<u8>,
+    /// The `ETag` header of the response.
+    pub etag: Option<String>,
+    /// The `Last-Modified` header of the response.
+    pub last_modified: Option<String>,
+    /// Until when the response can be used without revalidating it. If [`None`], the response is
+    /// revalidated every time it's requested.
+    pub fresh_until: Option<DateTime<Utc>>,
+}
+
+impl CachedResponse {
+    /// Create a new cache entry from the response headers and body. Returns [`None`] if the
+    /// response must not be cached (`Cache-Control: no-store`) or if it's neither fresh nor has any
+    /// validators to revalidate it.
+    pub(crate) fn new(
+        headers: &HeaderMap,
+        body: Vec<u8>,
+        default_ttl: Option<Duration>,
+    ) -> Option<Self> {
+        let cache_control = CacheControl::from_headers(headers);
+        if cache_control.no_store {
+            return None;
+        }
+
+        let response = Self {
+            body,
+            etag: header_string(headers, ETAG),
+            last_modified: header_string(headers, LAST_MODIFIED),
+            fresh_until: cache_control.fresh_until(default_ttl),
+        };
+        (response.fresh_until.is_some() || response.has_validators()).then_some(response)
+    }
+
+    /// Check if the response can be used without revalidating it.
+    pub fn is_fresh(&self) -> bool {
+        self.fresh_until
+            .is_some_and(|fresh_until| fresh_until > Utc::now())
+    }
+
+    /// Check if the response has an `ETag` or `Last-Modified` validator.
+    pub fn has_validators(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+
+    /// Add the conditional request headers for the validators of this response.
+    pub(crate) fn apply_validators(&self, headers: &mut HeaderMap) {
+        if let Some(etag) = self.etag.as_ref().and_then(|etag| etag.parse().ok()) {
+            headers.insert(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = self
+            .last_modified
+            .as_ref()
+            .and_then(|last_modified| last_modified.parse().ok())
+        {
+            headers.insert(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    /// Update the response after Crunchyroll confirmed that it's still valid (`304 Not Modified`).
+    pub(crate) fn revalidated(
+        mut self,
+        headers: &HeaderMap,
+        default_ttl: Option<Duration>,
+    ) -> Self {
+        self.fresh_until = CacheControl::from_headers(headers).fresh_until(default_ttl);
+        if let Some(etag) = header_string(headers, ETAG) {
+            self.etag = Some(etag)
+        }
+        if let Some(last_modified) = header_string(headers, LAST_MODIFIED) {
+            self.last_modified = Some(last_modified)
+        }
+        self
+    }
+}
+
+/// Storage backend of a [`ResponseCache`]. Implement this to store cached responses somewhere
+/// else than in memory ([`MemoryCacheStorage`]) or on disk ([`DiskCacheStorage`]).
+#[async_trait]
+pub trait CacheStorage: Send + Sync {
+    /// Get the cached response for `key`.
+    async fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Store a response for `key`.
+    async fn set(&self, key: &str, response: CachedResponse) -> Result<()>;
+
+    /// Remove all cached responses.
+    async fn clear(&self) -> Result<()>;
+}
+
+/// Stores cached responses in memory. If more than [`MemoryCacheStorage::with_capacity`]
+/// responses (1000 by default) are stored, the least recently used one is evicted.
+#[derive(Debug)]
+pub struct MemoryCacheStorage {
+    capacity: usize,
+    responses: Mutex<MemoryCacheEntries>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryCacheEntries {
+    /// The response and when it was used the last time.
+    entries: HashMap<String, (CachedResponse, u64)>,
+    tick: u64,
+}
+
+impl MemoryCacheStorage {
+    /// Create a new storage which holds at most `capacity` responses.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            responses: Mutex::default(),
+        }
+    }
+}
+
+impl Default for MemoryCacheStorage {
+    fn default() -> Self {
+        Self::with_capacity(1000)
+    }
+}
+
+#[async_trait]
+impl CacheStorage for MemoryCacheStorage {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut responses = self.responses.lock().unwrap();
+        responses.tick += 1;
+        let tick = responses.tick;
+        let (response, last_used) = responses.entries.get_mut(key)?;
+        *last_used = tick;
+        Some(response.clone())
+    }
+
+    async fn set(&self, key: &str, response: CachedResponse) -> Result<()> {
+        let mut responses = self.responses.lock().unwrap();
+        responses.tick += 1;
+        let tick = responses.tick;
+        if !responses.entries.contains_key(key) && responses.entries.len() >= self.capacity {
+            let least_recently_used = responses
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(least_recently_used) = least_recently_used {
+                responses.entries.remove(&least_recently_used);
+            }
+        }
+        responses.entries.insert(key.to_string(), (response, tick));
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<()> {
+        self.responses.lock().unwrap().entries.clear();
+        Ok(())
+    }
+}
+
+/// Stores cached responses as files in a directory. The directory is created if it doesn't exist.
+#[derive(Clone, Debug)]
+pub struct DiskCacheStorage {
+    directory: PathBuf,
+}
+
+impl DiskCacheStorage {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        // fnv-1a is used because it's stable across rust versions, unlike the std hasher
+        let hash = key.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+        self.directory.join(format!("{hash:016x}.json"))
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct DiskCacheEntry {
+    key: String,
+    response: CachedResponse,
+}
+
+#[async_trait]
+impl CacheStorage for DiskCacheStorage {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        let raw = fs::read(self.path(key)).await.ok()?;
+        let entry: DiskCacheEntry = serde_json::from_slice(&raw).ok()?;
+        // the file name is only a hash of the key, so it must be checked that the entry actually
+        // belongs to the key
+        (entry.key == key).then_some(entry.response)
+    }
+
+    async fn set(&self, key: &str, response: CachedResponse) -> Result<()> {
+        fs::create_dir_all(&self.directory)
+            .await
+            .map_err(io_error)?;
+        let entry = DiskCacheEntry {
+            key: key.to_string(),
+            response,
+        };
+        fs::write(self.path(key), serde_json::to_vec(&entry)?)
+            .await
+            .map_err(io_error)
+    }
+
+    async fn clear(&self) -> Result<()> {
+        let Ok(paths) = fs::read_dir(&self.directory).await else {
+            return Ok(());
+        };
+        for path in paths {
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                fs::remove_file(path).await.map_err(io_error)?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Cache for api responses. Pass it to [`crate::crunchyroll::CrunchyrollBuilder::cache`] to
+/// enable caching.
+///
+/// The request url is used as cache key. Some responses differ depending on the logged in account,
+/// so a [`DiskCacheStorage`] directory shouldn't be shared between multiple accounts.
+///
+/// ```no_run
+/// use chrono::Duration;
+/// use crunchyroll_rs::cache::ResponseCache;
+/// use crunchyroll_rs::Crunchyroll;
+///
+/// # async fn f() -> Result<(), crunchyroll_rs::error::Error> {
+/// let crunchy = Crunchyroll::builder()
+///     // use responses for 10 minutes before revalidating them if Crunchyroll doesn't say
+///     // otherwise
+///     .cache(ResponseCache::memory().default_ttl(Duration::minutes(10)))
+///     .login_anonymously()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ResponseCache {
+    pub(crate) storage: Arc<dyn CacheStorage>,
+    pub(crate) default_ttl: Option<Duration>,
+}
+
+impl ResponseCache {
+    /// Create a new cache which uses a custom storage.
+    pub fn new(storage: impl CacheStorage + 'static) -> Self {
+        Self {
+            storage: Arc::new(storage),
+            default_ttl: None,
+        }
+    }
+
+    /// Create a new cache which stores responses in memory.
+    pub fn memory() -> Self {
+        Self::new(MemoryCacheStorage::default())
+    }
+
+    /// Create a new cache which stores responses in `directory`.
+    pub fn disk(directory: impl Into<PathBuf>) -> Self {
+        Self::new(DiskCacheStorage::new(directory))
+    }
+
+    /// How long a response can be used without revalidating it if it has no `Cache-Control`
+    /// `max-age` directive. By default, such responses are revalidated on every request.
+    pub fn default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = Some(ttl);
+        self
+    }
+
+    /// Remove all cached responses.
+    pub async fn clear(&self) -> Result<()> {
+        self.storage.clear().await
+    }
+}
+
+impl Debug for ResponseCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("default_ttl", &self.default_ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Default)]
+struct CacheControl {
+    no_store: bool,
+    no_cache: bool,
+    max_age: Option<i64>,
+}
+
+impl CacheControl {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let mut cache_control = Self::default();
+        for directive in headers
+            .get_all(CACHE_CONTROL)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+        {
+            let directive = directive.trim().to_lowercase();
+            if directive == "no-store" {
+                cache_control.no_store = true
+            } else if directive == "no-cache" {
+                cache_control.no_cache = true
+            } else if let Some(max_age) = directive.strip_prefix("max-age=") {
+                cache_control.max_age = max_age.trim_matches('"').parse().ok()
+            }
+        }
+        cache_control
+    }
+
+    fn fresh_until(&self, default_ttl: Option<Duration>) -> Option<DateTime<Utc>> {
+        if self.no_cache {
+            return None;
+        }
+        self.max_age
+            .and_then(Duration::try_seconds)
+            .or(default_ttl)
+            .map(|ttl| Utc::now() + ttl)
+    }
+}
+
+fn header_string(headers: &HeaderMap, name: reqwest::header::HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
+
+fn io_error(err: std::io::Error) -> crate::error::Error {
+    crate::error::Error::Internal {
+        message: format!("failed to access response cache: {err}"),
+    }
+}
@@ -2,8 +2,11 @@
 
 use crate::common::{Image, V2BulkResult};
 use crate::crunchyroll::Executor;
+use crate::media::{Concurrency, MediaType};
+use crate::search::BrowseOptions;
 use crate::Result;
-use crate::{enum_values, Crunchyroll, Locale, Request};
+use crate::{enum_values, Crunchyroll, Locale, MediaCollection, Request};
+use futures_util::{stream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -57,6 +60,12 @@ impl From<CategoryInformation> for Category {
     }
 }
 
+impl From<SubCategoryInformation> for Category {
+    fn from(sub_category_information: SubCategoryInformation) -> Self {
+        sub_category_information.category
+    }
+}
+
 /// Images for [`CategoryInformation`].
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
@@ -92,6 +101,12 @@ pub struct CategoryInformation {
 
     /// A human readable title & description about the category.
     pub localization: CategoryInformationLocalization,
+
+    /// All sub-categories of this category. Only populated if this struct was returned by
+    /// [`Crunchyroll::categories`], use [`CategoryInformation::sub_categories`] otherwise.
+    #[serde(rename = "children")]
+    #[serde(default)]
+    pub sub_category_information: Vec<SubCategoryInformation>,
 }
 
 impl CategoryInformation {
@@ -111,7 +126,8 @@ impl CategoryInformation {
     }
 }
 
-/// A sub category of an anime category / genre.
+/// A sub category of an anime category / genre. Can be converted into a [`Category`] to filter
+/// [`Crunchyroll::browse`] results by it (via [`crate::search::BrowseOptions::categories`]).
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
 #[cfg_attr(not(feature = "__test_strict"), serde(default))]
@@ -127,15 +143,98 @@ pub struct SubCategoryInformation {
     pub localization: CategoryInformationLocalization,
 }
 
+/// Number of titles in a category, returned by [`Crunchyroll::category_statistics`].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CategoryStatistics {
+    pub category: Category,
+
+    /// Number of series in this category.
+    pub series_count: u32,
+    /// Number of movie listings in this category.
+    pub movie_listing_count: u32,
+
+    /// Statistics of all sub-categories of this category. Always empty if this struct is a
+    /// sub-category itself.
+    pub sub_categories: Vec<CategoryStatistics>,
+}
+
+impl CategoryStatistics {
+    /// Total number of titles (series and movie listings) in this category.
+    pub fn total(&self) -> u32 {
+        self.series_count + self.movie_listing_count
+    }
+}
+
 impl Crunchyroll {
+    /// Returns the number of series and movie listings per category and sub-category. Note that
+    /// this function makes two requests per category and sub-category, which are made with
+    /// [`Concurrency::default`] concurrent requests.
+    pub async fn category_statistics(&self) -> Result<Vec<CategoryStatistics>> {
+        let category_information = self.categories().await?;
+
+        let categories = category_information.iter().flat_map(|c| {
+            std::iter::once(&c.category).chain(
+                c.sub_category_information
+                    .iter()
+                    .map(|sub_category| &sub_category.category),
+            )
+        });
+        let mut statistics = stream::iter(categories.cloned())
+            .map(|category| self.single_category_statistics(category))
+            .buffered(Concurrency::default().0)
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter();
+
+        let mut result = vec![];
+        for information in category_information {
+            // the statistics are in the same order as the categories they were requested for
+            let mut category_statistics = statistics.next().unwrap();
+            category_statistics.sub_categories = statistics
+                .by_ref()
+                .take(information.sub_category_information.len())
+                .collect();
+            result.push(category_statistics)
+        }
+        Ok(result)
+    }
+
+    async fn single_category_statistics(&self, category: Category) -> Result<CategoryStatistics> {
+        let mut counts = vec![];
+        for media_type in [MediaType::Series, MediaType::Movie] {
+            let options = BrowseOptions::default()
+                .categories(vec![category.clone()])
+                .media_type(media_type);
+            let endpoint = "https://www.crunchyroll.com/content/v2/discover/browse";
+            let total = self
+                .executor
+                .get(endpoint)
+                .query(&options.into_query())
+                .query(&[("n", 1)])
+                .apply_locale_query()
+                .apply_preferred_audio_locale_query()
+                .request::<V2BulkResult<MediaCollection>>()
+                .await?
+                .total;
+            counts.push(total)
+        }
+        Ok(CategoryStatistics {
+            category,
+            series_count: counts[0],
+            movie_listing_count: counts[1],
+            sub_categories: vec![],
+        })
+    }
+
     /// Returns all video categories. Note that not all categories declared in [`Category`] are
-    /// returned since some of them are sub-categories. Call [`Category::sub_categories`] to get a
-    /// list of categories which are sub-categories.
+    /// returned since some of them are sub-categories. The sub-categories of every category are
+    /// available via [`CategoryInformation::sub_category_information`].
     pub async fn categories(&self) -> Result<Vec<CategoryInformation>> {
         let endpoint = "https://www.crunchyroll.com/content/v2/discover/categories";
         Ok(self
             .executor
             .get(endpoint)
+            .query(&[("include_subcategories", "true")])
             .apply_locale_query()
             .request::<V2BulkResult<CategoryInformation>>()
             .await?
@@ -1,18 +1,22 @@
 //! Commonly used types.
 
-use crate::{Executor, Result};
+use crate::error::Error;
+use crate::{enum_values, Executor, Result};
+use chrono::{DateTime, Utc};
+use futures_util::future::MaybeDone;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::task::{Context, Poll};
 
 pub(crate) use crunchyroll_rs_internal::Request;
 
 // export this crate traits as public as they're needed for pagination
 pub use futures_util::{Stream, StreamExt, TryStream, TryStreamExt};
+pub use tokio_util::sync::CancellationToken;
 
 /// Contains a variable amount of items and the maximum / total of item which are available.
 /// Mostly used when fetching pagination results.
@@ -48,16 +52,36 @@ pub(crate) struct V2TypeBulkResult<T: Default + DeserializeOwned + Request> {
     pub(crate) items: Vec<T>,
 }
 
+enum_values! {
+    /// Direction in which a [`Pagination`] returns its items, see [`Pagination::order`].
+    pub enum PaginationOrder {
+        Ascending = "asc"
+        Descending = "desc"
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct PaginationOptions {
     pub(crate) executor: Arc<Executor>,
     pub(crate) start: u32,
     pub(crate) page: u32,
     pub(crate) page_size: u32,
+    pub(crate) order: Option<PaginationOrder>,
     pub(crate) query: Vec<(String, String)>,
     pub(crate) extra: BTreeMap<&'static str, String>,
 }
 
+impl PaginationOptions {
+    /// Query parameters for the order set via [`Pagination::order`]. Empty if no order is set, so
+    /// that the endpoint returns its items in their default order.
+    pub(crate) fn order_query(&self) -> Vec<(&'static str, String)> {
+        self.order
+            .iter()
+            .map(|order| ("order", order.to_string()))
+            .collect()
+    }
+}
+
 /// Crunchyroll doesn't always deliver the correct number of total elements on pagination endpoints.
 /// Sometimes it also delivers a link which refers to the next page which can be used to indicate if
 /// more pages are existing. This enum stores if more pages existing by looking up if the link is
@@ -113,6 +137,22 @@ impl<T: Default + DeserializeOwned + Request> From<BulkResult<T>> for Pagination
 }
 
 /// Pagination for results which can be continuously be fetched.
+///
+/// Endpoints which return all items at once (like [`crate::Series::seasons`]) are returned as
+/// pagination too, with only a single page, so that all list results can be consumed the same way:
+///
+/// ```no_run
+/// use crunchyroll_rs::common::StreamExt;
+/// use crunchyroll_rs::Series;
+///
+/// # async fn f(series: Series) -> Result<(), crunchyroll_rs::error::Error> {
+/// let mut seasons = series.seasons();
+/// while let Some(season) = seasons.next().await {
+///     println!("{}", season?.title)
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[allow(clippy::type_complexity)]
 pub struct Pagination<T: Default + DeserializeOwned + Request> {
     data: Vec<T>,
@@ -124,11 +164,26 @@ pub struct Pagination<T: Default + DeserializeOwned + Request> {
                 -> Pin<Box<dyn Future<Output = Result<PaginationData<T>>> + Send + 'static>>
             + Send,
     >,
-    next_state: Option<Pin<Box<dyn Future<Output = Result<PaginationData<T>>> + Send + 'static>>>,
+    /// Requested pages, in order. Contains more than one page if [`Pagination::prefetch`] is used.
+    pending: VecDeque<
+        MaybeDone<Pin<Box<dyn Future<Output = Result<PaginationData<T>>> + Send + 'static>>>,
+    >,
+    /// Amount of pages which should be requested in advance.
+    prefetch: u32,
+    /// If the endpoint respects [`Pagination::order`].
+    order_supported: bool,
+    /// Resolves when the token set via [`Pagination::cancel_on`] gets cancelled.
+    cancelled: Option<Pin<Box<tokio_util::sync::WaitForCancellationFutureOwned>>>,
 
     paginator_options: PaginationOptions,
+    /// Start offset of the next requested page.
+    next_start: u32,
 
     count: u32,
+    /// Amount of items which should be discarded from the next fetched page(s). Used by
+    /// [`Pagination::skip_to`] if the offset is in the middle of a page.
+    skip: u32,
+    total: Option<u32>,
     next_type: Option<PaginationNextType>,
 }
 
@@ -138,38 +193,28 @@ impl<T: Default + DeserializeOwned + Request> Stream for Pagination<T> {
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.get_mut();
 
-        if !this.data.is_empty() || this.has_next_page() {
-            if !this.data.is_empty() {
-                this.count += 1;
-                return Poll::Ready(Some(Ok(this.data.remove(0))));
+        if let Some(cancelled) = &mut this.cancelled {
+            if cancelled.as_mut().poll(cx).is_ready() {
+                this.cancelled = None;
+                // drops all in-flight requests and ends the pagination
+                this.data.clear();
+                this.pending.clear();
+                this.next_type = Some(PaginationNextType::NextPage(false));
+                return Poll::Ready(Some(Err(Error::Cancelled)));
             }
+        }
 
-            if this.next_state.is_none() {
-                let f = this.next_fn.as_mut();
-                let options = &mut this.paginator_options;
-                options.start = this.count;
-                options.page += 1;
-                this.next_state = Some(f(options.clone()));
-            }
+        match this.poll_fill(cx) {
+            Poll::Ready(Ok(())) => (),
+            Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e))),
+            Poll::Pending => return Poll::Pending,
+        }
 
-            let fut = this.next_state.as_mut().unwrap();
-            match Pin::new(fut).poll(cx) {
-                Poll::Ready(result) => {
-                    this.next_state = None;
-                    match result {
-                        Ok(data) => {
-                            this.data = data.data;
-                            this.next_type = Some(data.next_type);
-
-                            Pin::new(this).poll_next(cx)
-                        }
-                        Err(e) => Poll::Ready(Some(Err(e))),
-                    }
-                }
-                Poll::Pending => Poll::Pending,
-            }
-        } else {
+        if this.data.is_empty() {
             Poll::Ready(None)
+        } else {
+            this.count += 1;
+            Poll::Ready(Some(Ok(this.data.remove(0))))
         }
     }
 }
@@ -194,20 +239,139 @@ impl<T: Default + DeserializeOwned + Request> Pagination<T> {
         Self {
             data: vec![],
             next_fn: Box::new(pagination_fn),
-            next_state: None,
+            pending: VecDeque::new(),
+            prefetch: 0,
+            order_supported: false,
+            cancelled: None,
             paginator_options: PaginationOptions {
                 executor,
                 start: 0,
                 page: 0,
                 page_size: 20,
+                order: None,
                 query: query.unwrap_or_default(),
                 extra: extra.map_or(BTreeMap::new(), BTreeMap::from_iter),
             },
+            next_start: 0,
             count: 0,
+            skip: 0,
+            total: None,
             next_type: None,
         }
     }
 
+    /// Create a pagination from a request which returns all items at once. The request is executed
+    /// when the first item is polled. Because all items are returned in a single page,
+    /// [`Pagination::page_size`] has no effect.
+    pub(crate) fn from_future<F>(future: F, executor: Arc<Executor>) -> Self
+    where
+        F: Future<Output = Result<Vec<T>>> + Send + 'static,
+    {
+        let mut future = Some(Box::pin(future));
+        Self::new(
+            move |_| {
+                let future = future.take();
+                Box::pin(async move {
+                    let data = match future {
+                        Some(future) => future.await?,
+                        None => vec![],
+                    };
+                    Ok(PaginationData {
+                        data,
+                        next_type: PaginationNextType::NextPage(false),
+                    })
+                })
+            },
+            executor,
+            None,
+            None,
+        )
+    }
+
+    /// Mark that the endpoint respects [`Pagination::order`].
+    pub(crate) fn with_order_support(mut self) -> Self {
+        self.order_supported = true;
+        self
+    }
+
+    /// Fetch new pages until items are available or no more pages exist.
+    fn poll_fill(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        while self.data.is_empty() && self.has_next_page() {
+            if self.pending.is_empty() {
+                if self.paginator_options.page_size == 0 {
+                    self.next_type = Some(PaginationNextType::NextPage(false));
+                    return Poll::Ready(Err(Error::Input {
+                        message: "page size must not be 0".to_string(),
+                    }));
+                }
+                self.request_next_page()
+            }
+
+            let page = self.pending.front_mut().unwrap();
+            if Pin::new(&mut *page).poll(cx).is_pending() {
+                self.poll_prefetch(cx);
+                return Poll::Pending;
+            }
+            let data = Pin::new(page).take_output().unwrap();
+            self.pending.pop_front();
+            let data = data?;
+
+            if let PaginationNextType::Total(total) = data.next_type {
+                self.total = Some(total)
+            }
+            // an empty page would otherwise cause an endless loop if the total amount reported by
+            // crunchyroll is wrong
+            self.next_type = if data.data.is_empty() {
+                Some(PaginationNextType::NextPage(false))
+            } else {
+                Some(data.next_type)
+            };
+            self.data = data.data;
+
+            let skip = self.skip.min(self.data.len() as u32);
+            self.data.drain(..skip as usize);
+            self.skip -= skip;
+            self.count += skip;
+            if self.pending.is_empty() {
+                self.next_start = self.count + self.data.len() as u32
+            }
+        }
+        self.poll_prefetch(cx);
+        Poll::Ready(Ok(()))
+    }
+
+    /// Request the page which follows the last requested page.
+    fn request_next_page(&mut self) {
+        let f = self.next_fn.as_mut();
+        let options = &mut self.paginator_options;
+        options.start = self.next_start;
+        options.page += 1;
+        self.next_start += options.page_size;
+        self.pending
+            .push_back(MaybeDone::Future(f(options.clone())));
+    }
+
+    /// Request pages in advance until [`Pagination::prefetch`] pages are pending and drive all
+    /// pending requests.
+    fn poll_prefetch(&mut self, cx: &mut Context<'_>) {
+        while (self.pending.len() as u32) < self.prefetch && self.can_prefetch() {
+            self.request_next_page()
+        }
+        for page in &mut self.pending {
+            let _ = Pin::new(page).poll(cx);
+        }
+    }
+
+    /// Check if the page after the last requested page is known to exist.
+    fn can_prefetch(&self) -> bool {
+        match self.next_type {
+            // only the existence of the page after the last received page is known
+            Some(PaginationNextType::NextPage(next)) => next && self.pending.is_empty(),
+            Some(PaginationNextType::Total(total)) => self.next_start < total,
+            None => false,
+        }
+    }
+
     /// Check if more pages are available.
     fn has_next_page(&self) -> bool {
         if let Some(next_type) = &self.next_type {
@@ -221,23 +385,106 @@ impl<T: Default + DeserializeOwned + Request> Pagination<T> {
     }
 
     /// Set the amount of pages fetched when needed. Only recommended to change if you want a big
-    /// batch of data (> 100). Make sure that the size is never 0 as this will cause a dead loop.
+    /// batch of data (> 100). A size of 0 is invalid, the pagination returns an [`Error::Input`]
+    /// and ends if a page should be requested with it.
     pub fn page_size(&mut self, size: u32) {
         self.paginator_options.page_size = size
     }
 
+    /// Set the direction in which items are returned, e.g. to iterate the watch history
+    /// oldest-first. Only endpoints which document support for it can be ordered, for all others
+    /// an [`Error::Input`] is returned. The order must be set before the first item is polled,
+    /// otherwise an [`Error::Input`] is returned too.
+    pub fn order(&mut self, order: PaginationOrder) -> Result<()> {
+        if !self.order_supported {
+            return Err(Error::Input {
+                message: "the endpoint of this pagination doesn't support ordering".to_string(),
+            });
+        }
+        if self.paginator_options.page > 0 {
+            return Err(Error::Input {
+                message: "the order must be set before the first page is requested".to_string(),
+            });
+        }
+        self.paginator_options.order = Some(order);
+        Ok(())
+    }
+
+    /// Request up to `pages` pages in advance, so that the next page(s) are fetched while the items
+    /// of the current page are consumed. Pages are requested only if they're known to exist. For
+    /// endpoints which don't report the total amount of items, only one page can be fetched in
+    /// advance. The prefetched requests progress whenever the pagination is polled. `0` (the
+    /// default) disables prefetching.
+    pub fn prefetch(&mut self, pages: u32) {
+        self.prefetch = pages
+    }
+
+    /// Stop the pagination when `token` gets cancelled. In-flight page requests are aborted, an
+    /// [`Error::Cancelled`] is returned once and the pagination ends afterwards.
+    pub fn cancel_on(&mut self, token: CancellationToken) {
+        self.cancelled = Some(Box::pin(token.cancelled_owned()))
+    }
+
+    /// Return the current page size. See [`Pagination::page_size`] to change it.
+    pub fn get_page_size(&self) -> u32 {
+        self.paginator_options.page_size
+    }
+
+    /// Return the offset of the next item which will be returned, which is the amount of items
+    /// already returned (plus the offset set via [`Pagination::skip_to`]). Can be stored to resume
+    /// an interrupted iteration later with [`Pagination::skip_to`].
+    pub fn offset(&self) -> u32 {
+        self.count + self.skip
+    }
+
+    /// Continue the iteration at the given offset. Can be used for random access, e.g. to jump to
+    /// a specific page (`page * page_size`) or to resume an interrupted iteration. Already fetched
+    /// but not yet returned items are discarded.
+    pub fn skip_to(&mut self, offset: u32) {
+        let page_size = self.paginator_options.page_size.max(1);
+        let page = offset / page_size;
+
+        self.data.clear();
+        self.pending.clear();
+        self.next_type = None;
+        // some endpoints only support page based pagination, so the page which contains the offset
+        // is fetched and all items before the offset are skipped
+        self.count = page * page_size;
+        self.next_start = self.count;
+        self.skip = offset - self.count;
+        self.paginator_options.page = page;
+    }
+
+    /// Collect the next `n` items (or less if not enough items are available).
+    pub async fn collect_n(&mut self, n: usize) -> Result<Vec<T>> {
+        let mut items = Vec::with_capacity(n.min(self.paginator_options.page_size as usize));
+        while items.len() < n {
+            match StreamExt::next(self).await {
+                Some(item) => items.push(item?),
+                None => break,
+            }
+        }
+        Ok(items)
+    }
+
+    /// Collect the items of the next `pages` pages. A page contains [`Pagination::get_page_size`]
+    /// items.
+    pub async fn take_pages(&mut self, pages: u32) -> Result<Vec<T>> {
+        self.collect_n((pages as usize).saturating_mul(self.paginator_options.page_size as usize))
+            .await
+    }
+
     /// Return the total amount of items which can be fetched. Is [`Some`] if the total amount is
     /// known, else [`None`] (Crunchyroll has two different pagination implementations, one doesn't
-    /// report the total amount).
+    /// report the total amount). If no page was fetched yet, the first page is requested (without
+    /// consuming any items).
     pub async fn total(&mut self) -> Option<u32> {
         if self.next_type.is_none() {
-            StreamExt::next(self).await;
-        }
-        if let PaginationNextType::Total(total) = self.next_type.as_ref().unwrap() {
-            Some(*total)
-        } else {
-            None
+            futures_util::future::poll_fn(|cx| self.poll_fill(cx))
+                .await
+                .ok()?;
         }
+        self.total
     }
 }
 
@@ -254,6 +501,71 @@ pub(crate) struct BulkResult<T: Default + DeserializeOwned + Request> {
     pub total: u32,
 }
 
+/// A timestamp which keeps the raw string the api returned and parses it on first access. Use it
+/// instead of [`DateTime`] in your own response types (e.g. for [`crate::Crunchyroll::custom_request`])
+/// if many timestamps are deserialized but only few of them are read.
+///
+/// Malformed timestamps don't fail the deserialization, [`LazyDateTime::get`] returns [`None`]
+/// for them instead.
+#[derive(Clone, Default)]
+pub struct LazyDateTime {
+    raw: String,
+    parsed: OnceLock<Option<DateTime<Utc>>>,
+}
+
+impl LazyDateTime {
+    /// The timestamp as returned by the api.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// The parsed timestamp. [`None`] if the timestamp is empty or malformed.
+    pub fn get(&self) -> Option<DateTime<Utc>> {
+        *self.parsed.get_or_init(|| {
+            DateTime::parse_from_rfc3339(&self.raw)
+                .ok()
+                .map(|date| date.with_timezone(&Utc))
+        })
+    }
+}
+
+impl From<DateTime<Utc>> for LazyDateTime {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self {
+            raw: value.to_rfc3339(),
+            parsed: OnceLock::from(Some(value)),
+        }
+    }
+}
+
+impl std::fmt::Debug for LazyDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LazyDateTime").field(&self.raw).finish()
+    }
+}
+
+impl PartialEq for LazyDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw || (self.get().is_some() && self.get() == other.get())
+    }
+}
+
+impl<'de> Deserialize<'de> for LazyDateTime {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+        Ok(Self {
+            raw,
+            parsed: OnceLock::new(),
+        })
+    }
+}
+
+impl Serialize for LazyDateTime {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
 /// The standard representation of images how the api returns them.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
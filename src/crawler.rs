@@ -0,0 +1,204 @@
+//! Walk the whole browse catalog.
+
+use crate::categories::Category;
+use crate::search::{BrowseOptions, BrowseSortType};
+use crate::{Crunchyroll, MediaCollection, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Progress of a [`Crawler`]. Store it (e.g. as json) to resume an interrupted crawl later via
+/// [`Crawler::resume`].
+#[cfg_attr(docsrs, doc(cfg(feature = "crawler")))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct CrawlerCheckpoint {
+    /// The categories which are crawled. [`None`] if the crawl hasn't started yet.
+    pub categories: Option<Vec<Category>>,
+    /// Index (in [`CrawlerCheckpoint::categories`]) of the category which is currently crawled.
+    pub category_index: usize,
+    /// Offset of the next item in the current category.
+    pub offset: u32,
+    /// Ids of all visited media. Media is listed in multiple categories but visited only once.
+    pub visited: HashSet<String>,
+}
+
+impl CrawlerCheckpoint {
+    /// If all categories were crawled.
+    pub fn is_finished(&self) -> bool {
+        self.categories
+            .as_ref()
+            .is_some_and(|categories| self.category_index >= categories.len())
+    }
+}
+
+/// Walks the whole browse catalog (every page of every category) and calls a visitor for every
+/// series / movie listing. Every media is visited only once, even if it's listed in multiple
+/// categories. The crawler waits [`Crawler::delay`] between requests to not hammer the api, use
+/// [`crate::crunchyroll::CrunchyrollBuilder::retry_rate_limited`] additionally to handle rate
+/// limits gracefully.
+///
+/// ```no_run
+/// use crunchyroll_rs::crawler::{Crawler, CrawlerCheckpoint};
+///
+/// # async fn f(crunchyroll: crunchyroll_rs::Crunchyroll) -> Result<(), Box<dyn std::error::Error>> {
+/// let checkpoint: CrawlerCheckpoint = match std::fs::read("checkpoint.json") {
+///     Ok(json) => serde_json::from_slice(&json)?,
+///     Err(_) => CrawlerCheckpoint::default(),
+/// };
+/// let mut crawler = Crawler::new(&crunchyroll)
+///     .resume(checkpoint)
+///     .on_checkpoint(|checkpoint| {
+///         let _ = std::fs::write("checkpoint.json", serde_json::to_vec(checkpoint).unwrap());
+///     });
+/// crawler.run(|media| println!("{}", media.id())).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "crawler")))]
+pub struct Crawler {
+    crunchyroll: Crunchyroll,
+    categories: Option<Vec<Category>>,
+    options: BrowseOptions,
+    page_size: u32,
+    delay: Duration,
+
+    checkpoint: CrawlerCheckpoint,
+    #[allow(clippy::type_complexity)]
+    on_checkpoint: Option<Box<dyn FnMut(&CrawlerCheckpoint) + Send>>,
+}
+
+impl Crawler {
+    /// Creates a new crawler. By default, all categories and sub-categories (see
+    /// [`Crunchyroll::categories`]) are crawled with 100 items per page and 1 second delay between
+    /// requests.
+    pub fn new(crunchyroll: &Crunchyroll) -> Self {
+        Self {
+            crunchyroll: crunchyroll.clone(),
+            categories: None,
+            options: BrowseOptions::default(),
+            page_size: 100,
+            delay: Duration::from_secs(1),
+            checkpoint: CrawlerCheckpoint::default(),
+            on_checkpoint: None,
+        }
+    }
+
+    /// Only crawl the given categories. Ignored if a checkpoint which already started is resumed.
+    pub fn categories(mut self, categories: Vec<Category>) -> Self {
+        self.categories = Some(categories);
+        self
+    }
+
+    /// Additional browse options, e.g. to only crawl dubbed media. [`BrowseOptions::categories`]
+    /// and [`BrowseOptions::sort`] are overwritten by the crawler.
+    pub fn browse_options(mut self, options: BrowseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set how many items are requested per page.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Set how long to wait between two requests.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Continue a previous crawl from the given checkpoint.
+    pub fn resume(mut self, checkpoint: CrawlerCheckpoint) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    /// Called with the current progress every time a page was visited completely. Useful to store
+    /// the checkpoint to resume the crawl if the process gets interrupted.
+    pub fn on_checkpoint(mut self, f: impl FnMut(&CrawlerCheckpoint) + Send + 'static) -> Self {
+        self.on_checkpoint = Some(Box::new(f));
+        self
+    }
+
+    /// The current progress. If [`Crawler::run`] failed, the crawl can be resumed from it.
+    pub fn checkpoint(&self) -> &CrawlerCheckpoint {
+        &self.checkpoint
+    }
+
+    /// Crawl the catalog and call `visitor` for every media which wasn't visited yet. Returns when
+    /// all categories were crawled or a request failed. Calling it again continues at the last
+    /// checkpoint. Not supported on wasm.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn run(&mut self, mut visitor: impl FnMut(MediaCollection)) -> Result<()> {
+        let mut first_request = true;
+
+        if self.checkpoint.categories.is_none() {
+            let categories = match &self.categories {
+                Some(categories) => categories.clone(),
+                None => {
+                    let mut categories = vec![];
+                    for category in self.crunchyroll.categories().await? {
+                        categories.push(category.category.clone());
+                        categories.extend(
+                            category
+                                .sub_category_information
+                                .into_iter()
+                                .map(|sub_category| sub_category.category),
+                        )
+                    }
+                    first_request = false;
+                    categories
+                }
+            };
+            self.checkpoint.categories = Some(categories);
+            self.checkpoint.category_index = 0;
+            self.checkpoint.offset = 0;
+            self.save_checkpoint()
+        }
+
+        let categories = self.checkpoint.categories.clone().unwrap_or_default();
+        while let Some(category) = categories.get(self.checkpoint.category_index) {
+            let mut pagination = self.crunchyroll.browse(
+                self.options
+                    .clone()
+                    .categories(vec![category.clone()])
+                    .sort(BrowseSortType::Alphabetical),
+            );
+            pagination.page_size(self.page_size);
+            pagination.skip_to(self.checkpoint.offset);
+
+            loop {
+                if !first_request {
+                    tokio::time::sleep(self.delay).await
+                }
+                first_request = false;
+
+                let page = pagination.take_pages(1).await?;
+                let last_page = (page.len() as u32) < self.page_size;
+                for media in page {
+                    if self.checkpoint.visited.insert(media.id().to_string()) {
+                        visitor(media)
+                    }
+                }
+                self.checkpoint.offset = pagination.offset();
+                if last_page {
+                    break;
+                }
+                self.save_checkpoint()
+            }
+
+            self.checkpoint.category_index += 1;
+            self.checkpoint.offset = 0;
+            self.save_checkpoint()
+        }
+        Ok(())
+    }
+
+    fn save_checkpoint(&mut self) {
+        if let Some(on_checkpoint) = &mut self.on_checkpoint {
+            on_checkpoint(&self.checkpoint)
+        }
+    }
+}
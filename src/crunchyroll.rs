@@ -1,8 +1,15 @@
 //! Builder and access to the [`Crunchyroll`] struct which is required to make any action.
 
 use crate::enum_values;
-use reqwest::Client;
-use std::sync::Arc;
+use crate::error::Error;
+use crate::{Request, Result};
+use reqwest::{Client, Method};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::sync::{Arc, OnceLock, Weak};
+
+/// User agent which is used by [`CrunchyrollBuilder::predefined_client_builder`].
+pub(crate) const USER_AGENT: &str = "Crunchyroll/1.8.0 Nintendo Switch/12.3.12.0 UE4/4.27";
 
 enum_values! {
     /// Enum of supported languages by Crunchyroll.
@@ -121,11 +128,33 @@ enum_values! {
 }
 
 /// Starting point of this whole library.
+///
+/// The struct, like all media objects (series, episodes, ...), is `Send + Sync` and cheap to
+/// clone, all clones share the same session. Media objects keep the session they were requested
+/// with alive, use [`Crunchyroll::downgrade`] and [`Crunchyroll::detach`] if you store them for a
+/// longer time (e.g. in a cache) and the session should be dropped in the meantime.
 #[derive(Clone, Debug)]
 pub struct Crunchyroll {
     pub(crate) executor: Arc<Executor>,
 }
 
+/// A [`Crunchyroll`] session which isn't kept alive by this handle. Created via
+/// [`Crunchyroll::downgrade`].
+#[derive(Clone, Debug)]
+pub struct WeakCrunchyroll {
+    executor: Weak<Executor>,
+}
+
+impl WeakCrunchyroll {
+    /// Return the session if it's still alive, which is the case as long as a [`Crunchyroll`]
+    /// instance or any media object which isn't detached of it exists.
+    pub fn upgrade(&self) -> Option<Crunchyroll> {
+        self.executor
+            .upgrade()
+            .map(|executor| Crunchyroll { executor })
+    }
+}
+
 impl Crunchyroll {
     pub fn builder() -> CrunchyrollBuilder {
         CrunchyrollBuilder::default()
@@ -152,13 +181,141 @@ impl Crunchyroll {
     pub async fn session_token(&self) -> SessionToken {
         self.executor.config.read().await.session_token.clone()
     }
+
+    /// Return a handle to this session which doesn't keep it alive.
+    pub fn downgrade(&self) -> WeakCrunchyroll {
+        WeakCrunchyroll {
+            executor: Arc::downgrade(&self.executor),
+        }
+    }
+
+    /// Attach a (detached) media object to this session, so that it can be used to make requests
+    /// again.
+    pub async fn attach<T: Request>(&self, value: &mut T) {
+        value.__set_executor(self.executor.clone()).await
+    }
+
+    /// Detach a media object from its session so that it doesn't keep the session alive anymore.
+    /// Requests made with the object fail with [`Error::Input`] (without being sent) until it's
+    /// attached to a session again via [`Crunchyroll::attach`].
+    pub async fn detach<T: Request>(value: &mut T) {
+        static DETACHED: OnceLock<Arc<Executor>> = OnceLock::new();
+        let executor = DETACHED.get_or_init(|| {
+            Arc::new(Executor {
+                detached: true,
+                ..Default::default()
+            })
+        });
+        value.__set_executor(executor.clone()).await
+    }
+
+    /// Make a request to an api endpoint which isn't (yet) implemented by this crate. The request
+    /// uses the session, locale and error handling of this instance, so only the endpoint itself
+    /// has to be modeled. `path` is either relative to `https://www.crunchyroll.com` (like
+    /// `/content/v2/discover/categories`) or a full `https` url of a Crunchyroll host
+    /// (`crunchyroll.com`, `crunchyrollsvc.com` or a subdomain of them). Other urls are rejected
+    /// with [`Error::Input`] because the session token is sent along with the request.
+    ///
+    /// ```no_run
+    /// use crunchyroll_rs::Crunchyroll;
+    /// use reqwest::Method;
+    ///
+    /// # async fn f(crunchy: Crunchyroll) -> Result<(), crunchyroll_rs::error::Error> {
+    /// let categories: serde_json::Value = crunchy
+    ///     .custom_request(
+    ///         Method::GET,
+    ///         "/content/v2/discover/categories",
+    ///         &[("n", "5")],
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn custom_request<T: DeserializeOwned, Q: Serialize + ?Sized>(
+        &self,
+        method: Method,
+        path: impl AsRef<str>,
+        query: &Q,
+        body: Option<serde_json::Value>,
+    ) -> Result<T> {
+        let url = custom_request_url(path.as_ref())?;
+
+        let mut builder = self
+            .executor
+            .method(method, &url)
+            .query(query)
+            .apply_locale_query();
+        if let Some(body) = &body {
+            builder = builder.json(body)
+        }
+        let value: serde_json::Value = builder.request().await?;
+        serde_json::from_value(value.clone()).map_err(|e| Error::Decode {
+            message: e.to_string(),
+            content: value.to_string().into_bytes(),
+            url,
+        })
+    }
+
+    /// Subscribe to the events of this client (and all its clones). Only events which are emitted
+    /// after this call are received. Use [`CrunchyrollBuilder::events`] to receive the login
+    /// event too.
+    pub fn events(&self) -> futures_util::stream::BoxStream<'static, crate::events::ClientEvent> {
+        self.executor.events.subscribe()
+    }
+
+    /// Return a snapshot of the request metrics collected since the login (or the last
+    /// [`Crunchyroll::reset_metrics`]). The metrics are shared by all clones of this instance.
+    #[cfg(feature = "metrics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+    pub fn metrics(&self) -> crate::metrics::MetricsSnapshot {
+        self.executor.metrics.snapshot()
+    }
+
+    /// Reset all collected request metrics.
+    #[cfg(feature = "metrics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+    pub fn reset_metrics(&self) {
+        self.executor.metrics.reset()
+    }
+}
+
+/// Resolve the path of [`Crunchyroll::custom_request`] to a full url. Only https urls of
+/// Crunchyroll hosts are accepted, so that the session token can't be sent elsewhere.
+fn custom_request_url(path: &str) -> Result<String> {
+    if !path.contains("://") {
+        return Ok(format!(
+            "https://www.crunchyroll.com/{}",
+            path.trim_start_matches('/')
+        ));
+    }
+
+    let url = reqwest::Url::parse(path).map_err(|e| Error::Input {
+        message: format!("invalid url '{path}': {e}"),
+    })?;
+    let host = url.host_str().unwrap_or_default();
+    let crunchyroll_host = ["crunchyroll.com", "crunchyrollsvc.com"]
+        .iter()
+        .any(|domain| host == *domain || host.ends_with(&format!(".{domain}")));
+    if url.scheme() != "https" || !crunchyroll_host {
+        return Err(Error::Input {
+            message: format!("'{path}' is not a https url of a Crunchyroll host"),
+        });
+    }
+    Ok(url.to_string())
 }
 
 mod auth {
-    use crate::error::{check_request, Error};
+    use crate::cache::{CachedResponse, ResponseCache};
+    use crate::error::{check_request, check_response_body, request_id, Error};
+    use crate::events::{ClientEvent, EventBus};
+    use crate::internal::in_flight::InFlightRequests;
+    use crate::internal::redact::redact_url;
+    use crate::internal::retry_budget::RetryBudget;
+    use crate::internal::scheduler::{RequestScheduler, SchedulerPermit};
     use crate::{Crunchyroll, Locale, Request, Result};
     use chrono::{DateTime, Duration, Utc};
-    use reqwest::{header, Client, ClientBuilder, IntoUrl, RequestBuilder};
+    use reqwest::{header, Client, ClientBuilder, IntoUrl, Method, RequestBuilder, StatusCode};
     use serde::de::DeserializeOwned;
     use serde::{Deserialize, Serialize};
     use std::ops::Add;
@@ -175,6 +332,78 @@ mod auth {
         Anonymous,
     }
 
+    /// Details of a new account, see [`CrunchyrollBuilder::register`].
+    #[derive(Clone)]
+    pub struct Registration {
+        email: String,
+        password: String,
+        locale: Option<Locale>,
+        accept_terms: bool,
+        marketing_emails: bool,
+    }
+
+    impl std::fmt::Debug for Registration {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Registration")
+                .field("email", &self.email)
+                .field("password", &"<redacted>")
+                .field("locale", &self.locale)
+                .field("accept_terms", &self.accept_terms)
+                .field("marketing_emails", &self.marketing_emails)
+                .finish()
+        }
+    }
+
+    impl Registration {
+        /// Creates the details of a new account with the given email and password. The terms of
+        /// use must be accepted via [`Registration::accept_terms`] before it can be registered.
+        pub fn new<S: AsRef<str>>(email: S, password: S) -> Self {
+            Self {
+                email: email.as_ref().to_string(),
+                password: password.as_ref().to_string(),
+                locale: None,
+                accept_terms: false,
+                marketing_emails: false,
+            }
+        }
+
+        /// Preferred language of the account. Defaults to the locale of the builder (see
+        /// [`CrunchyrollBuilder::locale`]).
+        pub fn locale(mut self, locale: Locale) -> Self {
+            self.locale = Some(locale);
+            self
+        }
+
+        /// Accept the [terms of use](https://www.crunchyroll.com/tos) and
+        /// [privacy policy](https://www.crunchyroll.com/privacy). Required to create an account.
+        pub fn accept_terms(mut self, accept: bool) -> Self {
+            self.accept_terms = accept;
+            self
+        }
+
+        /// Opt in to marketing emails. Disabled by default.
+        pub fn marketing_emails(mut self, enable: bool) -> Self {
+            self.marketing_emails = enable;
+            self
+        }
+
+        fn validate(&self) -> Result<()> {
+            let invalid = |message: &str| {
+                Err(Error::Input {
+                    message: format!("invalid registration: {message}"),
+                })
+            };
+
+            if !self.accept_terms {
+                return invalid("the terms of use must be accepted");
+            }
+            if !self.email.contains('@') {
+                return invalid(&format!("'{}' is not a valid email", self.email));
+            }
+            Ok(())
+        }
+    }
+
     #[derive(Debug, Default, Deserialize)]
     #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
     #[cfg_attr(not(feature = "__test_strict"), serde(default))]
@@ -199,6 +428,9 @@ mod auth {
         pub(crate) access_token: String,
         pub(crate) session_token: SessionToken,
         pub(crate) session_expire: DateTime<Utc>,
+        /// If the parental control PIN was verified via [`Crunchyroll::verify_pin`] in this
+        /// session.
+        pub(crate) pin_unlocked: bool,
     }
 
     #[allow(dead_code)]
@@ -230,23 +462,208 @@ mod auth {
         pub(crate) season_number: bool,
     }
 
+    /// How requests which hit the rate limit are retried. See
+    /// [`CrunchyrollBuilder::retry_rate_limited`].
+    #[derive(Clone, Debug)]
+    pub(crate) struct RateLimitRetry {
+        pub(crate) max_retries: u32,
+        pub(crate) max_wait: std::time::Duration,
+    }
+
+    /// How `GET` api requests which failed with a transient server error are retried. See
+    /// [`CrunchyrollBuilder::retry_server_errors`].
+    #[derive(Clone, Debug)]
+    pub(crate) struct ServerErrorRetry {
+        pub(crate) max_retries: u32,
+        /// Shared by all executors derived via [`Executor::with_locale`].
+        pub(crate) budget: Arc<RetryBudget>,
+    }
+
+    /// Priority class of a request. Only has an effect if prioritization is enabled, see
+    /// [`CrunchyrollBuilder::prioritize_requests`].
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub(crate) enum RequestPriority {
+        /// Metadata requests, which are usually triggered by user interaction.
+        #[default]
+        Interactive = 0,
+        /// Requests which are needed to start playback, like stream and manifest requests.
+        #[cfg_attr(not(feature = "streaming"), allow(dead_code))]
+        Playback = 1,
+        /// Raw data downloads, like stream segments.
+        Background = 2,
+    }
+
+    /// Default timeouts of requests. See [`CrunchyrollBuilder::api_timeout`] and
+    /// [`CrunchyrollBuilder::download_timeout`].
+    #[derive(Clone, Copy, Debug, Default)]
+    pub(crate) struct RequestTimeouts {
+        /// Timeout of api (json) requests.
+        pub(crate) api: Option<std::time::Duration>,
+        /// Timeout of raw data requests, like stream segments, subtitles or images.
+        pub(crate) download: Option<std::time::Duration>,
+    }
+
+    /// Summary of a request which was sent to Crunchyroll, passed to the hook set with
+    /// [`CrunchyrollBuilder::request_hook`]. Sensitive query parameters (tokens, signatures, ...)
+    /// are redacted from the url. Headers and bodies are never included.
+    #[derive(Clone, Debug)]
+    pub struct RequestSummary {
+        pub method: Method,
+        /// Url of the request with redacted sensitive query parameters.
+        pub url: String,
+        /// Status of the response. [`None`] if the request failed before a response was received.
+        pub status: Option<StatusCode>,
+        /// Time it took until the response arrived.
+        pub latency: std::time::Duration,
+        /// Size of the response body, if known.
+        pub body_size: Option<u64>,
+    }
+
+    #[derive(Clone)]
+    pub(crate) struct RequestHook(Arc<dyn Fn(&RequestSummary) + Send + Sync>);
+
+    impl RequestHook {
+        fn call(
+            &self,
+            method: &Method,
+            url: &str,
+            start: DateTime<Utc>,
+            resp: Option<&reqwest::Response>,
+        ) {
+            (self.0)(&RequestSummary {
+                method: method.clone(),
+                url: redact_url(url),
+                status: resp.map(|resp| resp.status()),
+                latency: (Utc::now() - start).to_std().unwrap_or_default(),
+                body_size: resp.and_then(|resp| resp.content_length()),
+            })
+        }
+    }
+
+    impl std::fmt::Debug for RequestHook {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("RequestHook").finish_non_exhaustive()
+        }
+    }
+
+    #[derive(Clone)]
+    #[allow(clippy::type_complexity)]
+    pub(crate) struct ResponseTransform(Arc<dyn Fn(&str, &mut serde_json::Value) + Send + Sync>);
+
+    impl ResponseTransform {
+        fn call(&self, url: &str, value: &mut serde_json::Value) {
+            (self.0)(&redact_url(url), value)
+        }
+    }
+
+    impl std::fmt::Debug for ResponseTransform {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ResponseTransform").finish_non_exhaustive()
+        }
+    }
+
+    /// Settings of an [`Executor`] which are applied to every request.
+    #[derive(Clone, Copy, Default)]
+    struct RequestContext<'a> {
+        cache: Option<&'a ResponseCache>,
+        request_limit: Option<&'a RequestScheduler>,
+        priority: RequestPriority,
+        in_flight: Option<&'a InFlightRequests>,
+        /// Fail if a response contains unknown fields.
+        strict: bool,
+        events: Option<&'a EventBus>,
+        rate_limit_retry: Option<&'a RateLimitRetry>,
+        server_error_retry: Option<&'a ServerErrorRetry>,
+        request_hook: Option<&'a RequestHook>,
+        response_transform: Option<&'a ResponseTransform>,
+        #[cfg(feature = "metrics")]
+        metrics: Option<&'a crate::metrics::Metrics>,
+        #[cfg(feature = "replay")]
+        recorder: Option<&'a crate::internal::replay::Recorder>,
+        #[cfg(feature = "tower")]
+        middleware: Option<&'a tokio::sync::Mutex<crate::internal::tower::Middleware>>,
+    }
+
     /// Internal struct to execute all request with.
     #[derive(Debug)]
     pub struct Executor {
         pub(crate) client: Client,
 
         /// Must be a [`RwLock`] because `Executor` is always passed inside `Arc` which does not
-        /// allow direct changes to the struct.
-        pub(crate) config: RwLock<ExecutorConfig>,
+        /// allow direct changes to the struct. Wrapped in an [`Arc`] so that executors derived via
+        /// [`Executor::with_locale`] share the same session.
+        pub(crate) config: Arc<RwLock<ExecutorConfig>>,
         pub(crate) details: ExecutorDetails,
+        pub(crate) cache: Option<ResponseCache>,
+        /// Limits how many requests can be in flight at the same time.
+        pub(crate) request_limit: Option<Arc<RequestScheduler>>,
+        /// Coalesces identical `GET` requests which are in flight at the same time.
+        pub(crate) in_flight: Option<Arc<InFlightRequests>>,
+        /// Fail if a response contains unknown fields.
+        pub(crate) strict: bool,
+        pub(crate) events: EventBus,
+        pub(crate) rate_limit_retry: Option<RateLimitRetry>,
+        pub(crate) server_error_retry: Option<ServerErrorRetry>,
+        pub(crate) request_hook: Option<RequestHook>,
+        pub(crate) response_transform: Option<ResponseTransform>,
+        pub(crate) timeouts: RequestTimeouts,
+        /// Set for objects which were detached via [`Crunchyroll::detach`]. Requests fail without
+        /// being sent.
+        pub(crate) detached: bool,
+        /// Try to download raw data via http/3.
+        #[cfg(feature = "http3")]
+        pub(crate) http3_downloads: bool,
+        /// Shared by all executors derived via [`Executor::with_locale`].
+        #[cfg(feature = "metrics")]
+        pub(crate) metrics: Arc<crate::metrics::Metrics>,
+        #[cfg(feature = "replay")]
+        pub(crate) recorder: Option<crate::internal::replay::Recorder>,
+        #[cfg(feature = "object-cache")]
+        pub(crate) object_cache: Option<crate::object_cache::ObjectCache>,
 
         #[cfg(feature = "tower")]
-        pub(crate) middleware: Option<tokio::sync::Mutex<crate::internal::tower::Middleware>>,
+        pub(crate) middleware: Option<Arc<tokio::sync::Mutex<crate::internal::tower::Middleware>>>,
         #[cfg(feature = "experimental-stabilizations")]
         pub(crate) fixes: ExecutorFixes,
     }
 
     impl Executor {
+        /// Returns a new executor which uses the same session as this one but requests metadata in
+        /// the given locale.
+        pub(crate) fn with_locale(self: &Arc<Self>, locale: Locale) -> Arc<Self> {
+            Arc::new(Executor {
+                client: self.client.clone(),
+                config: self.config.clone(),
+                details: ExecutorDetails {
+                    locale,
+                    ..self.details.clone()
+                },
+                cache: self.cache.clone(),
+                request_limit: self.request_limit.clone(),
+                in_flight: self.in_flight.clone(),
+                strict: self.strict,
+                events: self.events.clone(),
+                rate_limit_retry: self.rate_limit_retry.clone(),
+                server_error_retry: self.server_error_retry.clone(),
+                request_hook: self.request_hook.clone(),
+                response_transform: self.response_transform.clone(),
+                timeouts: self.timeouts,
+                detached: self.detached,
+                #[cfg(feature = "http3")]
+                http3_downloads: self.http3_downloads,
+                #[cfg(feature = "metrics")]
+                metrics: self.metrics.clone(),
+                #[cfg(feature = "replay")]
+                recorder: self.recorder.clone(),
+                #[cfg(feature = "object-cache")]
+                object_cache: self.object_cache.clone(),
+                #[cfg(feature = "tower")]
+                middleware: self.middleware.clone(),
+                #[cfg(feature = "experimental-stabilizations")]
+                fixes: self.fixes.clone(),
+            })
+        }
+
         pub(crate) fn get<U: IntoUrl>(self: &Arc<Self>, url: U) -> ExecutorRequestBuilder {
             ExecutorRequestBuilder::new(self.clone(), self.client.get(url))
         }
@@ -267,70 +684,69 @@ mod auth {
             ExecutorRequestBuilder::new(self.clone(), self.client.delete(url))
         }
 
+        pub(crate) fn method<U: IntoUrl>(
+            self: &Arc<Self>,
+            method: Method,
+            url: U,
+        ) -> ExecutorRequestBuilder {
+            ExecutorRequestBuilder::new(self.clone(), self.client.request(method, url))
+        }
+
+        fn request_context(&self, priority: RequestPriority) -> RequestContext<'_> {
+            RequestContext {
+                cache: self.cache.as_ref(),
+                request_limit: self.request_limit.as_deref(),
+                priority,
+                in_flight: self.in_flight.as_deref(),
+                strict: self.strict,
+                events: Some(&self.events),
+                rate_limit_retry: self.rate_limit_retry.as_ref(),
+                server_error_retry: self.server_error_retry.as_ref(),
+                request_hook: self.request_hook.as_ref(),
+                response_transform: self.response_transform.as_ref(),
+                #[cfg(feature = "metrics")]
+                metrics: Some(&self.metrics),
+                #[cfg(feature = "replay")]
+                recorder: self.recorder.as_ref(),
+                #[cfg(feature = "tower")]
+                middleware: self.middleware.as_deref(),
+            }
+        }
+
         pub(crate) async fn request<T: Request + DeserializeOwned>(
             self: &Arc<Self>,
             mut req: RequestBuilder,
+            priority: RequestPriority,
         ) -> Result<T> {
+            self.check_attached()?;
             req = self.auth_req(req).await?;
             req = req.header(header::CONTENT_TYPE, "application/json");
 
-            let mut resp: T = request(
-                &self.client,
-                req,
-                #[cfg(feature = "tower")]
-                self.middleware.as_ref(),
-            )
-            .await?;
+            let mut resp: T = request(&self.client, req, self.request_context(priority)).await?;
 
             resp.__set_executor(self.clone()).await;
 
             Ok(resp)
         }
 
+        fn check_attached(&self) -> Result<()> {
+            if self.detached {
+                return Err(Error::Input {
+                    message: "the object is detached from its session, attach it again via \
+                              `Crunchyroll::attach`"
+                        .to_string(),
+                });
+            }
+            Ok(())
+        }
+
         pub(crate) async fn auth_req(
             self: &Arc<Self>,
             mut req: RequestBuilder,
         ) -> Result<RequestBuilder> {
             let mut config = self.config.write().await;
             if config.session_expire <= Utc::now() {
-                let login_response = match config.session_token.clone() {
-                    SessionToken::RefreshToken(refresh_token) => {
-                        Executor::auth_with_refresh_token(
-                            &self.client,
-                            refresh_token,
-                            self.details.device_id.clone(),
-                            self.details.device_type.clone(),
-                        )
-                        .await?
-                    }
-                    SessionToken::EtpRt(etp_rt) => {
-                        Executor::auth_with_etp_rt(
-                            &self.client,
-                            etp_rt,
-                            self.details.device_id.clone(),
-                            self.details.device_type.clone(),
-                        )
-                        .await?
-                    }
-                    SessionToken::Anonymous => Executor::auth_anonymously(&self.client).await?,
-                };
-
-                let mut new_config = config.clone();
-                new_config.token_type = login_response.token_type;
-                new_config.access_token = login_response.access_token;
-                new_config.session_token = match new_config.session_token {
-                    SessionToken::RefreshToken(_) => {
-                        SessionToken::RefreshToken(login_response.refresh_token.unwrap())
-                    }
-                    SessionToken::EtpRt(_) => {
-                        SessionToken::EtpRt(login_response.refresh_token.unwrap())
-                    }
-                    SessionToken::Anonymous => SessionToken::Anonymous,
-                };
-                new_config.session_expire = Utc::now()
-                    .add(Duration::try_seconds(login_response.expires_in as i64).unwrap());
-
-                *config = new_config;
+                self.refresh_session(&mut config).await?
             }
 
             req = req.header(
@@ -340,6 +756,64 @@ mod auth {
             Ok(req)
         }
 
+        #[cfg_attr(
+            feature = "tracing",
+            tracing::instrument(
+                name = "token_refresh",
+                level = "debug",
+                skip_all,
+                err(level = "debug")
+            )
+        )]
+        async fn refresh_session(&self, config: &mut ExecutorConfig) -> Result<()> {
+            let login_response = match config.session_token.clone() {
+                SessionToken::RefreshToken(refresh_token) => {
+                    Executor::auth_with_refresh_token(
+                        &self.client,
+                        refresh_token,
+                        self.details.device_id.clone(),
+                        self.details.device_type.clone(),
+                    )
+                    .await?
+                }
+                SessionToken::EtpRt(etp_rt) => {
+                    Executor::auth_with_etp_rt(
+                        &self.client,
+                        etp_rt,
+                        self.details.device_id.clone(),
+                        self.details.device_type.clone(),
+                    )
+                    .await?
+                }
+                SessionToken::Anonymous => Executor::auth_anonymously(&self.client).await?,
+            };
+
+            let mut new_config = config.clone();
+            new_config.token_type = login_response.token_type;
+            new_config.access_token = login_response.access_token;
+            new_config.session_token = match new_config.session_token {
+                SessionToken::RefreshToken(_) => {
+                    SessionToken::RefreshToken(login_response.refresh_token.unwrap())
+                }
+                SessionToken::EtpRt(_) => {
+                    SessionToken::EtpRt(login_response.refresh_token.unwrap())
+                }
+                SessionToken::Anonymous => SessionToken::Anonymous,
+            };
+            new_config.session_expire =
+                Utc::now().add(Duration::try_seconds(login_response.expires_in as i64).unwrap());
+            // the new session has to verify the parental control pin again
+            new_config.pin_unlocked = false;
+
+            *config = new_config;
+            #[cfg(feature = "metrics")]
+            self.metrics.record_token_refresh();
+            self.events.emit(ClientEvent::TokenRefreshed {
+                expires_at: config.session_expire,
+            });
+            Ok(())
+        }
+
         pub(crate) async fn jwt_claim<T: DeserializeOwned>(
             &self,
             claim: &str,
@@ -399,6 +873,34 @@ mod auth {
             check_request(endpoint.to_string(), resp).await
         }
 
+        async fn register(
+            client: &Client,
+            registration: &Registration,
+            locale: &Locale,
+        ) -> Result<()> {
+            let anonymous = Executor::auth_anonymously(client).await?;
+
+            let endpoint = "https://www.crunchyroll.com/accounts/v1/signup";
+            let resp = client
+                .post(endpoint)
+                .header(
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", anonymous.access_token),
+                )
+                .json(&serde_json::json!({
+                    "email": registration.email,
+                    "password": registration.password,
+                    "locale": registration.locale.as_ref().unwrap_or(locale),
+                    "terms_accepted": registration.accept_terms,
+                    "email_opt_in": registration.marketing_emails,
+                }))
+                .send()
+                .await?;
+
+            check_request::<crate::EmptyJsonProxy>(endpoint.to_string(), resp).await?;
+            Ok(())
+        }
+
         async fn auth_with_credentials(
             client: &Client,
             email: String,
@@ -518,12 +1020,13 @@ mod auth {
         fn default() -> Self {
             Self {
                 client: Client::new(),
-                config: RwLock::new(ExecutorConfig {
+                config: Arc::new(RwLock::new(ExecutorConfig {
                     token_type: "".to_string(),
                     access_token: "".to_string(),
                     session_token: SessionToken::RefreshToken("".into()),
                     session_expire: Default::default(),
-                }),
+                    pin_unlocked: false,
+                })),
                 details: ExecutorDetails {
                     locale: Default::default(),
                     preferred_audio_locale: None,
@@ -535,6 +1038,25 @@ mod auth {
                     device_id: None,
                     device_type: None,
                 },
+                cache: None,
+                request_limit: None,
+                in_flight: None,
+                strict: false,
+                events: EventBus::default(),
+                rate_limit_retry: None,
+                server_error_retry: None,
+                request_hook: None,
+                response_transform: None,
+                timeouts: RequestTimeouts::default(),
+                detached: false,
+                #[cfg(feature = "http3")]
+                http3_downloads: false,
+                #[cfg(feature = "metrics")]
+                metrics: Default::default(),
+                #[cfg(feature = "replay")]
+                recorder: None,
+                #[cfg(feature = "object-cache")]
+                object_cache: None,
                 #[cfg(feature = "tower")]
                 middleware: None,
                 #[cfg(feature = "experimental-stabilizations")]
@@ -549,11 +1071,35 @@ mod auth {
     pub(crate) struct ExecutorRequestBuilder {
         executor: Arc<Executor>,
         builder: RequestBuilder,
+        /// Overrides the default timeout of the executor.
+        timeout: Option<std::time::Duration>,
+        /// Overrides the default priority ([`RequestPriority::Interactive`] for api requests,
+        /// [`RequestPriority::Background`] for raw data requests).
+        priority: Option<RequestPriority>,
     }
 
     impl ExecutorRequestBuilder {
         pub(crate) fn new(executor: Arc<Executor>, builder: RequestBuilder) -> Self {
-            Self { executor, builder }
+            Self {
+                executor,
+                builder,
+                timeout: None,
+                priority: None,
+            }
+        }
+
+        #[cfg(feature = "streaming")]
+        pub(crate) fn priority(mut self, priority: RequestPriority) -> ExecutorRequestBuilder {
+            self.priority = Some(priority);
+
+            self
+        }
+
+        #[cfg(feature = "streaming")]
+        pub(crate) fn timeout(mut self, timeout: std::time::Duration) -> ExecutorRequestBuilder {
+            self.timeout = Some(timeout);
+
+            self
         }
 
         pub(crate) fn query<T: Serialize + ?Sized>(mut self, query: &T) -> ExecutorRequestBuilder {
@@ -581,27 +1127,114 @@ mod auth {
             self
         }
 
-        pub(crate) async fn request<T: Request + DeserializeOwned>(self) -> Result<T> {
-            self.executor.request(self.builder).await
+        pub(crate) async fn request<T: Request + DeserializeOwned>(mut self) -> Result<T> {
+            if let Some(timeout) = self.timeout.or(self.executor.timeouts.api) {
+                self.builder = self.builder.timeout(timeout)
+            }
+            self.executor
+                .request(self.builder, self.priority.unwrap_or_default())
+                .await
         }
 
-        pub(crate) async fn request_raw(mut self, auth: bool) -> Result<Vec<u8>> {
+        #[cfg_attr(
+            feature = "tracing",
+            tracing::instrument(
+                name = "request",
+                level = "debug",
+                skip_all,
+                fields(method, endpoint, status, duration_ms, bytes),
+                err(level = "debug")
+            )
+        )]
+        pub(crate) async fn request_raw(mut self, auth: bool) -> Result<bytes::Bytes> {
+            self.executor.check_attached()?;
+            if let Some(timeout) = self.timeout.or(self.executor.timeouts.download) {
+                self.builder = self.builder.timeout(timeout)
+            }
             if auth {
                 self.builder = self.executor.auth_req(self.builder).await?;
             }
 
+            let req = self.builder.build()?;
+            let (method, url) = (req.method().clone(), req.url().to_string());
+            let _permit = acquire_request_permit(
+                self.executor.request_limit.as_deref(),
+                self.priority.unwrap_or(RequestPriority::Background),
+            )
+            .await;
+            // replayed requests are scheduled like live requests
+            #[cfg(feature = "replay")]
+            if let Some(recorder) = self.executor.recorder.as_ref().filter(|r| r.is_replay()) {
+                return recorder.replay(&method, &url).await.map(bytes::Bytes::from);
+            }
+            let start = Utc::now();
+            #[cfg(feature = "tracing")]
+            crate::internal::tracing::record_request(&req);
+            let download = method == Method::GET;
+            if download {
+                self.executor.events.emit(ClientEvent::DownloadStarted {
+                    url: redact_url(&url),
+                })
+            }
+
+            #[cfg(feature = "http3")]
+            let resp = match req.try_clone().filter(|_| self.executor.http3_downloads) {
+                Some(mut h3_req) => {
+                    *h3_req.version_mut() = reqwest::Version::HTTP_3;
+                    match self.executor.client.execute(h3_req).await {
+                        Ok(resp) => Ok(resp),
+                        // the server doesn't support http/3 (or udp is blocked)
+                        Err(_) => Self::execute_raw(&self.executor, req).await,
+                    }
+                }
+                None => Self::execute_raw(&self.executor, req).await,
+            };
+            #[cfg(not(feature = "http3"))]
+            let resp = Self::execute_raw(&self.executor, req).await;
+            if let Some(request_hook) = &self.executor.request_hook {
+                request_hook.call(&method, &url, start, resp.as_ref().ok())
+            }
+            self.executor
+                .events
+                .record_response(&method, &url, resp.as_ref().ok());
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_response(
+                &self.executor.metrics,
+                &method,
+                &url,
+                start,
+                resp.as_ref().ok(),
+            );
+            let body = async {
+                let resp = resp?;
+                #[cfg(feature = "tracing")]
+                crate::internal::tracing::record_response(&resp, start);
+                Ok::<_, Error>(resp.bytes().await?)
+            }
+            .await;
+            if download {
+                self.executor.events.emit(ClientEvent::DownloadFinished {
+                    url: redact_url(&url),
+                    bytes: body.as_ref().ok().map(|body| body.len()),
+                })
+            }
+            let body = body?;
+            #[cfg(feature = "replay")]
+            if let Some(recorder) = &self.executor.recorder {
+                recorder.record(&method, &url, body.to_vec()).await?
+            }
+            Ok(body)
+        }
+
+        async fn execute_raw(
+            executor: &Executor,
+            req: reqwest::Request,
+        ) -> Result<reqwest::Response> {
             #[cfg(feature = "tower")]
-            if let Some(middleware) = &self.executor.middleware {
-                return Ok(middleware
-                    .lock()
-                    .await
-                    .call(self.builder.build()?)
-                    .await?
-                    .bytes()
-                    .await?
-                    .to_vec());
+            if let Some(middleware) = &executor.middleware {
+                return middleware.lock().await.call(req).await;
             }
-            Ok(self.builder.send().await?.bytes().await?.to_vec())
+            executor.client.execute(req).await.map_err(Error::from)
         }
     }
 
@@ -612,6 +1245,33 @@ mod auth {
         locale: Locale,
         preferred_audio_locale: Option<Locale>,
         device_identifier: Option<(String, String)>,
+        cache: Option<ResponseCache>,
+        max_concurrent_requests: Option<usize>,
+        prioritize_requests: bool,
+        deduplicate_requests: bool,
+        strict_deserialization: bool,
+        events: EventBus,
+        rate_limit_retry: Option<RateLimitRetry>,
+        server_error_retry: Option<ServerErrorRetry>,
+        request_hook: Option<RequestHook>,
+        response_transform: Option<ResponseTransform>,
+        timeouts: RequestTimeouts,
+        #[cfg(feature = "http3")]
+        http3_downloads: bool,
+        #[cfg(feature = "replay")]
+        recorder: Option<crate::internal::replay::Recorder>,
+        #[cfg(feature = "object-cache")]
+        object_cache: Option<crate::object_cache::ObjectCache>,
+        #[cfg(not(target_arch = "wasm32"))]
+        custom_client: bool,
+        #[cfg(not(target_arch = "wasm32"))]
+        dns_overrides: Vec<(String, Vec<std::net::SocketAddr>)>,
+        #[cfg(not(target_arch = "wasm32"))]
+        root_certificates: Vec<Vec<u8>>,
+        #[cfg(not(target_arch = "wasm32"))]
+        accept_invalid_certs: bool,
+        #[cfg(not(target_arch = "wasm32"))]
+        dns_resolver: Option<Arc<dyn reqwest::dns::Resolve>>,
 
         #[cfg(feature = "tower")]
         middleware: Option<tokio::sync::Mutex<crate::internal::tower::Middleware>>,
@@ -628,6 +1288,33 @@ mod auth {
                 locale: Locale::en_US,
                 preferred_audio_locale: None,
                 device_identifier: None,
+                cache: None,
+                max_concurrent_requests: None,
+                prioritize_requests: false,
+                deduplicate_requests: false,
+                strict_deserialization: false,
+                events: EventBus::default(),
+                rate_limit_retry: None,
+                server_error_retry: None,
+                request_hook: None,
+                response_transform: None,
+                timeouts: RequestTimeouts::default(),
+                #[cfg(feature = "http3")]
+                http3_downloads: false,
+                #[cfg(feature = "replay")]
+                recorder: None,
+                #[cfg(feature = "object-cache")]
+                object_cache: None,
+                #[cfg(not(target_arch = "wasm32"))]
+                custom_client: false,
+                #[cfg(not(target_arch = "wasm32"))]
+                dns_overrides: vec![],
+                #[cfg(not(target_arch = "wasm32"))]
+                root_certificates: vec![],
+                #[cfg(not(target_arch = "wasm32"))]
+                accept_invalid_certs: false,
+                #[cfg(not(target_arch = "wasm32"))]
+                dns_resolver: None,
                 #[cfg(feature = "tower")]
                 middleware: None,
                 #[cfg(feature = "experimental-stabilizations")]
@@ -639,6 +1326,72 @@ mod auth {
         }
     }
 
+    /// Makes a type erased resolver usable with [`ClientBuilder::dns_resolver`].
+    #[cfg(not(target_arch = "wasm32"))]
+    struct SharedResolver(Arc<dyn reqwest::dns::Resolve>);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl reqwest::dns::Resolve for SharedResolver {
+        fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+            self.0.resolve(name)
+        }
+    }
+
+    /// Accepts every server certificate. Only the handshake signatures are still verified. See
+    /// [`CrunchyrollBuilder::danger_accept_invalid_certs`].
+    #[cfg(all(not(target_arch = "wasm32"), feature = "rustls-tls"))]
+    #[derive(Debug)]
+    struct NoCertificateVerification(Arc<rustls::crypto::CryptoProvider>);
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "rustls-tls"))]
+    impl rustls::client::danger::ServerCertVerifier for NoCertificateVerification {
+        fn verify_server_cert(
+            &self,
+            _: &rustls::pki_types::CertificateDer<'_>,
+            _: &[rustls::pki_types::CertificateDer<'_>],
+            _: &rustls::pki_types::ServerName<'_>,
+            _: &[u8],
+            _: rustls::pki_types::UnixTime,
+        ) -> std::result::Result<rustls::client::danger::ServerCertVerified, rustls::Error>
+        {
+            Ok(rustls::client::danger::ServerCertVerified::assertion())
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            message: &[u8],
+            cert: &rustls::pki_types::CertificateDer<'_>,
+            dss: &rustls::DigitallySignedStruct,
+        ) -> std::result::Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error>
+        {
+            rustls::crypto::verify_tls12_signature(
+                message,
+                cert,
+                dss,
+                &self.0.signature_verification_algorithms,
+            )
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            message: &[u8],
+            cert: &rustls::pki_types::CertificateDer<'_>,
+            dss: &rustls::DigitallySignedStruct,
+        ) -> std::result::Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error>
+        {
+            rustls::crypto::verify_tls13_signature(
+                message,
+                cert,
+                dss,
+                &self.0.signature_verification_algorithms,
+            )
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+            self.0.signature_verification_algorithms.supported_schemes()
+        }
+    }
+
     impl CrunchyrollBuilder {
         /// Return a [`ClientBuilder`] which has all required configurations necessary to send
         /// successful requests to Crunchyroll, applied (most of the time; sometimes Crunchyroll has
@@ -646,27 +1399,95 @@ mod auth {
         /// amount everything goes back to normal and works as it should). You can use this builder
         /// to configure the behavior of the download client. Use [`CrunchyrollBuilder::client`] or
         /// to set your built client.
+        #[cfg(not(target_arch = "wasm32"))]
         pub fn predefined_client_builder() -> ClientBuilder {
-            let tls_config = rustls::ClientConfig::builder_with_provider(
-                rustls::crypto::CryptoProvider {
+            Self::predefined_client_builder_with_root_certificates(Vec::<Vec<u8>>::new()).unwrap()
+        }
+
+        /// Like [`CrunchyrollBuilder::predefined_client_builder`] but additionally trusts the given
+        /// DER encoded root certificates, e.g. of a corporate proxy. The tls backend is rustls if
+        /// the `rustls-tls` feature is enabled (with bundled root certificates), otherwise the
+        /// platform tls stack if the `native-tls` feature is enabled.
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn predefined_client_builder_with_root_certificates<C: AsRef<[u8]>>(
+            certificates: impl IntoIterator<Item = C>,
+        ) -> Result<ClientBuilder> {
+            Self::tls_client_builder(certificates, false)
+        }
+
+        /// Build the predefined client builder. If `accept_invalid_certs` is set, certificates of
+        /// the server aren't verified at all.
+        #[cfg(not(target_arch = "wasm32"))]
+        fn tls_client_builder<C: AsRef<[u8]>>(
+            certificates: impl IntoIterator<Item = C>,
+            accept_invalid_certs: bool,
+        ) -> Result<ClientBuilder> {
+            let builder = Client::builder()
+                .https_only(true)
+                .cookie_store(true)
+                .user_agent(crate::crunchyroll::USER_AGENT);
+
+            #[cfg(feature = "rustls-tls")]
+            {
+                let mut root_store = rustls::RootCertStore {
+                    roots: webpki_roots::TLS_SERVER_ROOTS.into(),
+                };
+                for certificate in certificates {
+                    root_store
+                        .add(certificate.as_ref().to_vec().into())
+                        .map_err(|e| Error::Input {
+                            message: format!("invalid root certificate: {e}"),
+                        })?
+                }
+
+                let provider = Arc::new(rustls::crypto::CryptoProvider {
                     cipher_suites: rustls::crypto::ring::DEFAULT_CIPHER_SUITES.to_vec(),
                     kx_groups: vec![rustls::crypto::ring::kx_group::X25519],
                     ..rustls::crypto::ring::default_provider()
+                });
+                let mut tls_config = rustls::ClientConfig::builder_with_provider(provider.clone())
+                    .with_protocol_versions(&[&rustls::version::TLS12, &rustls::version::TLS13])
+                    .unwrap()
+                    .with_root_certificates(root_store)
+                    .with_no_client_auth();
+                if accept_invalid_certs {
+                    tls_config
+                        .dangerous()
+                        .set_certificate_verifier(Arc::new(NoCertificateVerification(provider)))
                 }
-                .into(),
-            )
-            .with_protocol_versions(&[&rustls::version::TLS12, &rustls::version::TLS13])
-            .unwrap()
-            .with_root_certificates(rustls::RootCertStore {
-                roots: webpki_roots::TLS_SERVER_ROOTS.into(),
-            })
-            .with_no_client_auth();
 
-            Client::builder()
-                .https_only(true)
-                .cookie_store(true)
-                .user_agent("Crunchyroll/1.8.0 Nintendo Switch/12.3.12.0 UE4/4.27")
-                .use_preconfigured_tls(tls_config)
+                Ok(builder.use_preconfigured_tls(tls_config))
+            }
+            #[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+            {
+                let mut builder = builder
+                    .use_native_tls()
+                    .danger_accept_invalid_certs(accept_invalid_certs);
+                for certificate in certificates {
+                    builder = builder
+                        .add_root_certificate(reqwest::Certificate::from_der(certificate.as_ref())?)
+                }
+                Ok(builder)
+            }
+            #[cfg(not(any(feature = "rustls-tls", feature = "native-tls")))]
+            {
+                if certificates.into_iter().next().is_some() || accept_invalid_certs {
+                    return Err(Error::Input {
+                        message:
+                            "certificate options require the 'rustls-tls' or 'native-tls' feature"
+                                .to_string(),
+                    });
+                }
+                Ok(builder)
+            }
+        }
+
+        /// Return a [`ClientBuilder`] which has all required configurations necessary to send
+        /// successful requests to Crunchyroll applied. On wasm, tls and cookies are handled by the
+        /// browser / runtime, so only the user agent is set.
+        #[cfg(target_arch = "wasm32")]
+        pub fn predefined_client_builder() -> ClientBuilder {
+            Client::builder().user_agent(crate::crunchyroll::USER_AGENT)
         }
 
         /// Set a custom client that will be used in all api requests.
@@ -675,6 +1496,63 @@ mod auth {
         /// which may be needed to make successful requests to Crunchyroll.
         pub fn client(mut self, client: Client) -> CrunchyrollBuilder {
             self.client = client;
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                self.custom_client = true;
+            }
+            self
+        }
+
+        /// Resolve `host` to the given addresses instead of using dns, e.g. to pin a specific CDN
+        /// edge. Applies to api as well as download (stream segments, subtitles, ...) requests.
+        /// Can be called multiple times for different hosts. Can't be combined with
+        /// [`CrunchyrollBuilder::client`], use [`ClientBuilder::resolve_to_addrs`] on your client
+        /// builder instead.
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn resolve<S: AsRef<str>>(
+            mut self,
+            host: S,
+            addresses: impl IntoIterator<Item = std::net::SocketAddr>,
+        ) -> CrunchyrollBuilder {
+            self.dns_overrides
+                .push((host.as_ref().to_string(), addresses.into_iter().collect()));
+            self
+        }
+
+        /// Use a custom dns resolver for all hosts which aren't overridden via
+        /// [`CrunchyrollBuilder::resolve`]. Applies to api as well as download requests. Can't be
+        /// combined with [`CrunchyrollBuilder::client`], use [`ClientBuilder::dns_resolver`] on
+        /// your client builder instead.
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn dns_resolver<R: reqwest::dns::Resolve + 'static>(
+            mut self,
+            resolver: Arc<R>,
+        ) -> CrunchyrollBuilder {
+            self.dns_resolver = Some(resolver);
+            self
+        }
+
+        /// Additionally trust the given DER encoded root certificate, e.g. of a corporate proxy or
+        /// a local debugging proxy like mitmproxy. Can be called multiple times. Requires the
+        /// `rustls-tls` or `native-tls` feature. Can't be combined with
+        /// [`CrunchyrollBuilder::client`], use
+        /// [`CrunchyrollBuilder::predefined_client_builder_with_root_certificates`] for your client
+        /// builder instead.
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn root_certificate(mut self, certificate: impl Into<Vec<u8>>) -> CrunchyrollBuilder {
+            self.root_certificates.push(certificate.into());
+            self
+        }
+
+        /// **DANGEROUS**: Accept every server certificate, including self-signed, expired and
+        /// certificates for other hosts. This disables the protection against man-in-the-middle
+        /// attacks completely, so your credentials and session can be stolen by anyone on the
+        /// network path. Only use it for local debugging (e.g. with mitmproxy) and prefer
+        /// [`CrunchyrollBuilder::root_certificate`] whenever possible. Requires the `rustls-tls` or
+        /// `native-tls` feature. Can't be combined with [`CrunchyrollBuilder::client`].
+        #[cfg(not(target_arch = "wasm32"))]
+        pub fn danger_accept_invalid_certs(mut self, enable: bool) -> CrunchyrollBuilder {
+            self.accept_invalid_certs = enable;
             self
         }
 
@@ -711,6 +1589,179 @@ mod auth {
             self
         }
 
+        /// Cache responses of metadata requests. See [`ResponseCache`] for more information.
+        pub fn cache(mut self, cache: ResponseCache) -> CrunchyrollBuilder {
+            self.cache = Some(cache);
+            self
+        }
+
+        /// Cache series, seasons, episodes, movie listings and movies which are requested by their
+        /// id (e.g. via [`Crunchyroll::media_from_id`]) on disk. See
+        /// [`crate::object_cache::ObjectCache`] for more information.
+        #[cfg(feature = "object-cache")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "object-cache")))]
+        pub fn object_cache(
+            mut self,
+            object_cache: crate::object_cache::ObjectCache,
+        ) -> CrunchyrollBuilder {
+            self.object_cache = Some(object_cache);
+            self
+        }
+
+        /// Set how many api requests can be in flight at the same time. Further requests wait until
+        /// a previous one has finished. Useful if many requests are made concurrently (e.g. when
+        /// requesting hundreds of episodes at once) which would otherwise trigger the rate limit.
+        /// By default, the amount of concurrent requests is unlimited. `0` is treated as `1`.
+        pub fn max_concurrent_requests(mut self, max: usize) -> CrunchyrollBuilder {
+            self.max_concurrent_requests = Some(max);
+            self
+        }
+
+        /// If a request limit is set via [`CrunchyrollBuilder::max_concurrent_requests`], let waiting
+        /// requests with a higher priority go first instead of handing out free slots in the order
+        /// they were requested. Metadata requests have the highest priority, followed by playback
+        /// requests (streams and stream manifests) and raw data downloads (stream segments,
+        /// subtitles, images, ...). This keeps e.g. a UI responsive which uses the same client as a
+        /// background downloader. Disabled by default.
+        pub fn prioritize_requests(mut self, enable: bool) -> CrunchyrollBuilder {
+            self.prioritize_requests = enable;
+            self
+        }
+
+        /// Coalesce identical api `GET` requests which are in flight at the same time (e.g. if the
+        /// same series is requested by multiple tasks concurrently) into a single request whose
+        /// result is shared with all callers. Disabled by default.
+        pub fn deduplicate_requests(mut self, enable: bool) -> CrunchyrollBuilder {
+            self.deduplicate_requests = enable;
+            self
+        }
+
+        /// Fail if an api response contains fields which aren't known by this crate. The returned
+        /// [`Error::Decode`] names the field(s) which caused the error. This is useful to detect
+        /// api changes early, e.g. in CI. Missing fields can't be detected because most fields are
+        /// optional or have a default value. Fields which are captured by the `extra-fields` feature
+        /// aren't reported either. Disabled by default.
+        pub fn strict_deserialization(mut self, enable: bool) -> CrunchyrollBuilder {
+            self.strict_deserialization = enable;
+            self
+        }
+
+        /// Subscribe to the events of the client which will be created by this builder, including
+        /// [`ClientEvent::LoggedIn`]. See [`Crunchyroll::events`].
+        pub fn events(&self) -> futures_util::stream::BoxStream<'static, ClientEvent> {
+            self.events.subscribe()
+        }
+
+        /// Set the timeout of api requests (metadata, search, ...). Overrides the timeout of the
+        /// [`Client`] for these requests. By default, the timeout of the [`Client`] is used.
+        pub fn api_timeout(mut self, timeout: std::time::Duration) -> CrunchyrollBuilder {
+            self.timeouts.api = Some(timeout);
+            self
+        }
+
+        /// Set the timeout of raw data downloads, like [`crate::media::StreamSegment::data`],
+        /// [`crate::media::Subtitle::data`] or [`crate::common::Image::download`]. Overrides the
+        /// timeout of the [`Client`] for these requests, so that a short timeout can be used for
+        /// api requests without aborting large segment downloads. By default, the timeout of the
+        /// [`Client`] is used. Can be overridden per segment with
+        /// [`crate::media::StreamSegment::data_with_timeout`].
+        pub fn download_timeout(mut self, timeout: std::time::Duration) -> CrunchyrollBuilder {
+            self.timeouts.download = Some(timeout);
+            self
+        }
+
+        /// Download raw data, like [`crate::media::StreamSegment::data`], via http/3 (QUIC). This
+        /// can improve the throughput on lossy networks. If a server doesn't support http/3, the
+        /// request is sent again via http/1.1 or http/2. Api requests are not affected. The
+        /// [`Client`] must be built with http/3 support, which is the case for
+        /// [`CrunchyrollBuilder::predefined_client_builder`]. Disabled by default.
+        #[cfg(feature = "http3")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "http3")))]
+        pub fn http3_downloads(mut self, enable: bool) -> CrunchyrollBuilder {
+            self.http3_downloads = enable;
+            self
+        }
+
+        /// Automatically retry requests which hit the rate limit (`429 Too Many Requests`) up to
+        /// `max_retries` times. Before every retry, it's waited as long as Crunchyroll demands via
+        /// the `Retry-After` header (or `2^attempt` seconds if the header is missing), but never
+        /// longer than `max_wait`. By default, rate limited requests are not retried. Not supported
+        /// on wasm.
+        pub fn retry_rate_limited(
+            mut self,
+            max_retries: u32,
+            max_wait: std::time::Duration,
+        ) -> CrunchyrollBuilder {
+            self.rate_limit_retry = Some(RateLimitRetry {
+                max_retries,
+                max_wait,
+            });
+            self
+        }
+
+        /// Automatically retry `GET` api requests which failed with a transient server error (`500`,
+        /// `502`, `503` or `504`) up to `max_retries` times, waiting `2^attempt` seconds (starting
+        /// at 500ms) before every retry. Retries are limited by a budget which is shared by all
+        /// requests of the session: every request adds `budget_ratio` (`0.0` - `1.0`) to the budget
+        /// and every retry consumes `1.0` of it, so e.g. `0.1` means that at most about 10% of all
+        /// requests are retries (plus a small initial reserve). This prevents retry storms from
+        /// amplifying an outage. By default, server errors are not retried. Not supported on wasm.
+        pub fn retry_server_errors(
+            mut self,
+            max_retries: u32,
+            budget_ratio: f64,
+        ) -> CrunchyrollBuilder {
+            self.server_error_retry = Some(ServerErrorRetry {
+                max_retries,
+                budget: Arc::new(RetryBudget::new(budget_ratio, 10)),
+            });
+            self
+        }
+
+        /// Call `hook` after every request which was sent to Crunchyroll with a summary of it (see
+        /// [`RequestSummary`]). Useful for audit logs or debugging without a custom http client.
+        pub fn request_hook(
+            mut self,
+            hook: impl Fn(&RequestSummary) + Send + Sync + 'static,
+        ) -> CrunchyrollBuilder {
+            self.request_hook = Some(RequestHook(Arc::new(hook)));
+            self
+        }
+
+        /// Call `transform` with every json api response before it gets deserialized. The first
+        /// argument is the url of the request (with sensitive query parameters redacted, see
+        /// [`RequestSummary::url`]), the second the response which can be modified in place. Useful
+        /// to work around upstream api quirks (e.g. patch known-bad fields or normalize timestamps)
+        /// without forking this crate. Raw data downloads (stream segments, subtitles, images, ...)
+        /// are not affected.
+        pub fn response_transform(
+            mut self,
+            transform: impl Fn(&str, &mut serde_json::Value) + Send + Sync + 'static,
+        ) -> CrunchyrollBuilder {
+            self.response_transform = Some(ResponseTransform(Arc::new(transform)));
+            self
+        }
+
+        /// Record all api responses to `directory`. The recording can be replayed later with
+        /// [`CrunchyrollBuilder::login_with_recording`], e.g. to write deterministic tests which
+        /// don't need any credentials. Only response bodies and non-secret session information
+        /// (account id and account benefits) are stored, request headers are never written to
+        /// disk. Tokens (e.g. stream session tokens) and url signatures in the request urls and
+        /// json response bodies are replaced with `REDACTED`. Raw data like subtitles or stream
+        /// segments is stored as is.
+        #[cfg(feature = "replay")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "replay")))]
+        pub fn record_responses(
+            mut self,
+            directory: impl Into<std::path::PathBuf>,
+        ) -> CrunchyrollBuilder {
+            self.recorder = Some(crate::internal::replay::Recorder::new(
+                crate::internal::replay::RecorderMode::Record,
+                directory.into(),
+            ));
+            self
+        }
+
         /// Adds a [tower](https://docs.rs/tower/latest/tower/) middleware which is called on every
         /// request.
         #[cfg(feature = "tower")]
@@ -757,7 +1808,7 @@ mod auth {
 
         /// Login without an account. This is just like if you would visit crunchyroll.com without
         /// an account. Some functions won't work if logged in with this method.
-        pub async fn login_anonymously(self) -> Result<Crunchyroll> {
+        pub async fn login_anonymously(mut self) -> Result<Crunchyroll> {
             self.pre_login().await?;
 
             let login_response = Executor::auth_anonymously(&self.client).await?;
@@ -768,16 +1819,45 @@ mod auth {
 
         /// Logs in with credentials (email and password) and returns a new `Crunchyroll` instance.
         pub async fn login_with_credentials<S: AsRef<str>>(
-            self,
+            mut self,
             email: S,
             password: S,
         ) -> Result<Crunchyroll> {
             self.pre_login().await?;
+            self.credentials_login(email.as_ref(), password.as_ref())
+                .await
+        }
+
+        /// Creates a new account and logs in with it. Fails with [`Error::Input`] if the
+        /// registration is invalid (e.g. the terms of use aren't accepted) and with an api error
+        /// if the email is already in use.
+        ///
+        /// ```no_run
+        /// use crunchyroll_rs::crunchyroll::Registration;
+        /// use crunchyroll_rs::Crunchyroll;
+        ///
+        /// # async fn f() -> Result<(), crunchyroll_rs::error::Error> {
+        /// let crunchy = Crunchyroll::builder()
+        ///     .register(Registration::new("me@example.com", "password").accept_terms(true))
+        ///     .await?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub async fn register(mut self, registration: Registration) -> Result<Crunchyroll> {
+            registration.validate()?;
+            self.pre_login().await?;
+
+            Executor::register(&self.client, &registration, &self.locale).await?;
+            self.credentials_login(&registration.email, &registration.password)
+                .await
+        }
 
+        /// Logs in with credentials. `pre_login` must have been called before.
+        async fn credentials_login(self, email: &str, password: &str) -> Result<Crunchyroll> {
             let login_response = Executor::auth_with_credentials(
                 &self.client,
-                email.as_ref().to_string(),
-                password.as_ref().to_string(),
+                email.to_string(),
+                password.to_string(),
                 self.device_identifier
                     .as_ref()
                     .map(|(device_id, _)| device_id.clone()),
@@ -799,7 +1879,7 @@ mod auth {
         /// internal they're different. I had issues when I tried to log in with the refresh token
         /// on [`CrunchyrollBuilder::login_with_etp_rt`] and vice versa.
         pub async fn login_with_refresh_token<S: AsRef<str>>(
-            self,
+            mut self,
             refresh_token: S,
         ) -> Result<Crunchyroll> {
             self.pre_login().await?;
@@ -829,7 +1909,7 @@ mod auth {
         /// error. Idk why, but these endpoints can only be used if the authentication is anything
         /// other than [`CrunchyrollBuilder::login_with_refresh_token_profile_id`].
         pub async fn login_with_refresh_token_profile_id<S: AsRef<str>>(
-            self,
+            mut self,
             refresh_token: S,
             profile_id: S,
         ) -> Result<Crunchyroll> {
@@ -859,7 +1939,7 @@ mod auth {
         /// [`CrunchyrollBuilder::login_with_refresh_token`] are having the same syntax, Crunchyroll
         /// internal they're different. I had issues when I tried to log in with the `etp_rt`
         /// cookie on [`CrunchyrollBuilder::login_with_refresh_token`] and vice versa.
-        pub async fn login_with_etp_rt<S: AsRef<str>>(self, etp_rt: S) -> Result<Crunchyroll> {
+        pub async fn login_with_etp_rt<S: AsRef<str>>(mut self, etp_rt: S) -> Result<Crunchyroll> {
             self.pre_login().await?;
 
             let login_response = Executor::auth_with_etp_rt(
@@ -878,7 +1958,130 @@ mod auth {
             self.post_login(login_response, session_token).await
         }
 
-        async fn pre_login(&self) -> Result<()> {
+        /// Creates a `Crunchyroll` instance which answers all requests with the responses recorded
+        /// via [`CrunchyrollBuilder::record_responses`] instead of sending them. No network
+        /// requests are made. Requests which weren't recorded return an [`Error::Input`]. Make
+        /// sure to use the same [`CrunchyrollBuilder::locale`] and
+        /// [`CrunchyrollBuilder::preferred_audio_locale`] as when the responses were recorded,
+        /// otherwise the request urls won't match.
+        #[cfg(feature = "replay")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "replay")))]
+        pub async fn login_with_recording(
+            self,
+            directory: impl Into<std::path::PathBuf>,
+        ) -> Result<Crunchyroll> {
+            let recorder = crate::internal::replay::Recorder::new(
+                crate::internal::replay::RecorderMode::Replay,
+                directory.into(),
+            );
+            let session = recorder.read_session()?;
+
+            // the access token is only read to get the account benefits, so a token which only
+            // contains them is sufficient
+            let access_token = jsonwebtoken::encode(
+                &jsonwebtoken::Header::default(),
+                &serde_json::json!({
+                    "benefits": session.benefits,
+                    "exp": DateTime::<Utc>::MAX_UTC.timestamp()
+                }),
+                &jsonwebtoken::EncodingKey::from_secret(b"replay"),
+            )
+            .map_err(|e| Error::Internal {
+                message: e.to_string(),
+            })?;
+
+            let crunchy = Crunchyroll {
+                executor: Arc::new(Executor {
+                    client: self.client,
+                    config: Arc::new(RwLock::new(ExecutorConfig {
+                        token_type: "Bearer".to_string(),
+                        access_token,
+                        session_token: SessionToken::Anonymous,
+                        session_expire: DateTime::<Utc>::MAX_UTC,
+                        pin_unlocked: false,
+                    })),
+                    details: ExecutorDetails {
+                        locale: self.locale,
+                        preferred_audio_locale: self.preferred_audio_locale,
+                        bucket: session.bucket,
+                        signature: String::new(),
+                        policy: String::new(),
+                        key_pair_id: String::new(),
+                        account_id: session.account_id.ok_or_else(|| Error::Authentication {
+                            message: "Login with a user account to use this function".to_string(),
+                        }),
+                        device_id: None,
+                        device_type: None,
+                    },
+                    cache: self.cache,
+                    request_limit: self
+                        .max_concurrent_requests
+                        .map(|max| Arc::new(RequestScheduler::new(max, self.prioritize_requests))),
+                    in_flight: self.deduplicate_requests.then(Default::default),
+                    strict: self.strict_deserialization,
+                    events: self.events.clone(),
+                    rate_limit_retry: self.rate_limit_retry,
+                    server_error_retry: self.server_error_retry,
+                    request_hook: self.request_hook,
+                    response_transform: self.response_transform,
+                    timeouts: self.timeouts,
+                    detached: false,
+                    #[cfg(feature = "http3")]
+                    http3_downloads: self.http3_downloads,
+                    #[cfg(feature = "metrics")]
+                    metrics: Default::default(),
+                    recorder: Some(recorder),
+                    #[cfg(feature = "object-cache")]
+                    object_cache: self.object_cache,
+                    #[cfg(feature = "tower")]
+                    middleware: None,
+                    #[cfg(feature = "experimental-stabilizations")]
+                    fixes: self.fixes,
+                }),
+            };
+            crunchy.executor.events.emit(ClientEvent::LoggedIn {
+                account_id: crunchy.executor.details.account_id.clone().ok(),
+            });
+            Ok(crunchy)
+        }
+
+        /// Rebuild the predefined client if dns overrides, a custom dns resolver or certificate
+        /// options are set.
+        #[cfg(not(target_arch = "wasm32"))]
+        fn apply_client_options(&mut self) -> Result<()> {
+            if self.dns_overrides.is_empty()
+                && self.dns_resolver.is_none()
+                && self.root_certificates.is_empty()
+                && !self.accept_invalid_certs
+            {
+                return Ok(());
+            }
+            if self.custom_client {
+                return Err(Error::Input {
+                    message: "dns and certificate options can't be applied to a custom client, configure them on the client builder instead".to_string(),
+                });
+            }
+
+            if self.accept_invalid_certs {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("certificate verification is disabled, connections are insecure");
+            }
+            let mut builder =
+                Self::tls_client_builder(&self.root_certificates, self.accept_invalid_certs)?;
+            for (host, addresses) in &self.dns_overrides {
+                builder = builder.resolve_to_addrs(host, addresses)
+            }
+            if let Some(resolver) = self.dns_resolver.clone() {
+                builder = builder.dns_resolver(Arc::new(SharedResolver(resolver)))
+            }
+            self.client = builder.build()?;
+            Ok(())
+        }
+
+        async fn pre_login(&mut self) -> Result<()> {
+            #[cfg(not(target_arch = "wasm32"))]
+            self.apply_client_options()?;
+
             // Request the index page to set cookies which are required to bypass the cloudflare bot
             // check
             self.client
@@ -915,9 +2118,7 @@ mod auth {
                 default_marketing_opt_in: bool,
                 service_available: bool,
 
-                #[cfg(feature = "__test_strict")]
                 cms: crate::StrictValue,
-                #[cfg(feature = "__test_strict")]
                 cms_beta: crate::StrictValue,
             }
 
@@ -931,8 +2132,17 @@ mod auth {
             let index: IndexResp = request(
                 &self.client,
                 index_req,
-                #[cfg(feature = "tower")]
-                self.middleware.as_ref(),
+                RequestContext {
+                    strict: self.strict_deserialization,
+                    events: Some(&self.events),
+                    rate_limit_retry: self.rate_limit_retry.as_ref(),
+                    server_error_retry: self.server_error_retry.as_ref(),
+                    request_hook: self.request_hook.as_ref(),
+                    response_transform: self.response_transform.as_ref(),
+                    #[cfg(feature = "tower")]
+                    middleware: self.middleware.as_ref(),
+                    ..Default::default()
+                },
             )
             .await?;
 
@@ -940,13 +2150,14 @@ mod auth {
                 executor: Arc::new(Executor {
                     client: self.client,
 
-                    config: RwLock::new(ExecutorConfig {
+                    config: Arc::new(RwLock::new(ExecutorConfig {
                         token_type: login_response.token_type,
                         access_token: login_response.access_token,
                         session_token,
                         session_expire: Utc::now()
                             .add(Duration::try_seconds(login_response.expires_in as i64).unwrap()),
-                    }),
+                        pin_unlocked: false,
+                    })),
                     details: ExecutorDetails {
                         locale: self.locale,
                         preferred_audio_locale: self.preferred_audio_locale,
@@ -978,51 +2189,111 @@ mod auth {
                             .as_ref()
                             .map(|(_, device_type)| device_type.clone()),
                     },
+                    cache: self.cache,
+                    request_limit: self
+                        .max_concurrent_requests
+                        .map(|max| Arc::new(RequestScheduler::new(max, self.prioritize_requests))),
+                    in_flight: self.deduplicate_requests.then(Default::default),
+                    strict: self.strict_deserialization,
+                    events: self.events.clone(),
+                    rate_limit_retry: self.rate_limit_retry,
+                    server_error_retry: self.server_error_retry,
+                    request_hook: self.request_hook,
+                    response_transform: self.response_transform,
+                    timeouts: self.timeouts,
+                    detached: false,
+                    #[cfg(feature = "http3")]
+                    http3_downloads: self.http3_downloads,
+                    #[cfg(feature = "metrics")]
+                    metrics: Default::default(),
+                    #[cfg(feature = "replay")]
+                    recorder: self.recorder,
+                    #[cfg(feature = "object-cache")]
+                    object_cache: self.object_cache,
                     #[cfg(feature = "tower")]
-                    middleware: self.middleware,
+                    middleware: self.middleware.map(Arc::new),
                     #[cfg(feature = "experimental-stabilizations")]
                     fixes: self.fixes,
                 }),
             };
 
+            #[cfg(feature = "replay")]
+            if let Some(recorder) = &crunchy.executor.recorder {
+                recorder.write_session(&crate::internal::replay::RecordedSession {
+                    account_id: crunchy.executor.details.account_id.clone().ok(),
+                    benefits: crunchy
+                        .executor
+                        .jwt_claim("benefits")
+                        .await?
+                        .unwrap_or_default(),
+                    bucket: crunchy.executor.details.bucket.clone(),
+                })?
+            }
+
+            crunchy.executor.events.emit(ClientEvent::LoggedIn {
+                account_id: crunchy.executor.details.account_id.clone().ok(),
+            });
             Ok(crunchy)
         }
     }
 
-    /// Make a request from the provided builder.
+    /// Make a request from the provided builder. If [`RequestContext::strict`] is set, the request
+    /// fails if the response contains fields which aren't used by `T`.
     async fn request<T: Request + DeserializeOwned>(
         client: &Client,
         req: RequestBuilder,
-        #[cfg(feature = "tower")] middleware: Option<
-            &tokio::sync::Mutex<crate::internal::tower::Middleware>,
-        >,
+        ctx: RequestContext<'_>,
     ) -> Result<T> {
         let built_req = req.build()?;
-        let url = built_req.url().to_string();
-        #[cfg(not(feature = "tower"))]
-        let resp = client.execute(built_req).await?;
-        #[cfg(feature = "tower")]
-        let resp = {
-            use std::ops::DerefMut;
-            if let Some(middleware) = middleware {
-                middleware.lock().await.deref_mut().call(built_req).await?
-            } else {
-                client.execute(built_req).await?
-            }
-        };
 
         #[cfg(not(feature = "__test_strict"))]
         {
-            check_request(url, resp).await
+            if !ctx.strict && ctx.response_transform.is_none() {
+                return send_request_deduplicated(client, built_req, ctx).await;
+            }
+
+            let url = built_req.url().to_string();
+            let mut value: serde_json::Value =
+                send_request_deduplicated(client, built_req, ctx).await?;
+            if let Some(response_transform) = ctx.response_transform {
+                response_transform.call(&url, &mut value)
+            }
+            if !ctx.strict {
+                return serde_json::from_value(value.clone()).map_err(|e| Error::Decode {
+                    message: e.to_string(),
+                    content: value.to_string().into_bytes(),
+                    url,
+                });
+            }
+            crate::internal::strict::from_value_strict(value.clone()).map_err(|message| {
+                Error::Decode {
+                    message,
+                    content: value.to_string().into_bytes(),
+                    url,
+                }
+            })
         }
         #[cfg(feature = "__test_strict")]
         {
-            let result = check_request(url.clone(), resp).await?;
+            let url = built_req.url().to_string();
+            let result = send_request_deduplicated(client, built_req, ctx).await?;
 
             let cleaned = clean_request(result);
-            let value = serde_json::Value::deserialize(serde::de::value::MapDeserializer::new(
-                cleaned.into_iter(),
-            ))?;
+            let mut value = serde_json::Value::deserialize(
+                serde::de::value::MapDeserializer::new(cleaned.into_iter()),
+            )?;
+            if let Some(response_transform) = ctx.response_transform {
+                response_transform.call(&url, &mut value)
+            }
+            if ctx.strict {
+                return crate::internal::strict::from_value_strict(value.clone()).map_err(
+                    |message| Error::Decode {
+                        message,
+                        content: value.to_string().into_bytes(),
+                        url,
+                    },
+                );
+            }
             serde_json::from_value(value.clone()).map_err(|e| Error::Decode {
                 message: format!("{} at {}:{}", e, e.line(), e.column()),
                 content: value.to_string().into_bytes(),
@@ -1031,6 +2302,245 @@ mod auth {
         }
     }
 
+    /// Like [`send_request_with_retries`] but waits for the result of an identical in-flight
+    /// request instead of sending a new one, if request deduplication is enabled.
+    async fn send_request_deduplicated<T: DeserializeOwned>(
+        client: &Client,
+        req: reqwest::Request,
+        ctx: RequestContext<'_>,
+    ) -> Result<T> {
+        let Some(in_flight) = ctx.in_flight.filter(|_| req.method() == Method::GET) else {
+            return send_request_with_retries(client, req, ctx).await;
+        };
+
+        let url = req.url().to_string();
+        let value = in_flight
+            .run(url.clone(), send_request_with_retries(client, req, ctx))
+            .await?;
+        serde_json::from_value(value.clone()).map_err(|e| Error::Decode {
+            message: e.to_string(),
+            content: value.to_string().into_bytes(),
+            url,
+        })
+    }
+
+    /// Like [`send_request`] but retries the request if it hit the rate limit or failed with a
+    /// transient server error and a retry policy is set.
+    async fn send_request_with_retries<T: DeserializeOwned>(
+        client: &Client,
+        req: reqwest::Request,
+        ctx: RequestContext<'_>,
+    ) -> Result<T> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if ctx.rate_limit_retry.is_some() || ctx.server_error_retry.is_some() {
+            let server_error_retry = ctx
+                .server_error_retry
+                .filter(|_| req.method() == Method::GET);
+            if let Some(server_error_retry) = server_error_retry {
+                server_error_retry.budget.deposit()
+            }
+
+            let mut rate_limit_attempt = 0;
+            let mut server_error_attempt = 0;
+            // requests with a streaming body can't be cloned and thus not retried
+            while let Some(retry_req) = req.try_clone() {
+                match send_request(client, retry_req, ctx).await {
+                    Err(err)
+                        if ctx.rate_limit_retry.is_some_and(|rate_limit_retry| {
+                            rate_limit_attempt < rate_limit_retry.max_retries
+                        }) && err.status() == Some(StatusCode::TOO_MANY_REQUESTS) =>
+                    {
+                        let wait = err
+                            .details()
+                            .and_then(|details| details.retry_after)
+                            .map(|secs| std::time::Duration::from_secs(secs as u64))
+                            .unwrap_or_else(|| {
+                                std::time::Duration::from_secs(1 << rate_limit_attempt.min(16))
+                            });
+                        let max_wait = ctx.rate_limit_retry.unwrap().max_wait;
+                        tokio::time::sleep(wait.min(max_wait)).await;
+                        rate_limit_attempt += 1;
+                    }
+                    Err(err)
+                        if server_error_retry.is_some_and(|server_error_retry| {
+                            server_error_attempt < server_error_retry.max_retries
+                                && is_transient_server_error(&err)
+                                && server_error_retry.budget.withdraw()
+                        }) =>
+                    {
+                        let wait =
+                            std::time::Duration::from_millis(500 << server_error_attempt.min(16));
+                        tokio::time::sleep(wait).await;
+                        server_error_attempt += 1;
+                    }
+                    result => return result,
+                }
+            }
+        }
+        send_request(client, req, ctx).await
+    }
+
+    /// If the request failed with a server error which is likely gone if the request is retried.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn is_transient_server_error(err: &Error) -> bool {
+        matches!(
+            err.status(),
+            Some(
+                StatusCode::INTERNAL_SERVER_ERROR
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            )
+        )
+    }
+
+    /// Send a request and decode the response. If a cache is given, `GET` requests are answered /
+    /// revalidated with the cached response.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "request",
+            level = "debug",
+            skip_all,
+            fields(method, endpoint, status, duration_ms, bytes, cache),
+            err(level = "debug")
+        )
+    )]
+    async fn send_request<T: DeserializeOwned>(
+        client: &Client,
+        mut req: reqwest::Request,
+        ctx: RequestContext<'_>,
+    ) -> Result<T> {
+        let url = req.url().to_string();
+        let method = req.method().clone();
+        let start = Utc::now();
+        #[cfg(feature = "tracing")]
+        crate::internal::tracing::record_request(&req);
+
+        #[cfg(feature = "replay")]
+        if let Some(recorder) = ctx.recorder.filter(|recorder| recorder.is_replay()) {
+            // replayed requests are scheduled like live requests
+            let _permit = acquire_request_permit(ctx.request_limit, ctx.priority).await;
+            let body = recorder.replay(&method, &url).await?;
+            return check_response_body(url, &StatusCode::OK, None, body.into());
+        }
+
+        let cache = ctx.cache.filter(|_| method == Method::GET);
+        let cached = match cache {
+            Some(cache) => cache.storage.get(&url).await,
+            None => None,
+        };
+        if let Some(cached) = &cached {
+            if cached.is_fresh() {
+                #[cfg(feature = "tracing")]
+                crate::internal::tracing::record_cache("hit");
+                return check_response_body(
+                    url,
+                    &StatusCode::OK,
+                    None,
+                    cached.body.as_slice().into(),
+                );
+            }
+            cached.apply_validators(req.headers_mut());
+        }
+
+        // the permit is held until the response body is read
+        let _permit = acquire_request_permit(ctx.request_limit, ctx.priority).await;
+        #[cfg(not(feature = "tower"))]
+        let resp = client.execute(req).await.map_err(Error::from);
+        #[cfg(feature = "tower")]
+        let resp = {
+            use std::ops::DerefMut;
+            if let Some(middleware) = ctx.middleware {
+                middleware.lock().await.deref_mut().call(req).await
+            } else {
+                client.execute(req).await.map_err(Error::from)
+            }
+        };
+        if let Some(request_hook) = ctx.request_hook {
+            request_hook.call(&method, &url, start, resp.as_ref().ok())
+        }
+        if let Some(events) = ctx.events {
+            events.record_response(&method, &url, resp.as_ref().ok())
+        }
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = ctx.metrics {
+            crate::metrics::record_response(metrics, &method, &url, start, resp.as_ref().ok())
+        }
+        let resp = resp?;
+        #[cfg(feature = "tracing")]
+        crate::internal::tracing::record_response(&resp, start);
+
+        if let Some(cache) = cache {
+            if let (Some(cached), StatusCode::NOT_MODIFIED) = (cached, resp.status()) {
+                #[cfg(feature = "tracing")]
+                crate::internal::tracing::record_cache("revalidated");
+                let cached = cached.revalidated(resp.headers(), cache.default_ttl);
+                let result = check_response_body(
+                    url.clone(),
+                    &StatusCode::OK,
+                    None,
+                    cached.body.as_slice().into(),
+                );
+                store_in_cache(cache, &url, cached).await;
+                return result;
+            }
+        }
+
+        #[cfg(feature = "replay")]
+        let recording = ctx.recorder.is_some();
+        #[cfg(not(feature = "replay"))]
+        let recording = false;
+        if resp.status().is_success() && (cache.is_some() || recording) {
+            #[cfg(feature = "tracing")]
+            if cache.is_some() {
+                crate::internal::tracing::record_cache("miss");
+            }
+            let status = resp.status();
+            let request_id = request_id(&resp);
+            let headers = resp.headers().clone();
+            let body = resp.bytes().await?.to_vec();
+            let result =
+                check_response_body(url.clone(), &status, request_id, body.as_slice().into());
+            // only store responses which are actually valid
+            if result.is_ok() {
+                #[cfg(feature = "replay")]
+                if let Some(recorder) = ctx.recorder {
+                    recorder.record(&method, &url, body.clone()).await?
+                }
+                if let Some(cache) = cache.filter(|_| status == StatusCode::OK) {
+                    if let Some(cached) = CachedResponse::new(&headers, body, cache.default_ttl) {
+                        store_in_cache(cache, &url, cached).await
+                    }
+                }
+            }
+            return result;
+        }
+
+        check_request(url, resp).await
+    }
+
+    /// Store a response in the cache. The response was received successfully, so a failing cache
+    /// storage must not fail the request and errors are only logged.
+    async fn store_in_cache(cache: &ResponseCache, url: &str, response: CachedResponse) {
+        let _result = cache.storage.set(url, response).await;
+        #[cfg(feature = "tracing")]
+        if let Err(err) = _result {
+            tracing::warn!("failed to store response in cache: {err}")
+        }
+    }
+
+    /// Wait until a new request is allowed to be sent if a request limit is set.
+    async fn acquire_request_permit(
+        request_limit: Option<&RequestScheduler>,
+        priority: RequestPriority,
+    ) -> Option<SchedulerPermit<'_>> {
+        match request_limit {
+            Some(request_limit) => Some(request_limit.acquire(priority).await),
+            None => None,
+        }
+    }
+
     /// Removes all fields which are starting and ending with `__` from a map (which is usually the
     /// response of a request). Some fields can be excluded from this process by providing the field
     /// names in `not_clean_fields`.
@@ -1069,5 +2579,5 @@ mod auth {
     }
 }
 
-pub(crate) use auth::Executor;
-pub use auth::{CrunchyrollBuilder, SessionToken};
+pub use auth::{CrunchyrollBuilder, Registration, RequestSummary, SessionToken};
+pub(crate) use auth::{Executor, RequestPriority};
@@ -4,30 +4,38 @@ use reqwest::{Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde_json::{Map, Value};
+use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
 
 pub(crate) type Result<T, E = Error> = core::result::Result<T, E>;
 
-/// Crate specific error types.
+/// Crate specific error types. New variants might be added in the future, so matches must have a
+/// wildcard arm.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Error was caused by something library internal. This only happens if something was
     /// implemented incorrectly (which hopefully should never be the case) or if Crunchyroll
     /// surprisingly changed specific parts of their api which broke a part of this crate.
     Internal { message: String },
 
-    /// Some sort of error occurred while requesting the Crunchyroll api.
+    /// Some sort of error occurred while requesting the Crunchyroll api. More fields might be
+    /// added in the future, so match it with `Error::Request { .. }`.
+    #[non_exhaustive]
     Request {
         message: String,
         status: Option<StatusCode>,
         /// The url which caused the error.
         url: String,
+        /// Additional information about the error which was returned by Crunchyroll.
+        details: Box<RequestErrorDetails>,
     },
     /// While decoding the api response body something went wrong.
     Decode {
         message: String,
         /// The content which failed to get decoded. Might be empty if the error got triggered by
-        /// the [`From<serde_json::Error>`] implementation for this enum.
+        /// the [`From<serde_json::Error>`] implementation for this enum or if the response was
+        /// parsed with the **simd-json** feature, which rewrites the content while parsing.
         content: Vec<u8>,
         /// The url which caused the error. Might be empty if the error got triggered by the
         /// [`From<serde_json::Error>`] implementation for this enum.
@@ -49,6 +57,144 @@ pub enum Error {
         /// The url which caused the error.
         url: String,
     },
+
+    /// The operation was cancelled via a [`crate::common::CancellationToken`].
+    Cancelled,
+
+    /// Reading or writing a file failed.
+    Io {
+        message: String,
+        /// The underlying io error.
+        error: std::sync::Arc<std::io::Error>,
+    },
+}
+
+/// Additional information about a [`Error::Request`].
+#[derive(Clone, Debug, Default)]
+pub struct RequestErrorDetails {
+    /// Error code returned by Crunchyroll, e.g. `invalid_grant` or `accounts.get_profile.forbidden`.
+    pub code: Option<String>,
+    /// Error type returned by Crunchyroll, e.g. `bad_request.missing_parameter`.
+    pub error_type: Option<String>,
+    /// Value of the `x-request-id` response header. Useful when reporting errors to Crunchyroll.
+    pub request_id: Option<String>,
+    /// Seconds after which the request can be retried. Only set if the rate limit was hit.
+    pub retry_after: Option<u32>,
+    /// Rate limit information sent by Crunchyroll. Only set if the rate limit was hit.
+    pub rate_limit: Option<RateLimit>,
+}
+
+/// Rate limit information of a response, parsed from the `x-ratelimit-*` / `ratelimit-*` headers.
+/// Every field is [`None`] if Crunchyroll didn't send the associated header.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimit {
+    /// Maximum number of requests which are allowed in the current window.
+    pub limit: Option<u32>,
+    /// Remaining number of requests in the current window.
+    pub remaining: Option<u32>,
+    /// Seconds until the current window resets.
+    pub reset: Option<u32>,
+}
+
+impl RateLimit {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            [format!("x-ratelimit-{name}"), format!("ratelimit-{name}")]
+                .iter()
+                .find_map(|name| headers.get(name))
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+        };
+        let rate_limit = Self {
+            limit: header("limit"),
+            remaining: header("remaining"),
+            reset: header("reset"),
+        };
+        (rate_limit.limit.is_some() || rate_limit.remaining.is_some() || rate_limit.reset.is_some())
+            .then_some(rate_limit)
+    }
+}
+
+/// Parse the `Retry-After` header which contains either the seconds to wait or a http date.
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<u32> {
+    let retry_after = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(secs) = retry_after.parse() {
+        return Some(secs);
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(retry_after).ok()?;
+    Some(
+        (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+            .num_seconds()
+            .clamp(0, u32::MAX as i64) as u32,
+    )
+}
+
+impl Error {
+    /// The http status of the response which caused the error, if available.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Request { status, .. } => *status,
+            _ => None,
+        }
+    }
+
+    /// Additional request error information returned by Crunchyroll, if available.
+    pub fn details(&self) -> Option<&RequestErrorDetails> {
+        match self {
+            Error::Request { details, .. } => Some(details.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Check if the failed action may succeed if it's retried later. This is the case if the rate
+    /// limit was hit, Crunchyroll had a server error or the request failed before a response was
+    /// received (e.g. connection errors or timeouts).
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Request { status: None, .. } => true,
+            Error::Request {
+                status: Some(status),
+                ..
+            } => {
+                *status == StatusCode::TOO_MANY_REQUESTS
+                    || *status == StatusCode::REQUEST_TIMEOUT
+                    || status.is_server_error()
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if the error is caused by missing / invalid authentication, e.g. wrong credentials,
+    /// an expired session or missing permissions for the requested resource.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            Error::Authentication { .. } => true,
+            Error::Request { status, .. } => {
+                *status == Some(StatusCode::UNAUTHORIZED) || *status == Some(StatusCode::FORBIDDEN)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if the request was rejected because the content / profile is locked behind the
+    /// parental control PIN. Verify the PIN via [`crate::Crunchyroll::verify_pin`] and retry the
+    /// request, or use [`crate::Crunchyroll::retry_with_pin`] which does both.
+    pub fn is_pin_required(&self) -> bool {
+        let Some(details) = self.details() else {
+            return false;
+        };
+        [&details.code, &details.error_type]
+            .into_iter()
+            .flatten()
+            .any(|code| {
+                let code = code.to_lowercase();
+                code.contains("pin_required") || code.contains("pin.required")
+            })
+    }
 }
 
 impl Display for Error {
@@ -84,6 +230,8 @@ impl Display for Error {
             Error::Authentication { message } => write!(f, "{message}"),
             Error::Input { message } => write!(f, "{message}"),
             Error::Block { message, body, url } => write!(f, "{message} ({url}): {body}"),
+            Error::Cancelled => write!(f, "operation was cancelled"),
+            Error::Io { message, error } => write!(f, "{message}: {error}"),
         }
     }
 }
@@ -113,6 +261,7 @@ impl From<reqwest::Error> for Error {
                 message: err.to_string(),
                 status: err.status(),
                 url: err.url().map_or("n/a".to_string(), |url| url.to_string()),
+                details: Box::default(),
             }
         } else if err.is_decode() {
             Error::Decode {
@@ -132,7 +281,12 @@ impl From<reqwest::Error> for Error {
     }
 }
 
-pub(crate) fn is_request_error(value: Value, url: &str, status: &StatusCode) -> Result<()> {
+pub(crate) fn is_request_error(
+    value: Value,
+    url: &str,
+    status: &StatusCode,
+    request_id: Option<String>,
+) -> Result<()> {
     #[derive(Debug, Deserialize)]
     #[serde(untagged)]
     #[allow(clippy::enum_variant_names)]
@@ -161,15 +315,22 @@ pub(crate) fn is_request_error(value: Value, url: &str, status: &StatusCode) ->
         other: Map<String, Value>,
     }
 
+    let mut details = RequestErrorDetails {
+        request_id,
+        ..Default::default()
+    };
     let error_msg = match serde_json::from_value::<ErrorTypes>(value) {
         Ok(ErrorTypes::MessageTypeError { message, r#type }) => {
-            format!("{} - {}", r#type, message)
+            let msg = format!("{} - {}", r#type, message);
+            details.error_type = Some(r#type);
+            msg
         }
         Ok(ErrorTypes::CodeError {
             code,
             context,
             message,
         }) => {
+            details.code = Some(code.clone());
             let mut msg = if let Some(message) = message {
                 format!("{message} - {code}")
             } else {
@@ -185,6 +346,7 @@ pub(crate) fn is_request_error(value: Value, url: &str, status: &StatusCode) ->
             msg
         }
         Ok(ErrorTypes::GenericError { error, other }) => {
+            details.code = Some(error.clone());
             let mut msg = error;
             if !other.is_empty() {
                 msg += &format!(" ({})", serde_json::to_string(&other).unwrap())
@@ -197,13 +359,14 @@ pub(crate) fn is_request_error(value: Value, url: &str, status: &StatusCode) ->
         message: error_msg,
         status: Some(*status),
         url: url.to_string(),
+        details: Box::new(details),
     })
 }
 
 pub(crate) async fn check_request<T: DeserializeOwned>(url: String, resp: Response) -> Result<T> {
-    let content_length = resp.content_length().unwrap_or(0);
     let status = resp.status();
-    let _raw = match resp.status().as_u16() {
+    let request_id = request_id(&resp);
+    let raw = match resp.status().as_u16() {
         403 => {
             let raw = resp.bytes().await?;
             if raw.starts_with(b"<!DOCTYPE html>")
@@ -224,17 +387,14 @@ pub(crate) async fn check_request<T: DeserializeOwned>(url: String, resp: Respon
                 message: "The requested resource is not present".to_string(),
                 status: Some(resp.status()),
                 url,
+                details: Box::new(RequestErrorDetails {
+                    request_id,
+                    ..Default::default()
+                }),
             })
         }
         429 => {
-            let retry_secs =
-                if let Some(retry_after) = resp.headers().get(reqwest::header::RETRY_AFTER) {
-                    retry_after.to_str().map_or(None, |retry_after_secs| {
-                        retry_after_secs.parse::<u32>().ok()
-                    })
-                } else {
-                    None
-                };
+            let retry_secs = retry_after(resp.headers());
 
             return Err(Error::Request {
                 message: format!(
@@ -245,26 +405,70 @@ pub(crate) async fn check_request<T: DeserializeOwned>(url: String, resp: Respon
                 ),
                 status: Some(resp.status()),
                 url,
+                details: Box::new(RequestErrorDetails {
+                    request_id,
+                    retry_after: retry_secs,
+                    rate_limit: RateLimit::from_headers(resp.headers()),
+                    ..Default::default()
+                }),
             });
         }
         _ => resp.bytes().await?,
     };
-    let mut raw: &[u8] = _raw.as_ref();
+    // simd-json parses in place, the buffer of the body is reused if it isn't shared
+    #[cfg(all(feature = "simd-json", not(target_arch = "wasm32")))]
+    let body = Cow::Owned(Vec::from(raw));
+    #[cfg(not(all(feature = "simd-json", not(target_arch = "wasm32"))))]
+    let body = Cow::Borrowed(raw.as_ref());
+    check_response_body(url, &status, request_id, body)
+}
 
+/// Decode the body of an api response and check if it contains an error. With the **simd-json**
+/// feature, an owned `body` is parsed in place without copying it.
+pub(crate) fn check_response_body<T: DeserializeOwned>(
+    url: String,
+    status: &StatusCode,
+    request_id: Option<String>,
+    mut body: Cow<'_, [u8]>,
+) -> Result<T> {
     // to ensure compatibility with `T`, convert a empty response to {}
-    if raw.is_empty() && (content_length == 0) {
-        raw = "{}".as_bytes();
+    if body.is_empty() {
+        body = Cow::Borrowed("{}".as_bytes());
     }
 
-    let value: Value = serde_json::from_slice(raw).map_err(|e| Error::Decode {
-        message: format!("{} at {}:{}", e, e.line(), e.column()),
-        content: raw.to_vec(),
-        url: url.clone(),
-    })?;
-    is_request_error(value.clone(), &url, &status)?;
+    #[cfg(all(feature = "simd-json", not(target_arch = "wasm32")))]
+    let (value, content) = {
+        // simd-json rewrites the buffer while parsing, so it can't be included in errors
+        let mut body = body.into_owned();
+        let value: Value = simd_json::serde::from_slice(&mut body).map_err(|e| Error::Decode {
+            message: e.to_string(),
+            content: vec![],
+            url: url.clone(),
+        })?;
+        (value, Vec::new)
+    };
+    #[cfg(not(all(feature = "simd-json", not(target_arch = "wasm32"))))]
+    let (value, content) = {
+        let value: Value = serde_json::from_slice(&body).map_err(|e| Error::Decode {
+            message: format!("{} at {}:{}", e, e.line(), e.column()),
+            content: body.to_vec(),
+            url: url.clone(),
+        })?;
+        (value, || body.to_vec())
+    };
+
+    is_request_error(value.clone(), &url, status, request_id)?;
     serde_json::from_value::<T>(value).map_err(|e| Error::Decode {
         message: format!("{} at {}:{}", e, e.line(), e.column()),
-        content: raw.to_vec(),
+        content: content(),
         url,
     })
 }
+
+/// Get the `x-request-id` header of a response.
+pub(crate) fn request_id(resp: &Response) -> Option<String> {
+    resp.headers()
+        .get("x-request-id")
+        .and_then(|id| id.to_str().ok())
+        .map(|id| id.to_string())
+}
@@ -0,0 +1,112 @@
+//! Subscribe to events of a client, like logins, token refreshes or failed requests. Use
+//! [`crate::Crunchyroll::events`] (or [`crate::crunchyroll::CrunchyrollBuilder::events`] to
+//! receive login events too) to get a stream of them.
+
+use crate::internal::redact::redact_url;
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+use tokio::sync::broadcast;
+
+/// Amount of events which are buffered per subscriber. If a subscriber doesn't keep up, the
+/// oldest events are dropped for it.
+const CAPACITY: usize = 256;
+
+/// An event emitted by a client. Credentials in urls (tokens, signatures, ...) are replaced with
+/// `REDACTED`, so events can be logged safely.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ClientEvent {
+    /// The login succeeded.
+    LoggedIn {
+        /// [`None`] if logged in anonymously.
+        account_id: Option<String>,
+    },
+    /// The access token was refreshed.
+    TokenRefreshed { expires_at: DateTime<Utc> },
+    /// A request failed because it hit the rate limit (`429`), with a server error (`5xx`) or
+    /// because no response was received at all (connection error, timeout, ...). Emitted for
+    /// every attempt, also if the request is retried afterwards.
+    RequestFailed {
+        method: reqwest::Method,
+        url: String,
+        /// [`None`] if no response was received.
+        status: Option<u16>,
+    },
+    /// A stream session was created, see [`crate::media::Stream`].
+    StreamSessionCreated {
+        /// Id of the episode / movie / music video.
+        id: String,
+        /// When the session expires if it isn't renewed.
+        expires_at: DateTime<Utc>,
+    },
+    /// A stream session was ended via [`crate::media::Stream::invalidate`].
+    StreamSessionExpired {
+        /// Id of the episode / movie / music video.
+        id: String,
+    },
+    /// Raw data (like stream segments, subtitles or images) is being downloaded.
+    DownloadStarted { url: String },
+    /// A download finished. `bytes` is [`None`] if it failed.
+    DownloadFinished { url: String, bytes: Option<usize> },
+}
+
+/// Broadcasts [`ClientEvent`]s to all subscribers. Shared by all clones of a client.
+#[derive(Clone, Debug)]
+pub(crate) struct EventBus {
+    sender: broadcast::Sender<ClientEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self {
+            sender: broadcast::channel(CAPACITY).0,
+        }
+    }
+}
+
+impl EventBus {
+    pub(crate) fn emit(&self, event: ClientEvent) {
+        // fails only if no one is subscribed
+        let _ = self.sender.send(event);
+    }
+
+    /// Emit [`ClientEvent::RequestFailed`] if no response was received (`resp` is [`None`]) or
+    /// the response hit the rate limit or failed with a server error.
+    pub(crate) fn record_response(
+        &self,
+        method: &reqwest::Method,
+        url: &str,
+        resp: Option<&reqwest::Response>,
+    ) {
+        let status = resp.map(|resp| resp.status());
+        let failed = match status {
+            None => true,
+            Some(status) => {
+                status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+            }
+        };
+        if failed {
+            self.emit(ClientEvent::RequestFailed {
+                method: method.clone(),
+                url: redact_url(url),
+                status: status.map(|status| status.as_u16()),
+            })
+        }
+    }
+
+    /// Events which are emitted after this call. Events which were dropped because the subscriber
+    /// lagged behind are skipped.
+    pub(crate) fn subscribe(&self) -> BoxStream<'static, ClientEvent> {
+        futures_util::stream::unfold(self.sender.subscribe(), |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => return Some((event, receiver)),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+        .boxed()
+    }
+}
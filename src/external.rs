@@ -0,0 +1,220 @@
+//! Map Crunchyroll media to external anime databases.
+
+use crate::common::TryStreamExt;
+use crate::{enum_values, MovieListing, Result, Series};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+enum_values! {
+    /// External anime databases.
+    #[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+    pub enum ExternalDatabase {
+        MyAnimeList = "myanimelist"
+        AniList = "anilist"
+        AniDB = "anidb"
+    }
+}
+
+/// An id of a title in an external database.
+#[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ExternalId {
+    pub database: ExternalDatabase,
+    pub id: String,
+}
+
+/// Information about a Crunchyroll title which can be used by a [`ExternalIdResolver`] to look
+/// up the title in an external database.
+#[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ExternalIdQuery {
+    /// The Crunchyroll id of the title.
+    pub crunchyroll_id: String,
+    pub title: String,
+    pub slug_title: String,
+    pub release_year: Option<u32>,
+    /// Date when the first episode of the title aired. Only set if the query was created with
+    /// [`Series::external_id_query`] and the series has already aired episodes.
+    pub first_air_date: Option<DateTime<Utc>>,
+}
+
+impl From<&Series> for ExternalIdQuery {
+    fn from(value: &Series) -> Self {
+        Self {
+            crunchyroll_id: value.id.clone(),
+            title: value.title.clone(),
+            slug_title: value.slug_title.clone(),
+            release_year: value.series_launch_year,
+            first_air_date: None,
+        }
+    }
+}
+
+impl From<&MovieListing> for ExternalIdQuery {
+    fn from(value: &MovieListing) -> Self {
+        Self {
+            crunchyroll_id: value.id.clone(),
+            title: value.title.clone(),
+            slug_title: value.slug_title.clone(),
+            release_year: Some(value.movie_release_year).filter(|y| *y != 0),
+            first_air_date: None,
+        }
+    }
+}
+
+/// Resolves Crunchyroll titles to ids of external databases. Implement this to plug in your own
+/// lookup logic, e.g. querying the AniList api or a local mapping file.
+#[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+#[async_trait::async_trait]
+pub trait ExternalIdResolver: Send + Sync {
+    /// Returns all external ids this resolver could find for the given title. An empty vec
+    /// should be returned if nothing was found.
+    async fn resolve(&self, query: &ExternalIdQuery) -> Result<Vec<ExternalId>>;
+}
+
+/// Resolver which looks up external ids in a static mapping of Crunchyroll id to external ids.
+#[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StaticExternalIdResolver {
+    pub mappings: HashMap<String, Vec<ExternalId>>,
+}
+
+#[async_trait::async_trait]
+impl ExternalIdResolver for StaticExternalIdResolver {
+    async fn resolve(&self, query: &ExternalIdQuery) -> Result<Vec<ExternalId>> {
+        Ok(self
+            .mappings
+            .get(&query.crunchyroll_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+/// A title in an external database, used by [`TitleExternalIdResolver`].
+#[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TitleMapping {
+    pub title: String,
+    /// Crunchyroll slug of the title. Matched instead of [`TitleMapping::title`] if set.
+    pub slug_title: Option<String>,
+    /// If set, only matches titles with the same release year.
+    pub release_year: Option<u32>,
+    /// If set, only matches titles whose first episode aired on this date.
+    pub first_air_date: Option<NaiveDate>,
+    pub ids: Vec<ExternalId>,
+}
+
+impl TitleMapping {
+    fn matches(&self, query: &ExternalIdQuery) -> bool {
+        let name_matches = match &self.slug_title {
+            Some(slug_title) => slug_title == &query.slug_title,
+            None => normalize_title(&self.title) == normalize_title(&query.title),
+        };
+        name_matches
+            && (self.release_year.is_none()
+                || query.release_year.is_none()
+                || self.release_year == query.release_year)
+            && (self.first_air_date.is_none()
+                || query.first_air_date.is_none()
+                || self.first_air_date == query.first_air_date.map(|d| d.date_naive()))
+    }
+}
+
+/// Resolver which looks up external ids by slug or title, for mappings which don't know the
+/// Crunchyroll id (e.g. exported from an external database). Titles are compared
+/// case-insensitively and ignoring non-alphanumeric characters. Ambiguous titles can be
+/// narrowed down with [`TitleMapping::release_year`] and [`TitleMapping::first_air_date`].
+#[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TitleExternalIdResolver {
+    pub mappings: Vec<TitleMapping>,
+}
+
+#[async_trait::async_trait]
+impl ExternalIdResolver for TitleExternalIdResolver {
+    async fn resolve(&self, query: &ExternalIdQuery) -> Result<Vec<ExternalId>> {
+        Ok(self
+            .mappings
+            .iter()
+            .find(|m| m.matches(query))
+            .map(|m| m.ids.clone())
+            .unwrap_or_default())
+    }
+}
+
+fn normalize_title(title: &str) -> String {
+    title
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Combines multiple [`ExternalIdResolver`]s. Resolvers are queried in the order they were
+/// added and only the first found id per [`ExternalDatabase`] is kept.
+#[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+#[derive(Default)]
+pub struct ExternalIdMapper {
+    resolvers: Vec<Box<dyn ExternalIdResolver>>,
+}
+
+impl ExternalIdMapper {
+    /// Adds a resolver to the mapper.
+    pub fn resolver(mut self, resolver: impl ExternalIdResolver + 'static) -> Self {
+        self.resolvers.push(Box::new(resolver));
+        self
+    }
+
+    /// Resolves all external ids for the given title.
+    pub async fn resolve(&self, query: &ExternalIdQuery) -> Result<Vec<ExternalId>> {
+        let mut result: Vec<ExternalId> = vec![];
+        for resolver in &self.resolvers {
+            for external_id in resolver.resolve(query).await? {
+                if !result.iter().any(|e| e.database == external_id.database) {
+                    result.push(external_id)
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Series {
+    /// Creates a [`ExternalIdQuery`] with [`ExternalIdQuery::first_air_date`] set. This requests
+    /// the seasons and the episodes of the first season, use [`ExternalIdQuery::from`] if the
+    /// air date isn't needed.
+    #[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+    pub async fn external_id_query(&self) -> Result<ExternalIdQuery> {
+        let mut query = ExternalIdQuery::from(self);
+        let seasons = self.seasons().try_collect::<Vec<_>>().await?;
+        if let Some(season) = seasons.iter().min_by_key(|s| s.season_number) {
+            let now = Utc::now();
+            query.first_air_date = season
+                .episodes()
+                .try_collect::<Vec<_>>()
+                .await?
+                .into_iter()
+                .map(|e| e.episode_air_date)
+                .filter(|date| *date <= now)
+                .min();
+        }
+        Ok(query)
+    }
+
+    /// Resolves the ids of this series in external databases with the given mapper. Use
+    /// [`Series::external_id_query`] and [`ExternalIdMapper::resolve`] if your resolvers need
+    /// [`ExternalIdQuery::first_air_date`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+    pub async fn external_ids(&self, mapper: &ExternalIdMapper) -> Result<Vec<ExternalId>> {
+        mapper.resolve(&ExternalIdQuery::from(self)).await
+    }
+}
+
+impl MovieListing {
+    /// Resolves the ids of this movie listing in external databases with the given mapper.
+    #[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+    pub async fn external_ids(&self, mapper: &ExternalIdMapper) -> Result<Vec<ExternalId>> {
+        mapper.resolve(&ExternalIdQuery::from(self)).await
+    }
+}
@@ -1,13 +1,16 @@
 //! Feeds like home feed or news feed.
 
 use crate::common::{Pagination, PaginationBulkResultMeta, V2BulkResult, V2TypeBulkResult};
-use crate::media::MediaType;
+use crate::crunchyroll::Executor;
+use crate::media::{Media, MediaType};
 use crate::search::{BrowseOptions, BrowseSortType};
-use crate::{Crunchyroll, MediaCollection, Request, Series};
+use crate::{Crunchyroll, Episode, MediaCollection, Request, Result, Series};
 use chrono::{DateTime, Utc};
-use futures_util::FutureExt;
+use futures_util::{FutureExt, TryStreamExt};
 use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeSet;
+use std::sync::Arc;
 
 /// Images for a [`FeedCarousel`].
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
@@ -22,6 +25,7 @@ pub struct FeedCarouselImages {
 /// The carousel / sliding images showed at first when visiting crunchyroll.com
 #[allow(dead_code)]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
+#[request(executor(panel))]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
 #[cfg_attr(not(feature = "__test_strict"), serde(default))]
 pub struct FeedCarousel {
@@ -39,9 +43,9 @@ pub struct FeedCarousel {
 
     pub button_text: String,
 
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     id: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     third_party_impression_tracker: crate::StrictValue,
 }
 
@@ -70,19 +74,39 @@ pub struct FeedBanner {
 }
 
 /// A feed containing multiple ids to different series.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
 pub struct SeriesFeed {
+    #[serde(skip)]
+    executor: Arc<Executor>,
+
     pub title: String,
 
     pub description: String,
 
-    /// Ids to series. Use [`Series::from_id`] to get the series.
+    /// Ids to series. Use [`SeriesFeed::series`] or [`Series::from_id`] to get the series.
     pub ids: Vec<String>,
 }
 
+impl SeriesFeed {
+    /// Resolves all [`SeriesFeed::ids`] to actual series.
+    pub async fn series(&self) -> Result<Vec<Series>> {
+        let crunchyroll = Crunchyroll {
+            executor: self.executor.clone(),
+        };
+        let mut series = vec![];
+        for id in &self.ids {
+            series.push(Series::from_id(&crunchyroll, id).await?)
+        }
+        Ok(series)
+    }
+}
+
 /// A feed containing an id to a series or episode, depending on what you've watched in the past.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
 pub struct SimilarFeed {
+    #[serde(skip)]
+    executor: Arc<Executor>,
+
     pub title: String,
 
     pub description: String,
@@ -91,6 +115,16 @@ pub struct SimilarFeed {
     pub similar_id: String,
 }
 
+impl SimilarFeed {
+    /// Returns media similar to [`SimilarFeed::similar_id`].
+    pub fn similar(&self) -> Pagination<MediaCollection> {
+        Crunchyroll {
+            executor: self.executor.clone(),
+        }
+        .similar_to(&self.similar_id)
+    }
+}
+
 /// A feed containing information about a game with a link to it.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GameFeed {
@@ -101,7 +135,8 @@ pub struct GameFeed {
 }
 
 /// Items which can be shown on the home feed.
-#[derive(Clone, Debug, Serialize, Request)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Serialize)]
 pub enum HomeFeed {
     /// The feed at the top of the Crunchyroll website.
     CarouselFeed(Vec<FeedCarousel>),
@@ -109,14 +144,15 @@ pub enum HomeFeed {
     Series(Series),
     /// Recommendations for you. Use [`Crunchyroll::recommendations`] to get them.
     Recommendation,
-    /// Your watch history. Use [`Crunchyroll::watch_history`] to get it.
+    /// Your watch history, shown as "Continue Watching" on the website. Use
+    /// [`Crunchyroll::watch_history`] to get it.
     History,
     /// A banner containing a link to a series or article.
     Banner(FeedBanner),
     /// Your watchlist. Use [`Crunchyroll::watchlist`] to get it.
     Watchlist,
     /// A feed containing a title with description and multiple series (ids) matching to title and
-    /// description.
+    /// description. Use [`SeriesFeed::series`] to get the series.
     SeriesFeed(SeriesFeed),
     /// A feed containing ids to music videos. Use [`crate::MusicVideo::from_id`] to get usable
     /// structs from it.
@@ -133,8 +169,7 @@ pub enum HomeFeed {
     /// overwrite [`BrowseOptions::sort`] and [`BrowseOptions::media_type`], this might cause
     /// confusing results.
     Browse(BrowseOptions),
-    /// Results similar to a series. Get the series struct via [`SimilarFeed::similar_id`] and call
-    /// [`Series::similar`] to get similar series.
+    /// Results similar to a series. Use [`SimilarFeed::similar`] to get them.
     SimilarTo(SimilarFeed),
     Game(GameFeed),
     /// Crunchyroll may update their feed / add new items. This field catches everything which is
@@ -148,6 +183,27 @@ impl Default for HomeFeed {
     }
 }
 
+#[async_trait::async_trait]
+impl Request for HomeFeed {
+    async fn __set_executor(&mut self, executor: Arc<Executor>) {
+        match self {
+            HomeFeed::CarouselFeed(carousel) => {
+                for item in carousel.iter_mut() {
+                    Request::__set_executor(item, executor.clone()).await
+                }
+            }
+            HomeFeed::Series(series) => Request::__set_executor(series, executor).await,
+            HomeFeed::SeriesFeed(series_feed) => {
+                Request::__set_executor(series_feed, executor).await
+            }
+            HomeFeed::SimilarTo(similar_feed) => {
+                Request::__set_executor(similar_feed, executor).await
+            }
+            _ => (),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for HomeFeed {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -170,10 +226,12 @@ impl<'de> Deserialize<'de> for HomeFeed {
 
         match resource_type.as_str() {
             "hero_carousel" => Ok(Self::CarouselFeed(
-                serde_json::from_value(get_value("items")?).map_err(map_serde_error)?,
+                crate::internal::strict::from_value(get_value("items")?)
+                    .map_err(map_serde_error)?,
             )),
             "panel" => Ok(Self::Series(
-                serde_json::from_value(get_value("panel")?).map_err(map_serde_error)?,
+                crate::internal::strict::from_value(get_value("panel")?)
+                    .map_err(map_serde_error)?,
             )),
             "dynamic_collection" => {
                 let response_type = get_value("response_type")?
@@ -183,7 +241,7 @@ impl<'de> Deserialize<'de> for HomeFeed {
 
                 match response_type.as_str() {
                     "recommendations" => Ok(Self::Recommendation),
-                    "history" => Ok(Self::History),
+                    "history" | "continue_watching" => Ok(Self::History),
                     "watchlist" => Ok(Self::Watchlist),
                     "news_feed" => Ok(Self::NewsFeed),
                     "browse" | "recent_episodes" => {
@@ -192,7 +250,7 @@ impl<'de> Deserialize<'de> for HomeFeed {
                             .ok_or_else(|| type_error("link", "string"))?
                             .to_string();
                         let query: Vec<(String, String)> =
-                            serde_urlencoded::from_str(link.split('?').last().unwrap())
+                            serde_urlencoded::from_str(link.split('?').next_back().unwrap())
                                 .map_err(|e| Error::custom(e.to_string()))?;
 
                         let mut browse_options = BrowseOptions::default();
@@ -218,7 +276,7 @@ impl<'de> Deserialize<'de> for HomeFeed {
                             .ok_or_else(|| type_error("source_media_id", "string"))?
                             .to_string();
 
-                        let mut similar_feed: SimilarFeed = serde_json::from_value(
+                        let mut similar_feed: SimilarFeed = crate::internal::strict::from_value(
                             serde_json::to_value(as_map).map_err(map_serde_error)?,
                         )
                         .map_err(map_serde_error)?;
@@ -232,8 +290,10 @@ impl<'de> Deserialize<'de> for HomeFeed {
                 }
             }
             "in_feed_banner" => Ok(Self::Banner(
-                serde_json::from_value(serde_json::to_value(as_map).map_err(map_serde_error)?)
-                    .map_err(map_serde_error)?,
+                crate::internal::strict::from_value(
+                    serde_json::to_value(as_map).map_err(map_serde_error)?,
+                )
+                .map_err(map_serde_error)?,
             )),
             "curated_collection" => {
                 let response_type = get_value("response_type")?
@@ -243,7 +303,7 @@ impl<'de> Deserialize<'de> for HomeFeed {
 
                 match response_type.as_str() {
                     "series" => Ok(Self::SeriesFeed(
-                        serde_json::from_value(
+                        crate::internal::strict::from_value(
                             serde_json::to_value(as_map).map_err(map_serde_error)?,
                         )
                         .map_err(map_serde_error)?,
@@ -253,7 +313,7 @@ impl<'de> Deserialize<'de> for HomeFeed {
                             .as_array()
                             .ok_or_else(|| type_error("ids", "string list"))?
                             .iter()
-                            .map(|v| v.to_string())
+                            .filter_map(|v| v.as_str().map(|s| s.to_string()))
                             .collect();
                         Ok(Self::ConcertFeed(ids))
                     }
@@ -262,7 +322,7 @@ impl<'de> Deserialize<'de> for HomeFeed {
                             .as_array()
                             .ok_or_else(|| type_error("ids", "string list"))?
                             .iter()
-                            .map(|v| v.to_string())
+                            .filter_map(|v| v.as_str().map(|s| s.to_string()))
                             .collect();
                         Ok(Self::MusicVideoFeed(ids))
                     }
@@ -271,7 +331,7 @@ impl<'de> Deserialize<'de> for HomeFeed {
                             .as_array()
                             .ok_or_else(|| type_error("ids", "string list"))?
                             .iter()
-                            .map(|v| v.to_string())
+                            .filter_map(|v| v.as_str().map(|s| s.to_string()))
                             .collect();
                         Ok(Self::ArtistFeed(ids))
                     }
@@ -284,7 +344,7 @@ impl<'de> Deserialize<'de> for HomeFeed {
                 }
             }
             "game" => Ok(Self::Game(
-                serde_json::from_value(
+                crate::internal::strict::from_value(
                     get_value("game").map_err(|_| type_error("game", "object"))?,
                 )
                 .map_err(map_serde_error)?,
@@ -301,12 +361,61 @@ impl<'de> Deserialize<'de> for HomeFeed {
     }
 }
 
+/// Why media was recommended in a [`RecommendationFeed`].
+#[derive(Clone, Debug)]
+pub enum RecommendationReason {
+    /// General recommendations based on your account ("Recommended for you").
+    ForYou,
+    /// Recommendations based on a media you've watched ("Because you watched ...").
+    BecauseYouWatched {
+        /// Id of the watched media.
+        id: String,
+        /// Title of the recommendation feed, usually contains the title of the watched media.
+        title: String,
+    },
+}
+
+/// Personalized recommendations together with the reason why they were recommended.
+pub struct RecommendationFeed {
+    pub reason: RecommendationReason,
+    pub recommendations: Pagination<MediaCollection>,
+}
+
 /// Top news and latest news.
 pub struct NewsFeedResult {
     pub top_news: Pagination<NewsFeed>,
     pub latest_news: Pagination<NewsFeed>,
 }
 
+/// Recently added episodes and titles.
+pub struct RecentlyAddedResult {
+    /// Newly released episodes, latest first.
+    pub episodes: Pagination<Episode>,
+    /// Newly added series and movie listings, latest first.
+    pub titles: Pagination<MediaCollection>,
+}
+
+/// State of the catalog at a specific time. Store it (it's serializable) and pass it to
+/// [`Crunchyroll::catalog_delta`] later to get all changes since then.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CatalogSnapshot {
+    pub taken_at: DateTime<Utc>,
+    /// Ids of all series and movie listings in the catalog.
+    pub title_ids: BTreeSet<String>,
+}
+
+/// Changes of the catalog since a [`CatalogSnapshot`], returned by [`Crunchyroll::catalog_delta`].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CatalogDelta {
+    /// Episodes which were released since the snapshot was taken, latest first.
+    pub new_episodes: Vec<Episode>,
+    /// Series and movie listings which were added since the snapshot was taken.
+    pub new_titles: Vec<MediaCollection>,
+    /// Ids of series and movie listings which were removed since the snapshot was taken. Only
+    /// set by [`Crunchyroll::catalog_delta_full`].
+    pub removed_title_ids: Vec<String>,
+}
+
 /// Crunchyroll news like new library anime, dubs, etc... .
 #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault, Request)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
@@ -417,6 +526,192 @@ impl Crunchyroll {
         }
     }
 
+    /// Returns recently added content ("New on Crunchyroll"), split into newly released episodes
+    /// and newly added series / movie listings. Episodes are returned newest first, use
+    /// [`Pagination::order`] with [`crate::common::PaginationOrder::Ascending`] to reverse them.
+    pub fn recently_added(&self) -> RecentlyAddedResult {
+        RecentlyAddedResult {
+            episodes: Pagination::new(
+                |options| {
+                    async move {
+                        let endpoint = "https://www.crunchyroll.com/content/v2/discover/browse";
+                        let result: V2BulkResult<Episode, PaginationBulkResultMeta> = options
+                            .executor
+                            .get(endpoint)
+                            .query(&[("type", "episode"), ("sort_by", "newly_added")])
+                            .query(&[("n", options.page_size), ("start", options.start)])
+                            .query(&options.order_query())
+                            .apply_locale_query()
+                            .apply_preferred_audio_locale_query()
+                            .request()
+                            .await?;
+                        Ok(result.into())
+                    }
+                    .boxed()
+                },
+                self.executor.clone(),
+                None,
+                None,
+            )
+            .with_order_support(),
+            titles: self.browse(BrowseOptions::default().sort(BrowseSortType::NewlyAdded)),
+        }
+    }
+
+    /// Takes a snapshot of the current catalog. Requests all series and movie listings, so this
+    /// may take some time.
+    pub async fn catalog_snapshot(&self) -> Result<CatalogSnapshot> {
+        let taken_at = Utc::now();
+        let mut titles = self.browse(BrowseOptions::default());
+        titles.page_size(100);
+        let title_ids = titles
+            .map_ok(|title| title.id().to_string())
+            .try_collect()
+            .await?;
+        Ok(CatalogSnapshot {
+            taken_at,
+            title_ids,
+        })
+    }
+
+    /// Returns all changes of the catalog since the given snapshot was taken, together with a new
+    /// snapshot which can be used for the next call. Only the recently added feeds
+    /// ([`Crunchyroll::recently_added`]) are requested until they reach
+    /// [`CatalogSnapshot::taken_at`] (episodes) or a title which is already part of the snapshot
+    /// (titles), so this is cheap if called regularly. Removed titles can't be detected this way,
+    /// [`CatalogDelta::removed_title_ids`] is always empty; use
+    /// [`Crunchyroll::catalog_delta_full`] to detect them.
+    pub async fn catalog_delta(
+        &self,
+        snapshot: &CatalogSnapshot,
+    ) -> Result<(CatalogDelta, CatalogSnapshot)> {
+        let taken_at = Utc::now();
+        let new_episodes = self.episodes_added_since(snapshot.taken_at).await?;
+
+        let mut new_titles = vec![];
+        let mut titles = self.recently_added().titles;
+        while let Some(title) = titles.try_next().await? {
+            if snapshot.title_ids.contains(title.id()) {
+                break;
+            }
+            new_titles.push(title)
+        }
+
+        let mut title_ids = snapshot.title_ids.clone();
+        title_ids.extend(new_titles.iter().map(|t| t.id().to_string()));
+        let delta = CatalogDelta {
+            new_episodes,
+            new_titles,
+            removed_title_ids: vec![],
+        };
+        Ok((
+            delta,
+            CatalogSnapshot {
+                taken_at,
+                title_ids,
+            },
+        ))
+    }
+
+    /// Like [`Crunchyroll::catalog_delta`] but requests all series and movie listings of the
+    /// catalog (like [`Crunchyroll::catalog_snapshot`]) and compares their ids with the ids of the
+    /// snapshot, so that removed titles are detected too. This may take some time.
+    pub async fn catalog_delta_full(
+        &self,
+        snapshot: &CatalogSnapshot,
+    ) -> Result<(CatalogDelta, CatalogSnapshot)> {
+        let taken_at = Utc::now();
+        let new_episodes = self.episodes_added_since(snapshot.taken_at).await?;
+
+        let mut titles = self.browse(BrowseOptions::default());
+        titles.page_size(100);
+        let titles: Vec<MediaCollection> = titles.try_collect().await?;
+        let title_ids: BTreeSet<String> = titles.iter().map(|t| t.id().to_string()).collect();
+
+        let delta = CatalogDelta {
+            new_episodes,
+            new_titles: titles
+                .into_iter()
+                .filter(|t| !snapshot.title_ids.contains(t.id()))
+                .collect(),
+            removed_title_ids: snapshot.title_ids.difference(&title_ids).cloned().collect(),
+        };
+        Ok((
+            delta,
+            CatalogSnapshot {
+                taken_at,
+                title_ids,
+            },
+        ))
+    }
+
+    async fn episodes_added_since(&self, since: DateTime<Utc>) -> Result<Vec<Episode>> {
+        let mut new_episodes = vec![];
+        let mut episodes = self.recently_added().episodes;
+        while let Some(episode) = episodes.try_next().await? {
+            if episode.premium_available_date <= since {
+                break;
+            }
+            new_episodes.push(episode)
+        }
+        Ok(new_episodes)
+    }
+
+    /// Returns all personalized recommendation feeds which are shown on the home feed
+    /// ("Recommended for you", "Because you watched ..."), each with the reason why it's
+    /// recommended.
+    pub async fn recommendation_feeds(&self) -> Result<Vec<RecommendationFeed>> {
+        let home_feed: Vec<HomeFeed> = self.home_feed().try_collect().await?;
+
+        let mut feeds = vec![];
+        for item in home_feed {
+            match item {
+                HomeFeed::Recommendation => feeds.push(RecommendationFeed {
+                    reason: RecommendationReason::ForYou,
+                    recommendations: self.recommendations(),
+                }),
+                HomeFeed::SimilarTo(similar_feed) => feeds.push(RecommendationFeed {
+                    recommendations: similar_feed.similar(),
+                    reason: RecommendationReason::BecauseYouWatched {
+                        id: similar_feed.similar_id,
+                        title: similar_feed.title,
+                    },
+                }),
+                _ => (),
+            }
+        }
+        Ok(feeds)
+    }
+
+    /// Returns series or movies which are similar to the media with the given id. Same as
+    /// [`Series::similar`] / [`crate::MovieListing::similar`] but without requesting the media
+    /// first.
+    pub fn similar_to<S: AsRef<str>>(&self, id: S) -> Pagination<MediaCollection> {
+        Pagination::new(
+            |options| {
+                async move {
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content/v2/discover/{}/similar_to/{}",
+                        options.executor.details.account_id.clone()?,
+                        options.extra.get("id").unwrap()
+                    );
+                    let result: V2BulkResult<MediaCollection, PaginationBulkResultMeta> = options
+                        .executor
+                        .get(endpoint)
+                        .query(&[("n", options.page_size), ("start", options.start)])
+                        .apply_locale_query()
+                        .request()
+                        .await?;
+                    Ok(result.into())
+                }
+                .boxed()
+            },
+            self.executor.clone(),
+            None,
+            Some(vec![("id", id.as_ref().to_string())]),
+        )
+    }
+
     /// Returns recommended series or movies to watch.
     pub fn recommendations(&self) -> Pagination<MediaCollection> {
         Pagination::new(
@@ -0,0 +1,46 @@
+//! Async file system access. `tokio::fs` runs the blocking calls on the blocking thread pool so
+//! that they don't stall the async runtime. Wasm has no threads, so [`std::fs`] is used directly
+//! there.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use tokio::fs::{create_dir_all, read, remove_file, write};
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn create_dir_all(path: impl AsRef<Path>) -> io::Result<()> {
+    std::fs::create_dir_all(path)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    std::fs::read(path)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn remove_file(path: impl AsRef<Path>) -> io::Result<()> {
+    std::fs::remove_file(path)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    std::fs::write(path, contents)
+}
+
+/// Paths of all entries in `path`.
+pub(crate) async fn read_dir(path: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let mut dir = tokio::fs::read_dir(path).await?;
+        let mut paths = vec![];
+        while let Some(entry) = dir.next_entry().await? {
+            paths.push(entry.path())
+        }
+        Ok(paths)
+    }
+    #[cfg(target_arch = "wasm32")]
+    std::fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect()
+}
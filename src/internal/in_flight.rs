@@ -0,0 +1,71 @@
+use crate::Result;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+
+/// Coalesces identical requests which are in flight at the same time into a single one whose
+/// result is shared with all callers.
+#[derive(Debug, Default)]
+pub(crate) struct InFlightRequests(Mutex<HashMap<String, broadcast::Sender<Result<Value>>>>);
+
+impl InFlightRequests {
+    /// Run `request` if no request with the same `key` is currently in flight, otherwise wait for
+    /// the result of the in-flight request.
+    pub(crate) async fn run(
+        &self,
+        key: String,
+        request: impl Future<Output = Result<Value>>,
+    ) -> Result<Value> {
+        let receiver = {
+            let mut in_flight = self.0.lock().unwrap();
+            match in_flight.get(&key) {
+                Some(sender) => Some(sender.subscribe()),
+                None => {
+                    in_flight.insert(key.clone(), broadcast::channel(1).0);
+                    None
+                }
+            }
+        };
+        if let Some(mut receiver) = receiver {
+            // if the receive fails, the task which sent the request was cancelled, so the request
+            // has to be sent again
+            return match receiver.recv().await {
+                Ok(result) => result,
+                Err(_) => request.await,
+            };
+        }
+
+        let guard = InFlightGuard {
+            in_flight: self,
+            key: Some(key),
+        };
+        let result = request.await;
+        if let Some(sender) = guard.finish() {
+            let _ = sender.send(result.clone());
+        }
+        result
+    }
+}
+
+/// Removes the in-flight entry, even if the request future gets dropped.
+struct InFlightGuard<'a> {
+    in_flight: &'a InFlightRequests,
+    key: Option<String>,
+}
+
+impl InFlightGuard<'_> {
+    fn finish(mut self) -> Option<broadcast::Sender<Result<Value>>> {
+        let key = self.key.take()?;
+        self.in_flight.0.lock().unwrap().remove(&key)
+    }
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.in_flight.0.lock().unwrap().remove(&key);
+        }
+    }
+}
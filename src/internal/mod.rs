@@ -1,4 +1,15 @@
+pub(crate) mod fs;
+pub(crate) mod in_flight;
+pub(crate) mod redact;
+#[cfg(feature = "replay")]
+pub(crate) mod replay;
+pub(crate) mod retry_budget;
+pub(crate) mod scheduler;
 pub(crate) mod serde;
 pub(crate) mod strict;
 #[cfg(feature = "tower")]
 pub(crate) mod tower;
+#[cfg(feature = "tracing")]
+pub(crate) mod tracing;
+#[cfg(any(feature = "nfo", feature = "rss"))]
+pub(crate) mod xml;
@@ -0,0 +1,81 @@
+/// Replace the values of query parameters and path segments which may contain credentials with
+/// `REDACTED`.
+pub(crate) fn redact_url(url: &str) -> String {
+    const SENSITIVE: [&str; 8] = [
+        "token",
+        "access_token",
+        "refresh_token",
+        "etp_rt",
+        "password",
+        "policy",
+        "signature",
+        "key-pair-id",
+    ];
+
+    let Ok(mut url) = reqwest::Url::parse(url) else {
+        return url.to_string();
+    };
+
+    // stream session tokens are part of the path (`/v1/token/<content id>/<token>/...`)
+    if let Some(segments) = url.path_segments() {
+        let mut segments: Vec<String> = segments.map(|s| s.to_string()).collect();
+        if let Some(position) = segments.iter().position(|s| s == "token") {
+            if let Some(token) = segments.get_mut(position + 2) {
+                *token = "REDACTED".to_string();
+                url.set_path(&segments.join("/"))
+            }
+        }
+    }
+
+    // only rewrite the query if required, re-encoding it may change the url otherwise
+    if !url
+        .query_pairs()
+        .any(|(key, _)| SENSITIVE.contains(&key.to_lowercase().as_str()))
+    {
+        return url.to_string();
+    }
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            if SENSITIVE.contains(&key.to_lowercase().as_str()) {
+                (key.to_string(), "REDACTED".to_string())
+            } else {
+                (key.to_string(), value.to_string())
+            }
+        })
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url.to_string()
+}
+
+/// Replace the values of json fields which contain credentials (like stream session tokens) with
+/// `REDACTED` and redact all urls via [`redact_url`].
+#[cfg(feature = "replay")]
+pub(crate) fn redact_json(value: &mut serde_json::Value) {
+    const SENSITIVE: [&str; 5] = [
+        "token",
+        "access_token",
+        "refresh_token",
+        "etp_rt",
+        "password",
+    ];
+
+    match value {
+        serde_json::Value::String(string)
+            if string.starts_with("https://") || string.starts_with("http://") =>
+        {
+            *string = redact_url(string)
+        }
+        serde_json::Value::Array(array) => array.iter_mut().for_each(redact_json),
+        serde_json::Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                if value.is_string() && SENSITIVE.contains(&key.to_lowercase().as_str()) {
+                    *value = "REDACTED".into()
+                } else {
+                    redact_json(value)
+                }
+            }
+        }
+        _ => (),
+    }
+}
@@ -0,0 +1,110 @@
+use crate::cache::{CacheStorage, CachedResponse, DiskCacheStorage};
+use crate::error::Error;
+use crate::internal::redact::{redact_json, redact_url};
+use crate::Result;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Clone, Debug)]
+pub(crate) enum RecorderMode {
+    Record,
+    Replay,
+}
+
+/// Records api responses to a directory or replays them from it. Only response bodies are stored,
+/// request headers (which contain the authorization) are never written to disk. Tokens and url
+/// signatures are replaced with `REDACTED` in the stored request urls and json bodies (see
+/// [`redact_url`] and [`redact_json`]), requests are looked up by their redacted url when
+/// replaying.
+#[derive(Clone, Debug)]
+pub(crate) struct Recorder {
+    mode: RecorderMode,
+    directory: PathBuf,
+    storage: DiskCacheStorage,
+}
+
+/// Non-secret session information which is required to replay a recording.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub(crate) struct RecordedSession {
+    pub(crate) account_id: Option<String>,
+    pub(crate) benefits: Vec<String>,
+    pub(crate) bucket: String,
+}
+
+impl Recorder {
+    pub(crate) fn new(mode: RecorderMode, directory: PathBuf) -> Self {
+        Self {
+            mode,
+            storage: DiskCacheStorage::new(&directory),
+            directory,
+        }
+    }
+
+    pub(crate) fn is_replay(&self) -> bool {
+        matches!(self.mode, RecorderMode::Replay)
+    }
+
+    pub(crate) async fn record(&self, method: &Method, url: &str, body: Vec<u8>) -> Result<()> {
+        if !matches!(self.mode, RecorderMode::Record) {
+            return Ok(());
+        }
+        let body = match serde_json::from_slice::<serde_json::Value>(&body) {
+            Ok(mut json) => {
+                redact_json(&mut json);
+                serde_json::to_vec(&json)?
+            }
+            // raw data like subtitles or stream segments
+            Err(_) => body,
+        };
+        self.storage
+            .set(
+                &Self::key(method, url),
+                CachedResponse {
+                    body,
+                    etag: None,
+                    last_modified: None,
+                    fresh_until: None,
+                },
+            )
+            .await
+    }
+
+    pub(crate) async fn replay(&self, method: &Method, url: &str) -> Result<Vec<u8>> {
+        let key = Self::key(method, url);
+        self.storage
+            .get(&key)
+            .await
+            .map(|response| response.body)
+            .ok_or_else(|| Error::Input {
+                message: format!("no recorded response for '{key}'"),
+            })
+    }
+
+    fn key(method: &Method, url: &str) -> String {
+        format!("{method} {}", redact_url(url))
+    }
+
+    pub(crate) fn write_session(&self, session: &RecordedSession) -> Result<()> {
+        std::fs::create_dir_all(&self.directory).map_err(|e| Error::Internal {
+            message: format!("failed to create recording directory: {e}"),
+        })?;
+        std::fs::write(self.session_path(), serde_json::to_vec(session)?).map_err(|e| {
+            Error::Internal {
+                message: format!("failed to write recorded session: {e}"),
+            }
+        })
+    }
+
+    pub(crate) fn read_session(&self) -> Result<RecordedSession> {
+        let raw = std::fs::read(self.session_path()).map_err(|e| Error::Input {
+            message: format!("failed to read recorded session: {e}"),
+        })?;
+        Ok(serde_json::from_slice(&raw)?)
+    }
+
+    fn session_path(&self) -> PathBuf {
+        self.directory.join("session.json")
+    }
+}
@@ -0,0 +1,39 @@
+use std::sync::Mutex;
+
+/// Token bucket which limits how many requests can be retried. Every request deposits `ratio`
+/// tokens and every retry withdraws one, so retries can make up at most `ratio` of all requests
+/// (plus the initial reserve). This prevents retry storms from amplifying an outage.
+#[derive(Debug)]
+pub(crate) struct RetryBudget {
+    tokens: Mutex<f64>,
+    ratio: f64,
+    max_tokens: f64,
+}
+
+impl RetryBudget {
+    pub(crate) fn new(ratio: f64, max_tokens: u32) -> Self {
+        Self {
+            tokens: Mutex::new(max_tokens as f64),
+            ratio: ratio.clamp(0.0, 1.0),
+            max_tokens: max_tokens as f64,
+        }
+    }
+
+    /// Record a request which isn't a retry.
+    pub(crate) fn deposit(&self) {
+        let mut tokens = self.tokens.lock().unwrap();
+        *tokens = (*tokens + self.ratio).min(self.max_tokens)
+    }
+
+    /// Take a token for a retry. Returns `false` if the budget is exhausted and the request must
+    /// not be retried.
+    pub(crate) fn withdraw(&self) -> bool {
+        let mut tokens = self.tokens.lock().unwrap();
+        if *tokens >= 1.0 {
+            *tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
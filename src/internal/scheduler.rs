@@ -0,0 +1,108 @@
+use crate::crunchyroll::RequestPriority;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tokio::sync::oneshot;
+
+/// Limits how many requests can be in flight at the same time. If prioritization is enabled,
+/// waiting requests with a higher [`RequestPriority`] get a free slot before ones with a lower
+/// priority, otherwise slots are handed out in the order they were requested.
+#[derive(Debug)]
+pub(crate) struct RequestScheduler {
+    state: Mutex<SchedulerState>,
+    prioritize: bool,
+}
+
+#[derive(Debug)]
+struct SchedulerState {
+    available: usize,
+    /// Waiting requests, indexed by priority.
+    waiting: [VecDeque<oneshot::Sender<()>>; 3],
+}
+
+impl RequestScheduler {
+    pub(crate) fn new(max: usize, prioritize: bool) -> Self {
+        Self {
+            state: Mutex::new(SchedulerState {
+                available: max.max(1),
+                waiting: Default::default(),
+            }),
+            prioritize,
+        }
+    }
+
+    /// Wait until a slot is free. The slot is held until the returned permit is dropped.
+    pub(crate) async fn acquire(&self, priority: RequestPriority) -> SchedulerPermit<'_> {
+        let priority = if self.prioritize {
+            priority
+        } else {
+            RequestPriority::default()
+        };
+
+        let receiver = {
+            let mut state = self.state.lock().unwrap();
+            if state.available > 0 {
+                state.available -= 1;
+                return SchedulerPermit { scheduler: self };
+            }
+            let (sender, receiver) = oneshot::channel();
+            state.waiting[priority as usize].push_back(sender);
+            receiver
+        };
+
+        let mut waiter = Waiter {
+            scheduler: self,
+            receiver,
+            received: false,
+        };
+        // the sender is only dropped without sending if the scheduler is dropped, which can't
+        // happen while `self` is borrowed
+        let _ = (&mut waiter.receiver).await;
+        waiter.received = true;
+        SchedulerPermit { scheduler: self }
+    }
+
+    /// Hand the slot over to the waiting request with the highest priority or mark it as free if
+    /// no request is waiting.
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        for queue in state.waiting.iter_mut() {
+            while let Some(sender) = queue.pop_front() {
+                // fails if the waiting request was cancelled
+                if sender.send(()).is_ok() {
+                    return;
+                }
+            }
+        }
+        state.available += 1
+    }
+}
+
+/// A slot of a [`RequestScheduler`]. Frees the slot on drop.
+pub(crate) struct SchedulerPermit<'a> {
+    scheduler: &'a RequestScheduler,
+}
+
+impl Drop for SchedulerPermit<'_> {
+    fn drop(&mut self) {
+        self.scheduler.release()
+    }
+}
+
+/// Frees the slot if the waiting request got cancelled after the slot was handed over to it.
+struct Waiter<'a> {
+    scheduler: &'a RequestScheduler,
+    receiver: oneshot::Receiver<()>,
+    received: bool,
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        if self.received {
+            return;
+        }
+        self.receiver.close();
+        if self.receiver.try_recv().is_ok() {
+            self.scheduler.release()
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::{Request, Result};
 use chrono::Duration;
 use serde::de::{DeserializeOwned, Error as SerdeError};
 use serde::{Deserialize, Deserializer};
-use serde_json::{Map, Value};
+use serde_json::Value;
 use std::str::FromStr;
 
 #[derive(Request)]
@@ -119,7 +119,8 @@ where
     if value.is_object() {
         Ok(vec![])
     } else {
-        serde_json::from_value(value).map_err(|e| serde::de::Error::custom(e.to_string()))
+        crate::internal::strict::from_value(value)
+            .map_err(|e| serde::de::Error::custom(e.to_string()))
     }
 }
 
@@ -133,7 +134,8 @@ where
     if value.is_null() {
         Ok(T::default())
     } else {
-        serde_json::from_value(value).map_err(|e| serde::de::Error::custom(e.to_string()))
+        crate::internal::strict::from_value(value)
+            .map_err(|e| serde::de::Error::custom(e.to_string()))
     }
 }
 
@@ -177,7 +179,7 @@ pub(crate) fn deserialize_streams_link<'de, D: Deserializer<'de>>(
     Ok(as_string
         .trim_end_matches("/streams")
         .split('/')
-        .last()
+        .next_back()
         .ok_or_else(|| serde::de::Error::custom("cannot extract stream id"))?
         .to_string())
 }
@@ -187,14 +189,8 @@ where
     D: Deserializer<'de>,
     T: DeserializeOwned,
 {
-    let mut as_map = Map::deserialize(deserializer)?;
+    let panel = crate::media::Panel::deserialize(deserializer)?;
 
-    if let Some(mut episode_metadata) = as_map.remove("episode_metadata") {
-        as_map.append(episode_metadata.as_object_mut().unwrap())
-    }
-
-    serde_json::from_value(
-        serde_json::to_value(as_map).map_err(|e| SerdeError::custom(e.to_string()))?,
-    )
-    .map_err(|e| SerdeError::custom(e.to_string()))
+    crate::internal::strict::from_value(panel.into_value())
+        .map_err(|e| SerdeError::custom(e.to_string()))
 }
@@ -1,2 +1,326 @@
-#[allow(dead_code)]
-pub(crate) type StrictValue = serde_json::Value;
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, Deserializer, Error, IgnoredAny, IntoDeserializer,
+    MapAccess, SeqAccess, Visitor,
+};
+use serde::{forward_to_deserialize_any, Deserialize};
+use serde_json::{Map, Value};
+use std::cell::{Cell, RefCell};
+
+/// Placeholder for api fields which are known but not used. Its content is skipped while
+/// deserializing, the field only exists so that strict deserialization doesn't report it as
+/// unknown.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) struct StrictValue;
+
+impl<'de> Deserialize<'de> for StrictValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if is_active() {
+            // `IgnoredAny` would mark the field as unknown (see
+            // `TrackedValue::deserialize_ignored_any`)
+            deserializer.deserialize_any(Skip)?;
+        } else {
+            deserializer.deserialize_ignored_any(IgnoredAny)?;
+        }
+        Ok(StrictValue)
+    }
+}
+
+/// Like [`IgnoredAny`] but visits nested values via [`Deserializer::deserialize_any`].
+struct Skip;
+
+impl<'de> Deserialize<'de> for Skip {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(Skip)
+    }
+}
+
+impl<'de> Visitor<'de> for Skip {
+    type Value = Skip;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: Error>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(Skip)
+    }
+
+    fn visit_i64<E: Error>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(Skip)
+    }
+
+    fn visit_u64<E: Error>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(Skip)
+    }
+
+    fn visit_f64<E: Error>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(Skip)
+    }
+
+    fn visit_str<E: Error>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(Skip)
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Skip)
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Skip)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(Skip)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<Skip>()?.is_some() {}
+        Ok(Skip)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while map.next_entry::<Skip, Skip>()?.is_some() {}
+        Ok(Skip)
+    }
+}
+
+thread_local! {
+    /// Set while a value is deserialized by [`from_value_strict`]. Custom [`serde::Deserialize`]
+    /// implementations which buffer their input into a [`Value`] and deserialize it again must use
+    /// [`from_value`] / [`deserialize_tracked`], otherwise unknown fields in it can't be detected.
+    static ACTIVE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Deserialize `value` and fail if it contains fields which aren't used by `T` (like the
+/// `__test_strict` feature does at compile time). Fields starting and ending with `__` (e.g.
+/// `__links__`) are ignored. The returned error message contains the path of the field which
+/// caused the error.
+pub(crate) fn from_value_strict<T: DeserializeOwned>(value: Value) -> Result<T, String> {
+    let previous = ACTIVE.replace(true);
+    let result = tracked(value, |deserializer| T::deserialize(deserializer));
+    ACTIVE.set(previous);
+    result
+}
+
+/// Like [`serde_json::from_value`] but deserializes strictly if called while
+/// [`from_value_strict`] is running.
+pub(crate) fn from_value<T: DeserializeOwned>(value: Value) -> serde_json::Result<T> {
+    if is_active() {
+        from_value_strict(value).map_err(serde_json::Error::custom)
+    } else {
+        serde_json::from_value(value)
+    }
+}
+
+/// If [`from_value_strict`] is running.
+pub(crate) fn is_active() -> bool {
+    ACTIVE.get()
+}
+
+/// Deserialize `value` strictly with a custom deserialize function. Used for types which implement
+/// [`serde::Deserialize`] manually via `#[serde(remote = "Self")]`.
+pub(crate) fn deserialize_tracked<T>(
+    value: Value,
+    deserialize: impl FnOnce(TrackedValue<'_>) -> serde_json::Result<T>,
+) -> serde_json::Result<T> {
+    tracked(value, deserialize).map_err(serde_json::Error::custom)
+}
+
+fn tracked<T>(
+    value: Value,
+    deserialize: impl FnOnce(TrackedValue<'_>) -> serde_json::Result<T>,
+) -> Result<T, String> {
+    let tracker = Tracker::default();
+    let result = deserialize(TrackedValue {
+        value,
+        path: String::new(),
+        tracker: &tracker,
+    });
+
+    match result {
+        Ok(result) => {
+            let unknown = tracker.unknown.into_inner();
+            if unknown.is_empty() {
+                Ok(result)
+            } else {
+                Err(format!("unknown fields: {}", unknown.join(", ")))
+            }
+        }
+        Err(e) => {
+            let path = tracker.error_path.into_inner().unwrap_or_default();
+            if path.is_empty() {
+                Err(e.to_string())
+            } else {
+                Err(format!("{path}: {e}"))
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Tracker {
+    unknown: RefCell<Vec<String>>,
+    /// Path of the innermost value which failed to deserialize.
+    error_path: RefCell<Option<String>>,
+}
+
+impl Tracker {
+    fn failed_at(&self, path: &str) {
+        self.error_path
+            .borrow_mut()
+            .get_or_insert_with(|| path.to_string());
+    }
+}
+
+pub(crate) struct TrackedValue<'a> {
+    value: Value,
+    path: String,
+    tracker: &'a Tracker,
+}
+
+impl<'de> Deserializer<'de> for TrackedValue<'_> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let result = match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(b) => visitor.visit_bool(b),
+            Value::Number(n) => {
+                if let Some(u) = n.as_u64() {
+                    visitor.visit_u64(u)
+                } else if let Some(i) = n.as_i64() {
+                    visitor.visit_i64(i)
+                } else {
+                    visitor.visit_f64(n.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(s) => visitor.visit_string(s),
+            Value::Array(array) => visitor.visit_seq(TrackedSeq {
+                iter: array.into_iter().enumerate(),
+                path: &self.path,
+                tracker: self.tracker,
+            }),
+            Value::Object(object) => visitor.visit_map(TrackedMap {
+                iter: object.into_iter(),
+                value: None,
+                path: &self.path,
+                tracker: self.tracker,
+            }),
+        };
+        result.inspect_err(|_| self.tracker.failed_at(&self.path))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.value.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let path = self.path;
+        self.value
+            .deserialize_enum(name, variants, visitor)
+            .inspect_err(|_| self.tracker.failed_at(&path))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        // serde calls this for every field which isn't part of the struct which gets deserialized
+        self.tracker.unknown.borrow_mut().push(self.path);
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+struct TrackedSeq<'a, I> {
+    iter: I,
+    path: &'a str,
+    tracker: &'a Tracker,
+}
+
+impl<'de, I: Iterator<Item = (usize, Value)>> SeqAccess<'de> for TrackedSeq<'_, I> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let Some((i, value)) = self.iter.next() else {
+            return Ok(None);
+        };
+        let path = format!("{}[{i}]", self.path);
+        seed.deserialize(TrackedValue {
+            value,
+            path: path.clone(),
+            tracker: self.tracker,
+        })
+        .inspect_err(|_| self.tracker.failed_at(&path))
+        .map(Some)
+    }
+}
+
+struct TrackedMap<'a> {
+    iter: <Map<String, Value> as IntoIterator>::IntoIter,
+    value: Option<(String, Value)>,
+    path: &'a str,
+    tracker: &'a Tracker,
+}
+
+impl<'de> MapAccess<'de> for TrackedMap<'_> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        loop {
+            let Some((key, value)) = self.iter.next() else {
+                return Ok(None);
+            };
+            // these are api internal fields which are never used
+            if key.starts_with("__") && key.ends_with("__") {
+                continue;
+            }
+            self.value = Some((key.clone(), value));
+            return seed.deserialize(key.into_deserializer()).map(Some);
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let Some((key, value)) = self.value.take() else {
+            return Err(Self::Error::custom("value is missing"));
+        };
+        let path = if self.path.is_empty() {
+            key
+        } else {
+            format!("{}.{key}", self.path)
+        };
+        seed.deserialize(TrackedValue {
+            value,
+            path: path.clone(),
+            tracker: self.tracker,
+        })
+        .inspect_err(|_| self.tracker.failed_at(&path))
+    }
+}
@@ -0,0 +1,28 @@
+use crate::internal::redact::redact_url;
+use chrono::{DateTime, Utc};
+use reqwest::{Request, Response};
+use tracing::Span;
+
+/// Record the method and endpoint of `req` in the current span. Credentials in the endpoint are
+/// redacted.
+pub(crate) fn record_request(req: &Request) {
+    let span = Span::current();
+    span.record("method", req.method().as_str());
+    span.record("endpoint", redact_url(req.url().as_str()));
+}
+
+/// Record the status, the body size (if known) and the time it took until the response arrived in
+/// the current span.
+pub(crate) fn record_response(resp: &Response, start: DateTime<Utc>) {
+    let span = Span::current();
+    span.record("status", resp.status().as_u16());
+    span.record("duration_ms", (Utc::now() - start).num_milliseconds());
+    if let Some(bytes) = resp.content_length() {
+        span.record("bytes", bytes);
+    }
+}
+
+/// Record how the response cache was used in the current span (`hit`, `revalidated` or `miss`).
+pub(crate) fn record_cache(usage: &str) {
+    Span::current().record("cache", usage);
+}
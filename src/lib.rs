@@ -51,6 +51,7 @@
 //! let seasons = series
 //!     // get the seasons of this series
 //!     .seasons()
+//!     .try_collect::<Vec<_>>()
 //!     .await?;
 //! ```
 //!
@@ -111,42 +112,137 @@
 //! occurs depending on different factors like your location. If such a block occurs you can try to
 //! create a custom [`reqwest::Client`] which has the needed configuration to bypass this check,
 //! like other user agents or tls backends (note that [`reqwest`] currently only supports
-//! [`native-tls`](https://docs.rs/native-tls/latest/native_tls/) besides [`rustls`] as tls backend,
-//! which is confirmed to work with openssl on Linux only, on Windows the blocks are even more
-//! aggressive). The configurations may vary on the factors addressed so there is no 100% right way
-//! to do it.
+//! [`native-tls`](https://docs.rs/native-tls/latest/native_tls/) (see the **native-tls**
+//! [feature](#features)) besides [`rustls`](https://docs.rs/rustls) as tls backend, which is
+//! confirmed to work with openssl on Linux only, on Windows the blocks are even more aggressive).
+//! The configurations may vary on the factors addressed so there is no 100% right way to do it.
 //!
 //! # Features
 //!
 //! - **parse** *(enabled by default)*: Enables url parsing.
+//! - **streaming** *(enabled by default)*: Enables access to streams, subtitles and stream
+//!   segments (e.g. [`Episode::stream`]). Disable it if only metadata (search, browse, ...) is
+//!   needed to cut compile time and binary size.
+//! - **rustls-tls** *(enabled by default)*: Uses [rustls](https://docs.rs/rustls) with bundled root
+//!   certificates as tls backend. Works without a system certificate store, e.g. in static musl
+//!   builds or minimal containers.
+//! - **native-tls**: Uses the platform tls stack as tls backend. If **rustls-tls** is enabled
+//!   too, rustls is preferred, so disable the default features to use it. Custom root
+//!   certificates can be added with
+//!   [`crunchyroll::CrunchyrollBuilder::predefined_client_builder_with_root_certificates`].
+//! - **http3**: Enables http/3 for raw downloads like stream segments (see
+//!   [`crunchyroll::CrunchyrollBuilder::http3_downloads`]). Requires the unstable http/3 support
+//!   of [`reqwest`], so `RUSTFLAGS="--cfg reqwest_unstable"` must be set. Implies **rustls-tls**.
 //! - **tower**: Enables the usage of a [tower](https://docs.rs/tower) compatible middleware.
+//! - **blocking**: Enables a synchronous wrapper around the async api
+//!   ([`blocking::Crunchyroll`]).
+//! - **crawler**: Enables [`crawler::Crawler`] which walks the whole browse catalog with rate
+//!   limiting and resumable checkpoints.
+//! - **external-ids**: Enables mapping of Crunchyroll media to external databases like
+//!   MyAnimeList or AniList via pluggable resolvers.
+//! - **heartbeat**: Enables [`media::Stream::heartbeat`] which keeps the stream session alive and
+//!   reports the playhead in the background while a stream is played. Implies **streaming**.
+//! - **image-convert**: Enables [`common::Image::download_converted`] which converts downloaded
+//!   posters / thumbnails to another image format and optionally resizes them.
+//! - **m3u**: Enables exporting seasons and crunchylists as M3U playlists for players like VLC or
+//!   mpv (see [`playlist::M3uPlaylist`]). Implies **streaming**.
+//! - **manifest**: Enables [`manifest::DownloadManifest`] which lists downloaded segments / files
+//!   with their SHA-256 checksums to verify the integrity of archives later. Implies
+//!   **streaming**.
+//! - **metrics**: Collects request counts, error rates, downloaded bytes, token refreshes and
+//!   per-endpoint latency histograms which can be read via [`Crunchyroll::metrics`].
+//! - **nfo**: Enables exporting series, seasons, episodes and movies as `.nfo` metadata files
+//!   which are read by media centers like Kodi or Jellyfin (see [`nfo::Nfo`]).
+//! - **object-cache**: Enables [`object_cache::ObjectCache`] which caches series, seasons,
+//!   episodes, movie listings and movies on disk by their id, so that repeated runs don't request
+//!   unchanged metadata again. Implies **raw-json**.
+//! - **extra-fields**: Preserves api fields of media objects (series, episodes, ...) which aren't
+//!   modelled by this crate (yet) in an `extra` map, e.g. [`Series::extra`].
+//! - **raw-json**: Keeps the original json of media objects (series, episodes, ...) which can be
+//!   accessed via `.raw()`, e.g. [`Series::raw`]. Useful to read fields which aren't modelled by
+//!   this crate yet or to attach the json to bug reports.
+//! - **simd-json**: Parses api responses with [simd-json](https://docs.rs/simd-json) instead of
+//!   serde_json, which speeds up large browse / search crawls. Response bodies are parsed in
+//!   place, so [`error::Error::Decode`] errors don't contain the body. Ignored on wasm. Use the
+//!   `decode-benchmark` example to compare both backends with your own recorded responses.
+//! - **replay**: Enables recording api responses to disk and replaying them later without any
+//!   network access or credentials (see
+//!   [`crunchyroll::CrunchyrollBuilder::record_responses`]).
+//! - **watcher**: Enables [`watcher::Watcher`] which periodically checks series (or the whole
+//!   watchlist) for new episodes, seasons and dubs.
+//! - **rss**: Enables [`watcher::EpisodeFeed`] which renders new episodes as RSS / Atom feed.
+//!   Implies **watcher**.
+//! - **tracing**: Instruments all api requests, token refreshes and stream operations with
+//!   [tracing](https://docs.rs/tracing) spans (`endpoint`, `status`, `duration_ms`, `bytes`, ...)
+//!   on the `debug` level.
 //! - **experimental-stabilizations**: Provides some functions to maybe fix broken api results. See
 //!   [Bugs](#bugs) for more information.
 //!
+//! # WASM
+//! The crate can be compiled for `wasm32-unknown-unknown`. Tls and cookies are handled by the
+//! browser / runtime there, so [`crunchyroll::CrunchyrollBuilder::predefined_client_builder`] only
+//! sets the user agent. The **blocking** and **heartbeat** features aren't supported on wasm and
+//! are ignored there, as are request retries and the `run` functions of the **crawler** and
+//! **watcher** features which need a timer. Images are converted on the current thread
+//! instead of a blocking thread pool.
+//!
 //! # Implementation
 //! To ensure at least all existing parts of the library are working as expected, a special feature
 //! only for testing is implemented. When running tests with the `__test_strict` feature, it ensures
 //! that no fields were added or removed from an api response, otherwise the associated test will
 //! fail.
 //!
+//! Unknown fields can also be detected at runtime, without recompiling, with
+//! [`crunchyroll::CrunchyrollBuilder::strict_deserialization`].
+//!
 //! [DASH]: https://en.wikipedia.org/wiki/Dynamic_Adaptive_Streaming_over_HTTP
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod account;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+pub mod cache;
 pub mod categories;
 pub mod common;
+#[cfg(feature = "crawler")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crawler")))]
+pub mod crawler;
 pub mod crunchyroll;
 pub mod devices;
 pub mod error;
+pub mod events;
+#[cfg(feature = "external-ids")]
+#[cfg_attr(docsrs, doc(cfg(feature = "external-ids")))]
+pub mod external;
 pub mod feed;
 pub mod list;
+#[cfg(feature = "manifest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "manifest")))]
+pub mod manifest;
 pub mod media;
+#[cfg(feature = "metrics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+pub mod metrics;
+pub mod news;
+#[cfg(feature = "nfo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nfo")))]
+pub mod nfo;
+#[cfg(feature = "object-cache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "object-cache")))]
+pub mod object_cache;
 #[cfg(feature = "parse")]
 #[cfg_attr(docsrs, doc(cfg(feature = "parse")))]
 pub mod parse;
+#[cfg(feature = "m3u")]
+#[cfg_attr(docsrs, doc(cfg(feature = "m3u")))]
+pub mod playlist;
 pub mod profile;
 pub mod search;
+#[cfg(feature = "watcher")]
+#[cfg_attr(docsrs, doc(cfg(feature = "watcher")))]
+pub mod watcher;
 
 // internal
 mod internal;
@@ -166,5 +262,4 @@ pub use media::{
 #[cfg(feature = "parse")]
 pub use parse::{parse_url, UrlType};
 
-#[cfg(feature = "__test_strict")]
 use internal::strict::StrictValue;
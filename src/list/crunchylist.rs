@@ -22,6 +22,7 @@ pub struct CrunchylistEntry {
     pub modified_at: DateTime<Utc>,
 
     /// Should only be [`MediaCollection::Series`] or [`MediaCollection::MovieListing`].
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_panel")]
     pub panel: MediaCollection,
 }
 
@@ -41,6 +42,38 @@ impl CrunchylistEntry {
             .await?;
         Ok(())
     }
+
+    /// Move this entry in front of `other` in the parent crunchylist.
+    pub async fn move_before(&self, other: &CrunchylistEntry) -> Result<()> {
+        self.move_relative_to(other, "before").await
+    }
+
+    /// Move this entry behind `other` in the parent crunchylist.
+    pub async fn move_after(&self, other: &CrunchylistEntry) -> Result<()> {
+        self.move_relative_to(other, "after").await
+    }
+
+    async fn move_relative_to(&self, other: &CrunchylistEntry, location: &str) -> Result<()> {
+        if self.list_id != other.list_id {
+            return Err(Error::Input {
+                message: "entries must be in the same crunchylist".to_string(),
+            });
+        }
+
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/{}/custom-lists/{}/{}/position",
+            self.executor.details.account_id.clone()?,
+            self.list_id,
+            self.id
+        );
+        self.executor
+            .put(endpoint)
+            .json(&json!({ "location": location, "ref_list_item_id": other.id }))
+            .apply_locale_query()
+            .request::<EmptyJsonProxy>()
+            .await?;
+        Ok(())
+    }
 }
 
 /// Representation of Crunchylists / custom lists you can create to store series or movies in.
@@ -151,6 +184,45 @@ impl Crunchylist {
         Ok(())
     }
 
+    /// Reorder the entries of this crunchylist to match the order of `order` (like the drag and
+    /// drop sorting on the website). [`Crunchylist::items`] are always in the current custom
+    /// order. Entries which are not contained in `order` are placed behind the given entries.
+    /// Every entry which isn't already in the right position requires a separate request.
+    pub async fn reorder(&self, order: &[CrunchylistEntry]) -> Result<()> {
+        if let Some(entry) = order.iter().find(|e| e.list_id != self.id) {
+            return Err(Error::Input {
+                message: format!("entry '{}' isn't part of this crunchylist", entry.id),
+            });
+        }
+
+        // the current order, updated locally after every move to detect entries which are
+        // already in position
+        let mut current: Vec<&str> = self.items.iter().map(|e| e.id.as_str()).collect();
+
+        if let (Some(first), Some(current_first)) = (order.first(), self.items.first()) {
+            if first.id != current_first.id {
+                first.move_before(current_first).await?;
+                current.retain(|id| *id != first.id);
+                current.insert(0, &first.id)
+            }
+        }
+        for window in order.windows(2) {
+            let (previous, entry) = (&window[0], &window[1]);
+            let previous_position = current.iter().position(|id| *id == previous.id);
+            if previous_position.is_some_and(|p| current.get(p + 1) == Some(&entry.id.as_str())) {
+                continue;
+            }
+            entry.move_after(previous).await?;
+            current.retain(|id| *id != entry.id);
+            let position = current
+                .iter()
+                .position(|id| *id == previous.id)
+                .map_or(current.len(), |p| p + 1);
+            current.insert(position, &entry.id)
+        }
+        Ok(())
+    }
+
     /// Rename the current crunchylist.
     pub async fn rename<S: AsRef<str>>(&self, name: S) -> Result<()> {
         let endpoint = format!(
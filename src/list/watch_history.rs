@@ -1,15 +1,19 @@
 use crate::common::{Pagination, PaginationBulkResultMeta, V2BulkResult};
-use crate::{Crunchyroll, EmptyJsonProxy, MediaCollection, Request, Result};
+use crate::{Crunchyroll, EmptyJsonProxy, Executor, MediaCollection, Request, Result};
 use chrono::{DateTime, Utc};
 use futures_util::FutureExt;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
-/// Entry of your watchlist.
+/// Entry of your watch history.
 #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault, Request)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
 #[cfg_attr(not(feature = "__test_strict"), serde(default))]
 #[request(executor(panel))]
 pub struct WatchHistoryEntry {
+    #[serde(skip)]
+    executor: Arc<Executor>,
+
     /// Id of the episode or movie entry.
     pub id: String,
     pub parent_id: String,
@@ -23,11 +27,31 @@ pub struct WatchHistoryEntry {
     pub fully_watched: bool,
 
     /// Should always be [`MediaCollection::Episode`] or [`MediaCollection::Movie`].
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_panel")]
     pub panel: MediaCollection,
 }
 
+impl WatchHistoryEntry {
+    /// Remove this entry from your watch history.
+    pub async fn remove(self) -> Result<()> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/{}/watch-history/{}",
+            self.executor.details.account_id.clone()?,
+            self.id
+        );
+        self.executor
+            .delete(endpoint)
+            .apply_locale_query()
+            .request::<EmptyJsonProxy>()
+            .await?;
+        Ok(())
+    }
+}
+
 impl Crunchyroll {
-    /// Get the history which episodes / movies you've watched.
+    /// Get the history which episodes / movies you've watched, newest first. Use
+    /// [`Pagination::order`] with [`crate::common::PaginationOrder::Ascending`] to get the oldest
+    /// entries first.
     pub fn watch_history(&self) -> Pagination<WatchHistoryEntry> {
         Pagination::new(
             |options| {
@@ -40,6 +64,7 @@ impl Crunchyroll {
                         .executor
                         .get(endpoint)
                         .query(&[("page", options.page), ("page_size", options.page_size)])
+                        .query(&options.order_query())
                         .apply_locale_query()
                         .request()
                         .await?;
@@ -51,6 +76,7 @@ impl Crunchyroll {
             None,
             None,
         )
+        .with_order_support()
     }
 
     /// Clear your watch history.
@@ -1,9 +1,10 @@
-use crate::common::V2BulkResult;
+use crate::common::{Pagination, PaginationBulkResultMeta, V2BulkResult};
 use crate::error::Error;
 use crate::{
     enum_values, options, Crunchyroll, EmptyJsonProxy, Executor, MediaCollection, Request, Result,
 };
 use chrono::{DateTime, Utc};
+use futures_util::FutureExt;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::sync::Arc;
@@ -27,6 +28,7 @@ pub struct WatchlistEntry {
     pub playhead: u32,
 
     /// Should only be [`MediaCollection::Series`] or [`MediaCollection::MovieListing`].
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_panel")]
     pub panel: MediaCollection,
 }
 
@@ -43,7 +45,7 @@ impl WatchlistEntry {
     /// Remove this entry from your watchlist.
     pub async fn remove(self) -> Result<()> {
         let id = self.get_id()?;
-        remove_from_watchlist(self.executor, id).await
+        remove_from_watchlist(&self.executor, id).await
     }
 
     /// Get the media id of the series / movie listing which represents this entry.
@@ -86,10 +88,22 @@ impl SimpleWatchlistEntry {
 
     /// Remove this entry from your watchlist.
     pub async fn remove(self) -> Result<()> {
-        remove_from_watchlist(self.executor, self.id).await
+        remove_from_watchlist(&self.executor, self.id).await
     }
 }
 
+/// Watchlist state of a series or movie listing. Returned by [`Crunchyroll::watchlist_status`].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct WatchlistStatus {
+    pub id: String,
+
+    /// If the series / movie listing is on your watchlist.
+    pub on_watchlist: bool,
+    /// If the series / movie listing is marked as favorite. Always `false` if
+    /// [`WatchlistStatus::on_watchlist`] is `false`.
+    pub is_favorite: bool,
+}
+
 enum_values! {
     /// Filter how to sort watchlist entries when querying.
     pub enum WatchlistSort {
@@ -127,49 +141,115 @@ options! {
 }
 
 impl Crunchyroll {
-    /// Returns your watchlist.
-    pub async fn watchlist(&self, mut options: WatchlistOptions) -> Result<Vec<WatchlistEntry>> {
-        let true_string = true.to_string();
-        let language_field = if let Some(language) = options.language {
-            match language {
-                WatchlistLanguage::Subbed => ("is_subbed", true_string.as_str()),
-                WatchlistLanguage::Dubbed => ("is_dubbed", true_string.as_str()),
-                _ => ("", ""),
-            }
-        } else {
-            ("", "")
+    /// Returns your watchlist. Unlike crunchylists (see [`crate::list::Crunchylist::reorder`]),
+    /// the watchlist has no user defined order, the entries are always sorted by
+    /// [`WatchlistOptions::sort`] in [`WatchlistOptions::order`].
+    pub fn watchlist(&self, mut options: WatchlistOptions) -> Pagination<WatchlistEntry> {
+        let language_field = match options.language.take() {
+            Some(WatchlistLanguage::Subbed) => Some(("is_subbed".to_string(), true.to_string())),
+            Some(WatchlistLanguage::Dubbed) => Some(("is_dubbed".to_string(), true.to_string())),
+            _ => None,
         };
-        options.language = None;
+        let mut query = options.into_query();
+        query.extend(language_field);
+
+        Pagination::new(
+            |options| {
+                async move {
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content/v2/discover/{}/watchlist",
+                        options.executor.details.account_id.clone()?
+                    );
+                    let result: V2BulkResult<WatchlistEntry, PaginationBulkResultMeta> = options
+                        .executor
+                        .get(endpoint)
+                        .query(&options.query)
+                        .query(&[("n", options.page_size), ("start", options.start)])
+                        .apply_locale_query()
+                        .request()
+                        .await?;
+                    Ok(result.into())
+                }
+                .boxed()
+            },
+            self.executor.clone(),
+            Some(query),
+            None,
+        )
+    }
+
+    /// Add multiple series or movie listings to your watchlist at once. `ids` must be ids of
+    /// [`crate::Series`] or [`crate::MovieListing`]. The ids are added one after another and the
+    /// first error is returned, ids before the failed one stay on the watchlist.
+    pub async fn add_to_watchlist<S: AsRef<str>>(&self, ids: &[S]) -> Result<()> {
+        for id in ids {
+            add_to_watchlist(&self.executor, id.as_ref().to_string()).await?
+        }
+        Ok(())
+    }
 
+    /// Remove multiple series or movie listings from your watchlist at once. `ids` must be ids of
+    /// [`crate::Series`] or [`crate::MovieListing`]. The ids are removed one after another and the
+    /// first error is returned, ids before the failed one stay removed.
+    pub async fn remove_from_watchlist<S: AsRef<str>>(&self, ids: &[S]) -> Result<()> {
+        for id in ids {
+            remove_from_watchlist(&self.executor, id.as_ref().to_string()).await?
+        }
+        Ok(())
+    }
+
+    /// Check which of the given series or movie listing ids are on your watchlist. Only ids which
+    /// are on the watchlist are contained in the result.
+    pub async fn watchlist_entries<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+    ) -> Result<Vec<SimpleWatchlistEntry>> {
         let endpoint = format!(
-            "https://www.crunchyroll.com/content/v2/discover/{}/watchlist",
+            "https://www.crunchyroll.com/content/v2/{}/watchlist",
             self.executor.details.account_id.clone()?
         );
+        let content_ids = ids.iter().map(|id| id.as_ref()).collect::<Vec<&str>>();
         Ok(self
             .executor
             .get(endpoint)
-            .query(&options.into_query())
-            .query(&[language_field])
+            .query(&[("content_ids", content_ids.join(","))])
             .apply_locale_query()
-            .request::<V2BulkResult<WatchlistEntry>>()
+            .request::<V2BulkResult<SimpleWatchlistEntry>>()
             .await?
             .data)
     }
+
+    /// Check for every given series or movie listing id if it is on your watchlist. Unlike
+    /// [`Crunchyroll::watchlist_entries`], the result contains an entry for every id, in the same
+    /// order as the given ids.
+    pub async fn watchlist_status<S: AsRef<str>>(&self, ids: &[S]) -> Result<Vec<WatchlistStatus>> {
+        let entries = self.watchlist_entries(ids).await?;
+        Ok(ids
+            .iter()
+            .map(|id| {
+                let entry = entries.iter().find(|e| e.id == id.as_ref());
+                WatchlistStatus {
+                    id: id.as_ref().to_string(),
+                    on_watchlist: entry.is_some(),
+                    is_favorite: entry.is_some_and(|e| e.is_favorite),
+                }
+            })
+            .collect())
+    }
 }
 
 macro_rules! add_to_watchlist {
-    ($(#[doc = $add:literal] #[doc = $as:literal] $s:path);*) => {
+    ($(#[doc = $add:literal] #[doc = $remove:literal] #[doc = $as:literal] $s:path);*) => {
         $(
             impl $s {
                 #[doc = $add]
                 pub async fn add_to_watchlist(&self) -> Result<()> {
-                    let endpoint = format!("https://www.crunchyroll.com/content/v2/{}/watchlist", self.executor.details.account_id.clone()?);
-                    let _: EmptyJsonProxy = self.executor.post(endpoint)
-                        .json(&json!({"content_id": &self.id}))
-                        .apply_locale_query()
-                        .request()
-                        .await?;
-                    Ok(())
+                    add_to_watchlist(&self.executor, self.id.clone()).await
+                }
+
+                #[doc = $remove]
+                pub async fn remove_from_watchlist(&self) -> Result<()> {
+                    remove_from_watchlist(&self.executor, self.id.clone()).await
                 }
 
                 #[doc = $as]
@@ -192,13 +272,29 @@ macro_rules! add_to_watchlist {
 
 add_to_watchlist! {
     #[doc = "Add this series to your watchlist."]
+    #[doc = "Remove this series from your watchlist."]
     #[doc = "Check and convert this series to a watchlist entry (to check if this series was watched before)."]
     crate::media::Series;
     #[doc = "Add this movie to your watchlist."]
+    #[doc = "Remove this movie from your watchlist."]
     #[doc = "Check and convert this movie to a watchlist entry (to check if this movie was watched before)."]
     crate::media::MovieListing
 }
 
+async fn add_to_watchlist(executor: &Arc<Executor>, id: String) -> Result<()> {
+    let endpoint = format!(
+        "https://www.crunchyroll.com/content/v2/{}/watchlist",
+        executor.details.account_id.clone()?
+    );
+    executor
+        .post(endpoint)
+        .json(&json!({ "content_id": id }))
+        .apply_locale_query()
+        .request::<EmptyJsonProxy>()
+        .await?;
+    Ok(())
+}
+
 async fn mark_favorite_watchlist(
     executor: &Arc<Executor>,
     id: String,
@@ -217,7 +313,7 @@ async fn mark_favorite_watchlist(
     Ok(())
 }
 
-async fn remove_from_watchlist(executor: Arc<Executor>, id: String) -> Result<()> {
+async fn remove_from_watchlist(executor: &Arc<Executor>, id: String) -> Result<()> {
     let endpoint = format!(
         "https://www.crunchyroll.com/content/v2/{}/watchlist/{}",
         executor.details.account_id.clone()?,
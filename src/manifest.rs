@@ -0,0 +1,207 @@
+//! Manifests of downloaded stream data for later integrity verification.
+
+use crate::error::Error;
+use crate::media::{Resolution, StreamData, StreamDataInfo, StreamSegment, Subtitle};
+use crate::{Locale, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// What kind of data a [`ManifestEntry`] contains.
+#[cfg_attr(docsrs, doc(cfg(feature = "manifest")))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ManifestVariant {
+    Video {
+        bandwidth: u64,
+        codecs: String,
+        resolution: Resolution,
+        fps: f64,
+    },
+    Audio {
+        bandwidth: u64,
+        codecs: String,
+        sampling_rate: u32,
+    },
+    Subtitle {
+        locale: Locale,
+        format: String,
+    },
+}
+
+impl From<&StreamData> for ManifestVariant {
+    fn from(stream_data: &StreamData) -> Self {
+        match &stream_data.info {
+            StreamDataInfo::Video { resolution, fps } => ManifestVariant::Video {
+                bandwidth: stream_data.bandwidth,
+                codecs: stream_data.codecs.clone(),
+                resolution: resolution.clone(),
+                fps: *fps,
+            },
+            StreamDataInfo::Audio { sampling_rate } => ManifestVariant::Audio {
+                bandwidth: stream_data.bandwidth,
+                codecs: stream_data.codecs.clone(),
+                sampling_rate: *sampling_rate,
+            },
+        }
+    }
+}
+
+impl From<&Subtitle> for ManifestVariant {
+    fn from(subtitle: &Subtitle) -> Self {
+        ManifestVariant::Subtitle {
+            locale: subtitle.locale.clone(),
+            format: subtitle.format.clone(),
+        }
+    }
+}
+
+/// A single downloaded segment / file.
+#[cfg_attr(docsrs, doc(cfg(feature = "manifest")))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ManifestEntry {
+    /// Url the data was downloaded from. The query is stripped as it contains (expiring) access
+    /// signatures.
+    pub url: String,
+    /// Path of the file the data was written to. Relative paths are resolved against the directory
+    /// passed to [`DownloadManifest::verify_files`]. [`None`] if the data wasn't written to a
+    /// dedicated file (e.g. because all segments were concatenated).
+    pub path: Option<String>,
+    /// Size of the data in bytes.
+    pub size: u64,
+    /// Hex encoded SHA-256 checksum of the data.
+    pub sha256: String,
+    pub variant: Option<ManifestVariant>,
+}
+
+impl ManifestEntry {
+    /// Create an entry for arbitrary downloaded data.
+    pub fn new(url: impl AsRef<str>, data: &[u8]) -> Self {
+        let url = url.as_ref();
+        Self {
+            url: url.split('?').next().unwrap_or(url).to_string(),
+            path: None,
+            size: data.len() as u64,
+            sha256: sha256(data),
+            variant: None,
+        }
+    }
+
+    /// Create an entry for a downloaded segment of `stream_data`.
+    pub fn segment(stream_data: &StreamData, segment: &StreamSegment, data: &[u8]) -> Self {
+        Self::new(&segment.url, data).variant(stream_data.into())
+    }
+
+    /// Create an entry for a downloaded subtitle.
+    pub fn subtitle(subtitle: &Subtitle, data: &[u8]) -> Self {
+        Self::new(&subtitle.url, data).variant(subtitle.into())
+    }
+
+    /// Set the path of the file the data was written to.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set the variant details of the data.
+    pub fn variant(mut self, variant: ManifestVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Check if `data` matches the size and checksum of this entry.
+    pub fn verify(&self, data: &[u8]) -> bool {
+        self.size == data.len() as u64 && self.sha256 == sha256(data)
+    }
+}
+
+/// List of downloaded segments / files with their checksums, to verify the integrity of archives
+/// later.
+///
+/// ```no_run
+/// use crunchyroll_rs::manifest::{DownloadManifest, ManifestEntry};
+///
+/// # async fn f(stream_data: crunchyroll_rs::media::StreamData) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut manifest = DownloadManifest::new();
+/// for (i, segment) in stream_data.segments().iter().enumerate() {
+///     let data = segment.data().await?;
+///     let path = format!("segment-{i}.m4s");
+///     std::fs::write(&path, &data)?;
+///     manifest.push(ManifestEntry::segment(&stream_data, segment, &data).path(path))
+/// }
+/// std::fs::write("manifest.json", manifest.to_json())?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "manifest")))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DownloadManifest {
+    pub created_at: DateTime<Utc>,
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl Default for DownloadManifest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DownloadManifest {
+    /// Creates an empty manifest.
+    pub fn new() -> Self {
+        Self {
+            created_at: Utc::now(),
+            entries: vec![],
+        }
+    }
+
+    /// Add an entry to the manifest.
+    pub fn push(&mut self, entry: ManifestEntry) {
+        self.entries.push(entry)
+    }
+
+    /// Serialize the manifest as (pretty printed) json.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Read a manifest which was created via [`DownloadManifest::to_json`].
+    pub fn from_json(json: impl AsRef<[u8]>) -> Result<Self> {
+        let json = json.as_ref();
+        serde_json::from_slice(json).map_err(|e| Error::Decode {
+            message: e.to_string(),
+            content: json.to_vec(),
+            url: String::new(),
+        })
+    }
+
+    /// Check all entries which have a [`ManifestEntry::path`] against the files in `directory`.
+    /// Returns the entries whose file is missing or doesn't match the size / checksum. Not
+    /// supported on wasm.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn verify_files(&self, directory: impl AsRef<std::path::Path>) -> Vec<&ManifestEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                let Some(path) = &entry.path else {
+                    return false;
+                };
+                match std::fs::read(directory.as_ref().join(path)) {
+                    Ok(data) => !entry.verify(&data),
+                    Err(_) => true,
+                }
+            })
+            .collect()
+    }
+}
+
+fn sha256(data: &[u8]) -> String {
+    let digest = ring::digest::digest(&ring::digest::SHA256, data);
+    digest
+        .as_ref()
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
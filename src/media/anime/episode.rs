@@ -1,11 +1,19 @@
 use crate::common::{Image, Request};
 use crate::crunchyroll::Executor;
+#[cfg(feature = "streaming")]
+use crate::error::Error;
 use crate::media::anime::util::{fix_empty_episode_versions, fix_empty_season_versions};
-use crate::media::util::request_media;
+use crate::media::util::{request_media, request_media_object};
 use crate::media::Media;
-use crate::{Crunchyroll, Locale, Result, Season, Series};
+use crate::{Crunchyroll, Locale, MediaCollection, Result, Season, Series};
 use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "streaming")]
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "streaming")]
+use std::collections::HashMap;
+#[cfg(feature = "streaming")]
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
@@ -27,11 +35,17 @@ pub struct EpisodeVersion {
     pub is_premium_only: bool,
     pub original: bool,
 
-    #[cfg(feature = "__test_strict")]
-    pub(crate) variant: crate::StrictValue,
+    #[serde(skip_serializing)]
+    pub(crate) variant: Option<String>,
 }
 
 impl EpisodeVersion {
+    /// If this version is audio described, i.e. contains a narration of the visual content for
+    /// blind and visually impaired viewers.
+    pub fn is_audio_description(&self) -> bool {
+        crate::media::util::is_audio_description_variant(&self.variant)
+    }
+
     /// Requests an actual [`Episode`] from this version.
     pub async fn episode(&self) -> Result<Episode> {
         Episode::from_id(
@@ -42,6 +56,21 @@ impl EpisodeVersion {
         )
         .await
     }
+
+    /// Streams of this version. Shortcut for calling [`EpisodeVersion::episode`] and then
+    /// [`Episode::stream`] without requesting the episode.
+    #[cfg(feature = "streaming")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "streaming")))]
+    pub async fn stream(&self) -> Result<crate::media::Stream> {
+        crate::media::Stream::from_id_web_chrome(
+            &Crunchyroll {
+                executor: self.executor.clone(),
+            },
+            &self.id,
+            None,
+        )
+        .await
+    }
 }
 
 /// Metadata for an episode.
@@ -54,6 +83,10 @@ pub struct Episode {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
 
+    #[cfg(feature = "raw-json")]
+    #[serde(skip)]
+    pub(crate) raw: serde_json::Value,
+
     pub id: String,
     #[serde(alias = "streams_link")]
     #[serde(deserialize_with = "crate::internal::serde::deserialize_streams_link")]
@@ -64,6 +97,14 @@ pub struct Episode {
     pub slug: String,
     pub title: String,
     pub slug_title: String,
+    /// Title which is optimized for search engines. Might be empty.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub seo_title: String,
+    /// Description which is optimized for search engines. Might be empty.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub seo_description: String,
     pub description: String,
 
     // both missing if the episode is the last one in its season unpopulated
@@ -107,7 +148,7 @@ pub struct Episode {
 
     /// Descriptors about the episode content, e.g. 'Violence' or 'Sexualized Imagery'.
     #[serde(default)]
-    pub content_descriptors: Vec<String>,
+    pub content_descriptors: Vec<crate::media::ContentDescriptor>,
 
     #[serde(alias = "duration_ms")]
     #[serde(deserialize_with = "crate::internal::serde::deserialize_millis_to_duration")]
@@ -120,12 +161,25 @@ pub struct Episode {
     /// The same as episode_air_date as far as I can see.
     #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
     pub upload_date: DateTime<Utc>,
+    /// Date from which on the episode is available without premium. Use
+    /// [`Episode::free_available_at`] to check if it will ever be available for free.
     #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
     pub free_available_date: DateTime<Utc>,
+    /// Date from which on the episode is available with premium.
     #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
     pub premium_available_date: DateTime<Utc>,
+    /// Date from which on the episode is listed without premium. Keeps the raw string Crunchyroll
+    /// returned, [`crate::common::LazyDateTime::get`] is [`None`] if it wasn't set.
+    #[serde(default)]
+    pub available_date: crate::common::LazyDateTime,
+    /// Date from which on the episode is listed with premium. Keeps the raw string Crunchyroll
+    /// returned, [`crate::common::LazyDateTime::get`] is [`None`] if it wasn't set.
+    #[serde(default)]
+    pub premium_date: crate::common::LazyDateTime,
+    /// Start of the window in which the episode can be streamed.
     #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
     pub availability_starts: DateTime<Utc>,
+    /// End of the window in which the episode can be streamed.
     #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
     pub availability_ends: DateTime<Utc>,
 
@@ -139,58 +193,76 @@ pub struct Episode {
     pub is_clip: bool,
 
     pub is_mature: bool,
+    /// Maturity ratings of the content per region.
+    #[serde(default)]
+    pub extended_maturity_rating: crate::media::ExtendedMaturityRatings,
+    /// Maturity ratings of the content, e.g. `TV-14`.
     pub maturity_ratings: Vec<String>,
     pub mature_blocked: bool,
 
+    /// If the episode can be downloaded for offline viewing (in the official apps).
     pub available_offline: bool,
+    /// Human readable notes about the availability, e.g. when the episode becomes available.
     pub availability_notes: String,
 
     pub closed_captions_available: bool,
 
+    /// The region in which the episode is available.
     pub eligible_region: String,
 
     /// All versions of this episode (same episode but each entry has a different language).
     #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
     pub versions: Vec<EpisodeVersion>,
 
-    #[cfg(feature = "__test_strict")]
+    /// Fields returned by Crunchyroll which aren't modelled by this struct (yet).
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(flatten))]
+    #[cfg_attr(feature = "__test_strict", serde(skip))]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+
+    #[serde(skip_serializing)]
     media_type: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     external_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     linked_resource_key: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     new: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     promo_title: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     promo_description: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     search_metadata: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     #[serde(rename = "type")]
     _type: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    extended_maturity_rating: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     tenant_categories: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    available_date: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    premium_date: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    seo_title: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    seo_description: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     listing_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     hd_flag: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     recent_variant: Option<crate::StrictValue>,
 }
 
 impl Episode {
+    /// Human readable title including the series title and episode number, used for feed and
+    /// playlist entries.
+    #[cfg(any(feature = "rss", feature = "m3u"))]
+    pub(crate) fn display_title(&self) -> String {
+        if self.episode.is_empty() {
+            format!("{} - {}", self.series_title, self.title)
+        } else {
+            format!(
+                "{} - Episode {} - {}",
+                self.series_title, self.episode, self.title
+            )
+        }
+    }
+
     /// Returns the series the episode belongs to.
     pub async fn series(&self) -> Result<Series> {
         let endpoint = format!(
@@ -202,6 +274,16 @@ impl Episode {
             .remove(0))
     }
 
+    /// Get all music related to the series this episode belongs to, like opening / ending
+    /// performances or concerts. See [`Series::related_music`].
+    pub async fn related_music(&self) -> Result<Vec<MediaCollection>> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/music/featured/{}",
+            self.series_id
+        );
+        request_media(self.executor.clone(), endpoint).await
+    }
+
     /// Returns the season the episode belongs to.
     pub async fn season(&self) -> Result<Season> {
         let endpoint = format!(
@@ -215,6 +297,168 @@ impl Episode {
         Ok(season)
     }
 
+    /// Returns the episode which comes after this one. Uses [`Episode::next`] and falls back to the
+    /// episode order of the season if the api doesn't return a next episode.
+    pub async fn next_episode(&self) -> Result<Option<Episode>> {
+        if let Some(next) = self.next().await? {
+            return Ok(Some(next.media));
+        }
+        Ok(self
+            .season_episodes()
+            .await?
+            .into_iter()
+            .filter(|e| e.sequence_number > self.sequence_number)
+            .min_by(|a, b| a.sequence_number.total_cmp(&b.sequence_number)))
+    }
+
+    /// Returns the episode which comes before this one. Uses [`Episode::previous`] and falls back
+    /// to the episode order of the season if the api doesn't return a previous episode.
+    pub async fn previous_episode(&self) -> Result<Option<Episode>> {
+        if let Some(previous) = self.previous().await? {
+            return Ok(Some(previous.media));
+        }
+        Ok(self
+            .season_episodes()
+            .await?
+            .into_iter()
+            .filter(|e| e.sequence_number < self.sequence_number)
+            .max_by(|a, b| a.sequence_number.total_cmp(&b.sequence_number)))
+    }
+
+    async fn season_episodes(&self) -> Result<Vec<Episode>> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/cms/seasons/{}/episodes",
+            self.season_id
+        );
+        let mut episodes: Vec<Episode> = request_media(self.executor.clone(), endpoint).await?;
+        for episode in &mut episodes {
+            fix_empty_episode_versions(episode);
+        }
+        Ok(episodes)
+    }
+
+    /// Returns the version of this episode with the given audio locale, if available. Regular
+    /// versions are preferred over audio described ones. Use [`EpisodeVersion::episode`] to get
+    /// the actual episode.
+    pub fn version_with_audio(&self, audio_locale: &Locale) -> Option<&EpisodeVersion> {
+        self.versions
+            .iter()
+            .find(|v| &v.audio_locale == audio_locale && !v.is_audio_description())
+            .or_else(|| {
+                self.versions
+                    .iter()
+                    .find(|v| &v.audio_locale == audio_locale)
+            })
+    }
+
+    /// Returns all audio described versions of this episode, see
+    /// [`EpisodeVersion::is_audio_description`].
+    pub fn audio_description_versions(&self) -> Vec<&EpisodeVersion> {
+        self.versions
+            .iter()
+            .filter(|v| v.is_audio_description())
+            .collect()
+    }
+
+    /// Returns the original version of this episode (mostly the japanese one), if available.
+    pub fn original_version(&self) -> Option<&EpisodeVersion> {
+        self.versions.iter().find(|v| v.original)
+    }
+
+    /// Check if the episode is currently in an early access window, in which it can only be
+    /// watched with a higher premium tier (e.g. Mega Fan) before it unlocks for all premium users
+    /// at [`Episode::early_access_ends`].
+    pub fn is_early_access(&self) -> bool {
+        self.early_access_ends().is_some()
+    }
+
+    /// The date at which the early access window of this episode ends and it becomes available
+    /// for all premium users. [`None`] if the episode isn't in an early access window.
+    pub fn early_access_ends(&self) -> Option<DateTime<Utc>> {
+        let now = Utc::now();
+        (self.availability_starts <= now && now < self.premium_available_date)
+            .then_some(self.premium_available_date)
+    }
+
+    /// Downloads all available subtitles of this episode concurrently and writes each one to the
+    /// writer `writer_factory` returns for it. Returns the result per subtitle locale, a failed
+    /// subtitle doesn't abort the others. The [`crate::media::Stream`] which is requested to get
+    /// the subtitles is invalidated afterwards.
+    #[cfg(feature = "streaming")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "streaming")))]
+    pub async fn download_all_subtitles_with<W, F>(
+        &self,
+        mut writer_factory: F,
+    ) -> Result<HashMap<Locale, Result<()>>>
+    where
+        W: std::io::Write,
+        F: FnMut(&crate::media::Subtitle) -> std::io::Result<W>,
+    {
+        let (stream, downloads) = self.download_subtitles().await?;
+
+        let mut result = HashMap::new();
+        for (subtitle, data) in downloads {
+            let written = data.and_then(|data| {
+                writer_factory(&subtitle)
+                    .and_then(|mut writer| {
+                        writer.write_all(&data)?;
+                        writer.flush()
+                    })
+                    .map_err(|err| subtitle_write_error(&subtitle, err))
+            });
+            result.insert(subtitle.locale, written);
+        }
+        invalidate_subtitle_stream(stream).await;
+        Ok(result)
+    }
+
+    /// Downloads all available subtitles of this episode concurrently into `dir`, see
+    /// [`Episode::download_all_subtitles_with`]. The files are named
+    /// `<episode id>.<locale>.<format>`, e.g. `GRDKJZ81Y.en-US.ass`. Returns the path of the file
+    /// per subtitle locale.
+    #[cfg(feature = "streaming")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "streaming")))]
+    pub async fn download_all_subtitles(
+        &self,
+        dir: impl AsRef<Path>,
+    ) -> Result<HashMap<Locale, Result<PathBuf>>> {
+        let dir = dir.as_ref();
+        let (stream, downloads) = self.download_subtitles().await?;
+
+        let mut result = HashMap::new();
+        for (subtitle, data) in downloads {
+            let path = dir.join(format!(
+                "{}.{}.{}",
+                self.id, subtitle.locale, subtitle.format
+            ));
+            let written = match data {
+                Ok(data) => crate::internal::fs::write(&path, data)
+                    .await
+                    .map(|_| path)
+                    .map_err(|err| subtitle_write_error(&subtitle, err)),
+                Err(err) => Err(err),
+            };
+            result.insert(subtitle.locale, written);
+        }
+        invalidate_subtitle_stream(stream).await;
+        Ok(result)
+    }
+
+    /// Requests a stream and downloads all of its subtitles concurrently. The stream must be
+    /// invalidated by the caller.
+    #[cfg(feature = "streaming")]
+    async fn download_subtitles(
+        &self,
+    ) -> Result<(
+        crate::media::Stream,
+        Vec<(crate::media::Subtitle, Result<bytes::Bytes>)>,
+    )> {
+        let stream = self.stream().await?;
+        let subtitles: Vec<crate::media::Subtitle> = stream.subtitles.values().cloned().collect();
+        let downloads = join_all(subtitles.iter().map(|subtitle| subtitle.data())).await;
+        Ok((stream, subtitles.into_iter().zip(downloads).collect()))
+    }
+
     /// Show in which audios this [`Episode`] is also available.
     #[deprecated(since = "0.11.4", note = "Use the `.versions` field directly")]
     pub async fn available_versions(&mut self) -> Result<Vec<Locale>> {
@@ -251,15 +495,8 @@ impl Episode {
 #[async_trait::async_trait]
 impl Media for Episode {
     async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
-        let mut episode: Episode = request_media(
-            crunchyroll.executor.clone(),
-            format!(
-                "https://www.crunchyroll.com/content/v2/cms/episodes/{}",
-                id.as_ref()
-            ),
-        )
-        .await?
-        .remove(0);
+        let mut episode: Episode =
+            request_media_object(crunchyroll.executor.clone(), "episodes", id.as_ref()).await?;
         fix_empty_episode_versions(&mut episode);
         Ok(episode)
     }
@@ -300,3 +537,22 @@ impl Media for Episode {
         }
     }
 }
+
+#[cfg(feature = "streaming")]
+fn subtitle_write_error(subtitle: &crate::media::Subtitle, err: std::io::Error) -> Error {
+    Error::Io {
+        message: format!("failed to write {} subtitle", subtitle.locale),
+        error: std::sync::Arc::new(err),
+    }
+}
+
+/// The subtitles are already written, so a failed invalidation is only logged. The session
+/// expires on its own eventually.
+#[cfg(feature = "streaming")]
+async fn invalidate_subtitle_stream(stream: crate::media::Stream) {
+    let _result = stream.invalidate().await;
+    #[cfg(feature = "tracing")]
+    if let Err(err) = _result {
+        tracing::warn!("failed to invalidate subtitle stream: {err}")
+    }
+}
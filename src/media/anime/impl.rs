@@ -1,7 +1,7 @@
 use crate::common::{PaginationBulkResultMeta, Request};
 use crate::macros::enum_values;
 use crate::media::Media;
-use crate::{Episode, MediaCollection, Movie, MovieListing, Result, Season, Series};
+use crate::{Crunchyroll, Episode, MediaCollection, Movie, MovieListing, Result, Season, Series};
 use chrono::{DateTime, Utc};
 use serde::de::{DeserializeOwned, Error, IntoDeserializer};
 use serde::{Deserialize, Deserializer, Serialize};
@@ -18,18 +18,24 @@ pub struct SkipEventsEvent {
     /// End of the event in seconds.
     pub end: f32,
 
-    #[cfg(feature = "__test_strict")]
-    approver_id: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    distribution_number: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    title: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    series_id: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    new: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    r#type: crate::StrictValue,
+    #[serde(skip_serializing)]
+    #[serde(rename = "approverId")]
+    _approver_id: crate::StrictValue,
+    #[serde(skip_serializing)]
+    #[serde(rename = "distributionNumber")]
+    _distribution_number: crate::StrictValue,
+    #[serde(skip_serializing)]
+    #[serde(rename = "title")]
+    _title: crate::StrictValue,
+    #[serde(skip_serializing)]
+    #[serde(rename = "seriesId")]
+    _series_id: crate::StrictValue,
+    #[serde(skip_serializing)]
+    #[serde(rename = "new")]
+    _new: crate::StrictValue,
+    #[serde(skip_serializing)]
+    #[serde(rename = "type")]
+    _type: crate::StrictValue,
 }
 
 /// Information about skippable events like an intro or credits.
@@ -48,9 +54,9 @@ pub struct SkipEvents {
     #[serde(default)]
     pub preview: Option<SkipEventsEvent>,
 
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     media_id: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     last_updated: crate::StrictValue,
 }
 
@@ -66,7 +72,7 @@ impl<'de> Deserialize<'de> for SkipEvents {
             let Some(obj) = as_map.get(object) else {
                 continue;
             };
-            if obj.as_object().map_or(false, |o| o.is_empty())
+            if obj.as_object().is_some_and(|o| o.is_empty())
                 // crunchyroll sometimes has a skip events, but it's lacking start or end times.
                 // this is just abstracted away since an event without a start or end doesn't make
                 // sense to be wrapped in e.g. an Option
@@ -80,11 +86,12 @@ impl<'de> Deserialize<'de> for SkipEvents {
             }
         }
 
-        SkipEvents::deserialize(
-            serde_json::to_value(as_map)
-                .map_err(|e| Error::custom(e.to_string()))?
-                .into_deserializer(),
-        )
+        let value = serde_json::to_value(as_map).map_err(|e| Error::custom(e.to_string()))?;
+        if crate::internal::strict::is_active() {
+            crate::internal::strict::deserialize_tracked(value, |d| SkipEvents::deserialize(d))
+        } else {
+            SkipEvents::deserialize(value.into_deserializer())
+        }
         .map_err(|e| Error::custom(e.to_string()))
     }
 }
@@ -124,6 +131,26 @@ pub struct PlayheadInformation {
     pub last_modified: DateTime<Utc>,
 }
 
+impl Crunchyroll {
+    /// Get playhead information for multiple episodes / movies at once. Ids for which no playhead
+    /// exists (because they were never watched) are not contained in the result.
+    pub async fn playheads<S: AsRef<str>>(&self, ids: &[S]) -> Result<Vec<PlayheadInformation>> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/{}/playheads",
+            self.executor.details.account_id.clone()?
+        );
+        let content_ids = ids.iter().map(|id| id.as_ref()).collect::<Vec<&str>>();
+        Ok(self
+            .executor
+            .get(endpoint)
+            .query(&[("content_ids", content_ids.join(","))])
+            .apply_locale_query()
+            .request::<crate::common::V2BulkResult<PlayheadInformation>>()
+            .await?
+            .data)
+    }
+}
+
 enum_values! {
     /// Starts a rating can have. Crunchyroll does not use simple numbers which would be much easier
     /// to work with but own names for every star.
@@ -186,6 +213,8 @@ macro_rules! impl_manual_media_deserialize {
                     D: Deserializer<'de>,
                 {
                     let mut as_map = serde_json::Map::deserialize(deserializer)?;
+                    #[cfg(feature = "raw-json")]
+                    let raw = serde_json::Value::Object(as_map.clone());
 
                     if let Some(mut metadata) = as_map.remove($metadata) {
                         if let Some(object) = metadata.as_object_mut() {
@@ -195,12 +224,20 @@ macro_rules! impl_manual_media_deserialize {
                         }
                     }
 
-                    $media::deserialize(
-                        serde_json::to_value(as_map)
-                            .map_err(|e| Error::custom(e.to_string()))?
-                            .into_deserializer(),
-                    )
-                    .map_err(|e| Error::custom(e.to_string()))
+                    let value = serde_json::to_value(as_map)
+                        .map_err(|e| Error::custom(e.to_string()))?;
+                    #[allow(unused_mut)]
+                    let mut media = if crate::internal::strict::is_active() {
+                        crate::internal::strict::deserialize_tracked(value, |d| $media::deserialize(d))
+                    } else {
+                        $media::deserialize(value.into_deserializer())
+                    }
+                    .map_err(|e| Error::custom(e.to_string()))?;
+                    #[cfg(feature = "raw-json")]
+                    {
+                        media.raw = raw;
+                    }
+                    Ok(media)
                 }
             }
         )*
@@ -234,6 +271,33 @@ impl_manual_media_serialize! {
     Series Season Episode MovieListing Movie
 }
 
+macro_rules! impl_media_raw {
+    ($($media:ident)*) => {
+        $(
+            #[cfg(feature = "raw-json")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "raw-json")))]
+            impl $media {
+                /// The raw json this media was deserialized from. Contains all fields returned by
+                /// Crunchyroll, including the ones which aren't modelled by this struct.
+                pub fn raw(&self) -> &serde_json::Value {
+                    &self.raw
+                }
+            }
+
+            impl crate::media::util::RawMedia for $media {
+                #[cfg(feature = "object-cache")]
+                fn raw_json(&self) -> &serde_json::Value {
+                    &self.raw
+                }
+            }
+        )*
+    }
+}
+
+impl_media_raw! {
+    Series Season Episode MovieListing Movie
+}
+
 macro_rules! impl_media_request {
     ($($media:ident)*) => {
         $(
@@ -274,7 +338,8 @@ macro_rules! impl_media_video_collection {
     ($($media_video:ident = $endpoint:literal)*) => {
         $(
             impl $media_video {
-                /// Similar series or movie listing to the current item.
+                /// Similar series or movie listing to the current item. This is the same list
+                /// which is shown as "More Like This" on the Crunchyroll website.
                 pub fn similar(&self) -> $crate::common::Pagination<MediaCollection> {
                     use futures_util::FutureExt;
 
@@ -294,6 +359,8 @@ macro_rules! impl_media_video_collection {
                     }, self.executor.clone(), None, Some(vec![("id", self.id.clone())]))
                 }
 
+                /// Rating statistics of this series / movie listing. [`Rating::rating`] contains
+                /// your own rating, if you've rated it.
                 pub async fn rating(&self) -> Result<Rating> {
                     let endpoint = format!(
                         "https://www.crunchyroll.com/content-reviews/v2/user/{}/rating/{}/{}",
@@ -302,6 +369,7 @@ macro_rules! impl_media_video_collection {
                     self.executor.get(endpoint).request().await
                 }
 
+                /// Rate this series / movie listing. Calling it again updates your rating.
                 pub async fn rate(&self, stars: RatingStar) -> Result<Rating> {
                     let endpoint = format!(
                         "https://www.crunchyroll.com/content-reviews/v2/user/{}/rating/{}/{}",
@@ -312,6 +380,18 @@ macro_rules! impl_media_video_collection {
                         .request()
                         .await
                 }
+
+                /// Remove your rating of this series / movie listing.
+                pub async fn remove_rating(&self) -> Result<()> {
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content-reviews/v2/user/{}/rating/{}/{}",
+                        self.executor.details.account_id.clone()?, $endpoint, self.id
+                    );
+                    self.executor.delete(endpoint)
+                        .request_raw(true)
+                        .await?;
+                    Ok(())
+                }
             }
         )*
     }
@@ -329,6 +409,8 @@ macro_rules! impl_media_video {
                 /// Streams for this episode / movie.
                 /// All streams are drm encrypted, decryption is not handled in this crate, so you
                 /// must do this yourself.
+                #[cfg(feature = "streaming")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "streaming")))]
                 pub async fn stream(&self) -> Result<$crate::media::Stream> {
                     $crate::media::Stream::from_id_web_chrome(&$crate::Crunchyroll { executor: self.executor.clone() }, &self.id, None).await
                 }
@@ -338,6 +420,30 @@ macro_rules! impl_media_video {
                     self.executor.premium().await || !self.is_premium_only
                 }
 
+                /// The date from which on the episode / movie can be watched without premium.
+                /// [`None`] if it will never be available for free (Crunchyroll uses a placeholder
+                /// date far in the future for this).
+                pub fn free_available_at(&self) -> Option<DateTime<Utc>> {
+                    use chrono::Datelike;
+                    (self.free_available_date.year() < 9998).then_some(self.free_available_date)
+                }
+
+                /// The date from which on the episode / movie can be watched with premium.
+                pub fn premium_available_at(&self) -> DateTime<Utc> {
+                    self.premium_available_date
+                }
+
+                /// Who can currently play the episode / movie.
+                pub fn playback_availability(&self) -> $crate::media::PlaybackAvailability {
+                    $crate::media::PlaybackAvailability {
+                        premium_only: self.is_premium_only,
+                        free_with_ads: !self.is_premium_only
+                            && self.free_available_at().is_some_and(|d| d <= Utc::now()),
+                        mature: self.is_mature,
+                        mature_blocked: self.mature_blocked,
+                    }
+                }
+
                 /// Get skippable events like intro or credits.
                 pub async fn skip_events(&self) -> Result<SkipEvents> {
                     let endpoint = format!(
@@ -347,7 +453,7 @@ macro_rules! impl_media_video {
                     let raw_result = self.executor.get(endpoint)
                         .request_raw(true)
                         .await?;
-                    let result = String::from_utf8_lossy(raw_result.as_slice());
+                    let result = String::from_utf8_lossy(&raw_result);
                     if result.contains("</Error>") {
                         // sometimes crunchyroll just returns a xml error instead of an empty result
                         return Ok(SkipEvents::default())
@@ -405,6 +511,16 @@ macro_rules! impl_media_video {
                         .cloned())
                 }
 
+                /// Get the position from which playback should be resumed. Starts from the beginning
+                /// if the episode / movie was never watched or is already fully watched.
+                pub async fn resume_position(&self) -> Result<chrono::Duration> {
+                    let seconds = match self.playhead().await? {
+                        Some(playhead) if !playhead.fully_watched => playhead.playhead,
+                        _ => 0,
+                    };
+                    Ok(chrono::Duration::seconds(seconds as i64))
+                }
+
                 /// Set the playhead (current playback position) for this episode / movie. Used unit
                 /// is seconds. Setting the playhead also triggers the Crunchyroll Discord
                 /// integration so if you update the playhead and have Crunchyroll connected to
@@ -418,6 +534,23 @@ macro_rules! impl_media_video {
                         .await?;
                     Ok(())
                 }
+
+                /// Mark this episode / movie as fully watched. This sets the playhead to the end of
+                /// the episode / movie.
+                pub async fn mark_watched(&self) -> Result<()> {
+                    self.set_playhead(self.duration.num_seconds() as u32).await
+                }
+
+                /// Mark this episode / movie as unwatched. This removes the playhead, so it looks
+                /// like the episode / movie was never watched.
+                pub async fn mark_unwatched(&self) -> Result<()> {
+                    let endpoint = format!("https://www.crunchyroll.com/content/v2/{}/playheads/{}", self.executor.details.account_id.clone()?, &self.id);
+                    self.executor.delete(endpoint)
+                        .apply_locale_query()
+                        .request::<$crate::EmptyJsonProxy>()
+                        .await?;
+                    Ok(())
+                }
             }
         )*
     }
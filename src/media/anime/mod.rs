@@ -2,6 +2,7 @@ mod episode;
 mod r#impl;
 mod movie;
 mod movie_listing;
+mod review;
 mod season;
 mod series;
 pub(crate) mod util;
@@ -10,5 +11,6 @@ pub use episode::*;
 pub use movie::*;
 pub use movie_listing::*;
 pub use r#impl::*;
+pub use review::*;
 pub use season::*;
 pub use series::*;
@@ -1,7 +1,7 @@
 use crate::crunchyroll::Executor;
-use crate::media::util::request_media;
+use crate::media::util::{request_media, request_media_object};
 use crate::media::{Media, ThumbnailImages};
-use crate::{Crunchyroll, MovieListing, Result};
+use crate::{Crunchyroll, Locale, MovieListing, Result};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -16,6 +16,10 @@ pub struct Movie {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
 
+    #[cfg(feature = "raw-json")]
+    #[serde(skip)]
+    pub(crate) raw: serde_json::Value,
+
     pub id: String,
     #[serde(alias = "streams_link")]
     #[serde(deserialize_with = "crate::internal::serde::deserialize_streams_link")]
@@ -39,16 +43,41 @@ pub struct Movie {
     #[default(Duration::try_milliseconds(0).unwrap())]
     pub duration: Duration,
 
+    /// The audio locale of the movie. Not always present.
+    #[serde(default)]
+    pub audio_locale: Option<Locale>,
+
     pub images: ThumbnailImages,
 
     /// Descriptors about the movie content, e.g. 'Violence' or 'Sexualized Imagery'.
     #[serde(default)]
-    pub content_descriptors: Vec<String>,
+    pub content_descriptors: Vec<crate::media::ContentDescriptor>,
 
+    /// Date from which on the movie is available without premium. Use
+    /// [`Movie::free_available_at`] to check if it will ever be available for free.
     #[default(DateTime::< Utc >::from(std::time::SystemTime::UNIX_EPOCH))]
     pub free_available_date: DateTime<Utc>,
+    /// Date from which on the movie is available with premium.
     #[default(DateTime::< Utc >::from(std::time::SystemTime::UNIX_EPOCH))]
     pub premium_available_date: DateTime<Utc>,
+    /// Date from which on the movie is listed without premium. Keeps the raw string Crunchyroll
+    /// returned, [`crate::common::LazyDateTime::get`] is [`None`] if it wasn't set.
+    #[serde(default)]
+    pub available_date: crate::common::LazyDateTime,
+    /// Date from which on the movie is listed with premium. Keeps the raw string Crunchyroll
+    /// returned, [`crate::common::LazyDateTime::get`] is [`None`] if it wasn't set.
+    #[serde(default)]
+    pub premium_date: crate::common::LazyDateTime,
+    /// Start of the window in which the movie can be streamed. Not always sent by Crunchyroll, the
+    /// unix epoch is used in this case.
+    #[serde(default)]
+    #[default(DateTime::< Utc >::from(std::time::SystemTime::UNIX_EPOCH))]
+    pub availability_starts: DateTime<Utc>,
+    /// End of the window in which the movie can be streamed. Not always sent by Crunchyroll, the
+    /// unix epoch is used in this case.
+    #[serde(default)]
+    #[default(DateTime::< Utc >::from(std::time::SystemTime::UNIX_EPOCH))]
+    pub availability_ends: DateTime<Utc>,
 
     pub is_subbed: bool,
     pub is_dubbed: bool,
@@ -56,37 +85,38 @@ pub struct Movie {
 
     pub is_premium_only: bool,
 
+    /// Maturity ratings of the content per region.
+    #[serde(default)]
+    pub extended_maturity_rating: crate::media::ExtendedMaturityRatings,
+    /// Maturity ratings of the content, e.g. `TV-14`.
     pub maturity_ratings: Vec<String>,
     pub is_mature: bool,
     pub mature_blocked: bool,
 
+    /// If the movie can be downloaded for offline viewing (in the official apps).
     pub available_offline: bool,
+    /// Human readable notes about the availability, e.g. when the movie becomes available.
     pub availability_notes: String,
 
-    #[cfg(feature = "__test_strict")]
+    /// Fields returned by Crunchyroll which aren't modelled by this struct (yet).
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(flatten))]
+    #[cfg_attr(feature = "__test_strict", serde(skip))]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+
+    #[serde(skip_serializing)]
     #[serde(rename = "type")]
     #[serde(alias = "media_type")]
     type_: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    audio_locale: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     external_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     linked_resource_key: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     promo_title: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     promo_description: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    extended_maturity_rating: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    available_date: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    availability_starts: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    availability_ends: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    premium_date: crate::StrictValue,
 }
 
 impl Movie {
@@ -105,15 +135,7 @@ impl Movie {
 #[async_trait::async_trait]
 impl Media for Movie {
     async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
-        Ok(request_media(
-            crunchyroll.executor.clone(),
-            format!(
-                "https://www.crunchyroll.com/content/v2/cms/movies/{}",
-                id.as_ref()
-            ),
-        )
-        .await?
-        .remove(0))
+        request_media_object(crunchyroll.executor.clone(), "movies", id.as_ref()).await
     }
 
     async fn __set_executor(&mut self, executor: Arc<Executor>) {
@@ -1,7 +1,8 @@
 use crate::categories::Category;
-use crate::common::Request;
+use crate::common::{Pagination, Request};
 use crate::crunchyroll::Executor;
-use crate::media::util::request_media;
+use crate::media::anime::util::is_trailer_title;
+use crate::media::util::{request_media, request_media_object};
 use crate::media::{Media, PosterImages};
 use crate::{Crunchyroll, Locale, Movie, Result};
 use chrono::{DateTime, Utc};
@@ -22,11 +23,17 @@ pub struct MovieListingVersion {
 
     pub original: bool,
 
-    #[cfg(feature = "__test_strict")]
-    variant: crate::StrictValue,
+    #[serde(skip_serializing)]
+    variant: Option<String>,
 }
 
 impl MovieListingVersion {
+    /// If this version is audio described, i.e. contains a narration of the visual content for
+    /// blind and visually impaired viewers.
+    pub fn is_audio_description(&self) -> bool {
+        crate::media::util::is_audio_description_variant(&self.variant)
+    }
+
     /// Requests an actual [`MovieListing`] from this version.
     pub async fn movie_listing(&self) -> Result<MovieListing> {
         MovieListing::from_id(
@@ -49,12 +56,24 @@ pub struct MovieListing {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
 
+    #[cfg(feature = "raw-json")]
+    #[serde(skip)]
+    pub(crate) raw: serde_json::Value,
+
     pub id: String,
     pub channel_id: String,
 
     pub slug: String,
     pub title: String,
     pub slug_title: String,
+    /// Title which is optimized for search engines. Might be empty.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub seo_title: String,
+    /// Description which is optimized for search engines. Might be empty.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub seo_description: String,
     pub description: String,
     pub extended_description: String,
 
@@ -76,7 +95,7 @@ pub struct MovieListing {
 
     /// Descriptors about the movie listing content, e.g. 'Violence' or 'Sexualized Imagery'.
     #[serde(default)]
-    pub content_descriptors: Vec<String>,
+    pub content_descriptors: Vec<crate::media::ContentDescriptor>,
 
     #[serde(default)]
     pub keywords: Vec<String>,
@@ -89,11 +108,23 @@ pub struct MovieListing {
     pub free_available_date: DateTime<Utc>,
     #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
     pub premium_available_date: DateTime<Utc>,
+    /// Date from which on the movie listing is listed without premium. Keeps the raw string Crunchyroll
+    /// returned, [`crate::common::LazyDateTime::get`] is [`None`] if it wasn't set.
+    #[serde(default)]
+    pub available_date: crate::common::LazyDateTime,
+    /// Date from which on the movie listing is listed with premium. Keeps the raw string Crunchyroll
+    /// returned, [`crate::common::LazyDateTime::get`] is [`None`] if it wasn't set.
+    #[serde(default)]
+    pub premium_date: crate::common::LazyDateTime,
 
     #[serde(default)]
     #[serde(rename = "tenant_categories")]
     pub categories: Vec<Category>,
 
+    /// Maturity ratings of the content per region.
+    #[serde(default)]
+    pub extended_maturity_rating: crate::media::ExtendedMaturityRatings,
+    /// Maturity ratings of the content, e.g. `TV-14`.
     pub maturity_ratings: Vec<String>,
     pub is_mature: bool,
     pub mature_blocked: bool,
@@ -104,53 +135,138 @@ pub struct MovieListing {
     /// All versions of this movie listing (same movie listing but each entry has a different language).
     pub versions: Vec<MovieListingVersion>,
 
-    #[cfg(feature = "__test_strict")]
-    extended_maturity_rating: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
+    /// Fields returned by Crunchyroll which aren't modelled by this struct (yet).
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(flatten))]
+    #[cfg_attr(feature = "__test_strict", serde(skip))]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+
+    #[serde(skip_serializing)]
     identifier: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    available_date: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    premium_date: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     duration_ms: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     external_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     first_movie_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     new: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     promo_title: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    seo_title: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     promo_description: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    seo_description: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     hd_flag: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     last_public: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     linked_resource_key: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     playback: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     #[serde(rename = "type")]
     _type: Option<crate::StrictValue>,
 }
 
 impl MovieListing {
-    /// Returns all movies for this movie listing.
-    pub async fn movies(&self) -> Result<Vec<Movie>> {
+    /// Returns all movies for this movie listing. The endpoint returns all movies at once, so the
+    /// pagination only has a single page.
+    pub fn movies(&self) -> Pagination<Movie> {
         let endpoint = format!(
             "https://www.crunchyroll.com/content/v2/cms/movie_listings/{}/movies",
             self.id
         );
+        Pagination::from_future(
+            request_media(self.executor.clone(), endpoint),
+            self.executor.clone(),
+        )
+    }
+
+    /// Returns the movies of all versions (audio locales) of this movie listing. The movies of the
+    /// original version come first, followed by the other versions in the order Crunchyroll lists
+    /// them. The movies of each version keep the order in which they are listed (like
+    /// [`MovieListing::movies`]). [`Movie::audio_locale`] is always set. Every version requires a
+    /// separate request.
+    pub async fn movies_of_all_versions(&self) -> Result<Vec<Movie>> {
+        let mut versions: Vec<&MovieListingVersion> = self.versions.iter().collect();
+        versions.sort_by_key(|v| !v.original);
+        let mut versions: Vec<(String, Locale)> = versions
+            .into_iter()
+            .map(|v| (v.id.clone(), v.audio_locale.clone()))
+            .collect();
+        if versions.is_empty() {
+            versions.push((
+                self.id.clone(),
+                self.audio_locale.clone().unwrap_or_default(),
+            ))
+        }
+
+        let mut result = vec![];
+        for (id, audio_locale) in versions {
+            let endpoint =
+                format!("https://www.crunchyroll.com/content/v2/cms/movie_listings/{id}/movies");
+            let mut movies: Vec<Movie> = request_media(self.executor.clone(), endpoint).await?;
+            for movie in &mut movies {
+                movie.audio_locale.get_or_insert(audio_locale.clone());
+            }
+            result.extend(movies)
+        }
+        Ok(result)
+    }
+
+    /// Returns all extra videos (trailers, bonus features, ...) of this movie listing. They can be
+    /// streamed like every other movie via [`Movie::stream`].
+    pub async fn extra_videos(&self) -> Result<Vec<Movie>> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/cms/movie_listings/{}/extra_videos",
+            self.id
+        );
         request_media(self.executor.clone(), endpoint).await
     }
 
+    /// Returns all trailers (and PVs / teasers) of this movie listing. Trailers are regular extra
+    /// videos (see [`MovieListing::extra_videos`]) and are detected by their title, which may not
+    /// work for every locale. Like every other movie, they can be played / downloaded via
+    /// [`Movie::stream`] (including hardsubs if available).
+    pub async fn trailers(&self) -> Result<Vec<Movie>> {
+        Ok(self
+            .extra_videos()
+            .await?
+            .into_iter()
+            .filter(|m| is_trailer_title(&m.title))
+            .collect())
+    }
+
+    /// Returns the version of this movie listing with the given audio locale, if available.
+    /// Regular versions are preferred over audio described ones. Use
+    /// [`MovieListingVersion::movie_listing`] to get the actual movie listing.
+    pub fn version_with_audio(&self, audio_locale: &Locale) -> Option<&MovieListingVersion> {
+        self.versions
+            .iter()
+            .find(|v| &v.audio_locale == audio_locale && !v.is_audio_description())
+            .or_else(|| {
+                self.versions
+                    .iter()
+                    .find(|v| &v.audio_locale == audio_locale)
+            })
+    }
+
+    /// Returns all audio described versions of this movie listing, see
+    /// [`MovieListingVersion::is_audio_description`].
+    pub fn audio_description_versions(&self) -> Vec<&MovieListingVersion> {
+        self.versions
+            .iter()
+            .filter(|v| v.is_audio_description())
+            .collect()
+    }
+
+    /// Returns the original version of this movie listing (mostly the japanese one), if
+    /// available.
+    pub fn original_version(&self) -> Option<&MovieListingVersion> {
+        self.versions.iter().find(|v| v.original)
+    }
+
     /// Show in which audios this [`MovieListing`] is also available.
     #[deprecated(since = "0.11.4", note = "Use the `.versions` field directly")]
     pub async fn available_versions(&mut self) -> Result<Vec<Locale>> {
@@ -187,15 +303,7 @@ impl MovieListing {
 #[async_trait::async_trait]
 impl Media for MovieListing {
     async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
-        Ok(request_media(
-            crunchyroll.executor.clone(),
-            format!(
-                "https://www.crunchyroll.com/content/v2/cms/movie_listings/{}",
-                id.as_ref()
-            ),
-        )
-        .await?
-        .remove(0))
+        request_media_object(crunchyroll.executor.clone(), "movie_listings", id.as_ref()).await
     }
 
     async fn __set_executor(&mut self, executor: Arc<Executor>) {
@@ -0,0 +1,257 @@
+use crate::common::{BulkResult, Pagination};
+use crate::crunchyroll::Executor;
+use crate::media::RatingStarDetails;
+use crate::{enum_values, options, Episode, Locale, MovieListing, Request, Result, Series};
+use chrono::{DateTime, Utc};
+use futures_util::FutureExt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+enum_values! {
+    /// Thumbs up / down rating which can be given to reviews and episodes.
+    pub enum ThumbRating {
+        Like = "like"
+        Dislike = "dislike"
+    }
+}
+
+enum_values! {
+    /// How to sort queried reviews.
+    pub enum ReviewSortType {
+        Newest = "newest"
+        Oldest = "oldest"
+        Helpful = "helpful"
+    }
+}
+
+enum_values! {
+    /// Filter reviews by the star rating their author gave.
+    pub enum ReviewFilter {
+        Positive = "positive"
+        Negative = "negative"
+        OneStar = "1s"
+        TwoStars = "2s"
+        ThreeStars = "3s"
+        FourStars = "4s"
+        FiveStars = "5s"
+    }
+}
+
+options! {
+    /// Options how to query reviews.
+    ReviewOptions;
+    /// Specifies how the reviews should be sorted.
+    sort(ReviewSortType, "sort") = Some(ReviewSortType::Helpful),
+    /// Specifies which reviews should be returned.
+    filter(ReviewFilter, "filter") = None
+}
+
+/// Statistics how helpful a [`Review`] is.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+pub struct ReviewRatings {
+    /// Users who found the review helpful.
+    pub yes: RatingStarDetails,
+    /// Users who didn't find the review helpful.
+    pub no: RatingStarDetails,
+
+    pub total: u32,
+
+    /// Your own rating of the review, if you've rated it.
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_empty_pre_string_to_none")]
+    pub rating: Option<ThumbRating>,
+}
+
+/// The author of a [`Review`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+pub struct ReviewAuthor {
+    pub id: String,
+    pub username: String,
+    pub avatar: String,
+}
+
+/// The actual text of a [`Review`].
+#[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+pub struct ReviewContent {
+    pub id: String,
+
+    pub title: String,
+    pub body: String,
+    pub language: Locale,
+
+    /// If the review contains spoilers.
+    pub spoiler: bool,
+
+    #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
+    pub created_at: DateTime<Utc>,
+    #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
+    pub modified_at: DateTime<Utc>,
+}
+
+/// A user review of a series or movie listing.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+pub struct Review {
+    #[serde(skip)]
+    executor: Arc<Executor>,
+
+    pub review: ReviewContent,
+    pub author: ReviewAuthor,
+    /// The star rating the author gave the reviewed series / movie listing.
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_empty_pre_string_to_none")]
+    pub author_rating: Option<crate::media::RatingStar>,
+
+    pub ratings: ReviewRatings,
+}
+
+impl Review {
+    /// Mark this review as helpful ([`ThumbRating::Like`]) or not helpful
+    /// ([`ThumbRating::Dislike`]).
+    pub async fn rate(&mut self, rating: ThumbRating) -> Result<()> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content-reviews/v2/user/{}/rating/review/{}",
+            self.executor.details.account_id.clone()?,
+            self.review.id
+        );
+        self.ratings = self
+            .executor
+            .put(endpoint)
+            .json(&json!({ "rating": rating }))
+            .request()
+            .await?;
+        Ok(())
+    }
+}
+
+/// Thumbs up / down statistics of an [`Episode`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+pub struct EpisodeRating {
+    pub likes: RatingStarDetails,
+    pub dislikes: RatingStarDetails,
+
+    pub total: u32,
+
+    /// Your own rating of the episode, if you've rated it.
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_empty_pre_string_to_none")]
+    pub rating: Option<ThumbRating>,
+}
+
+impl Episode {
+    /// Thumbs up / down statistics of this episode. [`EpisodeRating::rating`] contains your own
+    /// rating, if you've rated it.
+    pub async fn rating(&self) -> Result<EpisodeRating> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content-reviews/v2/user/{}/rating/episode/{}",
+            self.executor.details.account_id.clone()?,
+            self.id
+        );
+        self.executor.get(endpoint).request().await
+    }
+
+    /// Give this episode a thumbs up or down.
+    pub async fn rate(&self, rating: ThumbRating) -> Result<EpisodeRating> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content-reviews/v2/user/{}/rating/episode/{}",
+            self.executor.details.account_id.clone()?,
+            self.id
+        );
+        self.executor
+            .put(endpoint)
+            .json(&json!({ "rating": rating }))
+            .request()
+            .await
+    }
+}
+
+macro_rules! impl_media_review {
+    ($($media:ident = $endpoint:literal)*) => {
+        $(
+            impl $media {
+                /// Reviews other users have written.
+                pub fn reviews(&self, options: ReviewOptions) -> Pagination<Review> {
+                    Pagination::new(|options| {
+                        async move {
+                            let endpoint = format!(
+                                "https://www.crunchyroll.com/content-reviews/v2/{}/review/{}/{}/list",
+                                options.executor.details.locale, $endpoint, options.extra.get("id").unwrap()
+                            );
+                            let result: BulkResult<Review> = options
+                                .executor
+                                .get(endpoint)
+                                .query(&options.query)
+                                .query(&[("page", options.page), ("page_size", options.page_size)])
+                                .request()
+                                .await?;
+                            Ok(result.into())
+                        }
+                        .boxed()
+                    }, self.executor.clone(), Some(options.into_query()), Some(vec![("id", self.id.clone())]))
+                }
+
+                /// Your own review. Is [`None`] if you haven't written one.
+                pub async fn own_review(&self) -> Result<Option<Review>> {
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content-reviews/v2/{}/user/{}/review/{}/{}",
+                        self.executor.details.locale, self.executor.details.account_id.clone()?, $endpoint, self.id
+                    );
+                    let result: serde_json::Map<String, serde_json::Value> = self.executor.get(endpoint).request().await?;
+                    if result.is_empty() {
+                        return Ok(None)
+                    }
+                    let mut review: Review = serde_json::from_value(result.into())?;
+                    review.executor = self.executor.clone();
+                    Ok(Some(review))
+                }
+
+                /// Write a review. Fails if you've already written one, use
+                /// [`Self::edit_review`] in this case.
+                pub async fn write_review<T: AsRef<str>, B: AsRef<str>>(&self, title: T, body: B, spoiler: bool) -> Result<Review> {
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content-reviews/v2/{}/user/{}/review/{}/{}",
+                        self.executor.details.locale, self.executor.details.account_id.clone()?, $endpoint, self.id
+                    );
+                    self.executor.post(endpoint)
+                        .json(&json!({"title": title.as_ref(), "body": body.as_ref(), "spoiler": spoiler}))
+                        .request()
+                        .await
+                }
+
+                /// Edit your review.
+                pub async fn edit_review<T: AsRef<str>, B: AsRef<str>>(&self, title: T, body: B, spoiler: bool) -> Result<Review> {
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content-reviews/v2/{}/user/{}/review/{}/{}",
+                        self.executor.details.locale, self.executor.details.account_id.clone()?, $endpoint, self.id
+                    );
+                    self.executor.patch(endpoint)
+                        .json(&json!({"title": title.as_ref(), "body": body.as_ref(), "spoiler": spoiler}))
+                        .request()
+                        .await
+                }
+
+                /// Delete your review.
+                pub async fn delete_review(&self) -> Result<()> {
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content-reviews/v2/{}/user/{}/review/{}/{}",
+                        self.executor.details.locale, self.executor.details.account_id.clone()?, $endpoint, self.id
+                    );
+                    self.executor.delete(endpoint).request_raw(true).await?;
+                    Ok(())
+                }
+            }
+        )*
+    }
+}
+
+impl_media_review! {
+    Series = "series"
+    MovieListing = "movie_listing"
+}
@@ -1,7 +1,7 @@
-use crate::common::Request;
+use crate::common::{Pagination, Request, TryStreamExt};
 use crate::crunchyroll::Executor;
 use crate::media::anime::util::{fix_empty_episode_versions, fix_empty_season_versions};
-use crate::media::util::request_media;
+use crate::media::util::{request_media, request_media_object};
 use crate::media::Media;
 use crate::{Crunchyroll, Episode, Locale, Result, Series};
 use chrono::{DateTime, Utc};
@@ -43,11 +43,17 @@ pub struct SeasonVersion {
     #[serde(default)]
     pub restriction_windows: Vec<SeasonVersionRestrictionWindow>,
 
-    #[cfg(feature = "__test_strict")]
-    pub(crate) variant: crate::StrictValue,
+    #[serde(skip_serializing)]
+    pub(crate) variant: Option<String>,
 }
 
 impl SeasonVersion {
+    /// If this version is audio described, i.e. contains a narration of the visual content for
+    /// blind and visually impaired viewers.
+    pub fn is_audio_description(&self) -> bool {
+        crate::media::util::is_audio_description_variant(&self.variant)
+    }
+
     /// Requests an actual [`Season`] from this version.
     pub async fn season(&self) -> Result<Season> {
         Season::from_id(
@@ -70,6 +76,10 @@ pub struct Season {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
 
+    #[cfg(feature = "raw-json")]
+    #[serde(skip)]
+    pub(crate) raw: serde_json::Value,
+
     pub id: String,
     pub series_id: String,
     pub channel_id: String,
@@ -78,10 +88,22 @@ pub struct Season {
 
     pub title: String,
     pub slug_title: String,
+    /// Title which is optimized for search engines. Might be empty.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub seo_title: String,
+    /// Description which is optimized for search engines. Might be empty.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub seo_description: String,
     pub description: String,
 
     pub season_number: u32,
     pub season_sequence_number: u32,
+    /// The season number as displayed on the website. Might be empty, or something like 'OVA' for
+    /// specials.
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub season_display_number: String,
 
     pub number_of_episodes: u32,
 
@@ -93,11 +115,12 @@ pub struct Season {
     pub season_tags: Vec<String>,
     /// Descriptors about the season episodes' content, e.g. 'Violence' or 'Sexualized Imagery'.
     #[serde(default)]
-    pub content_descriptors: Vec<String>,
+    pub content_descriptors: Vec<crate::media::ContentDescriptor>,
 
     pub is_subbed: bool,
     pub is_dubbed: bool,
     pub is_simulcast: bool,
+
     #[serde(skip_serializing)]
     audio_locale: Option<Locale>,
     /// Most of the time, like 99%, this contains only one locale. But sometimes Crunchyroll does
@@ -107,6 +130,10 @@ pub struct Season {
     pub audio_locales: Vec<Locale>,
     pub subtitle_locales: Vec<Locale>,
 
+    /// Maturity ratings of the content per region.
+    #[serde(default)]
+    pub extended_maturity_rating: crate::media::ExtendedMaturityRatings,
+    /// Maturity ratings of the content, e.g. `TV-14`.
     pub maturity_ratings: Vec<String>,
     pub is_mature: bool,
     pub mature_blocked: bool,
@@ -118,17 +145,16 @@ pub struct Season {
     #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
     pub versions: Vec<SeasonVersion>,
 
-    #[cfg(feature = "__test_strict")]
+    /// Fields returned by Crunchyroll which aren't modelled by this struct (yet).
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(flatten))]
+    #[cfg_attr(feature = "__test_strict", serde(skip))]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+
+    #[serde(skip_serializing)]
     // currently empty (on all of my tests) but its might be filled in the future
     images: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    season_display_number: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    extended_maturity_rating: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    seo_title: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    seo_description: Option<crate::StrictValue>,
 }
 
 impl Season {
@@ -143,17 +169,75 @@ impl Season {
             .remove(0))
     }
 
-    /// Returns all episodes of this season.
-    pub async fn episodes(&self) -> Result<Vec<Episode>> {
+    /// Returns all episodes of this season. The endpoint returns all episodes at once, so the
+    /// pagination only has a single page.
+    pub fn episodes(&self) -> Pagination<Episode> {
+        let executor = self.executor.clone();
         let endpoint = format!(
             "https://www.crunchyroll.com/content/v2/cms/seasons/{}/episodes",
             self.id
         );
-        let mut episodes: Vec<Episode> = request_media(self.executor.clone(), endpoint).await?;
-        for episode in &mut episodes {
-            fix_empty_episode_versions(episode);
+        Pagination::from_future(
+            async move {
+                let mut episodes: Vec<Episode> = request_media(executor, endpoint).await?;
+                for episode in &mut episodes {
+                    fix_empty_episode_versions(episode);
+                }
+                Ok(episodes)
+            },
+            self.executor.clone(),
+        )
+    }
+
+    /// Returns the episode with the given episode number. Specials without an episode number
+    /// (like recaps between two episodes) are ignored. Returns [`None`] if no such episode
+    /// exists. Use [`Series::episode`] if the season might be split into multiple cours.
+    pub async fn episode(&self, number: u32) -> Result<Option<Episode>> {
+        Ok(self
+            .episodes()
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .find(|e| e.episode_number == Some(number)))
+    }
+
+    /// Check if this season is a special / OVA / extras season rather than a regular season.
+    /// Crunchyroll has no explicit flag for this, so it's detected by a non-numeric
+    /// [`Season::season_display_number`] or an [`Season::identifier`] which doesn't mark a
+    /// regular season (regular seasons have identifiers like `<series id>|S1`).
+    pub fn is_special(&self) -> bool {
+        let display_number = self.season_display_number.trim();
+        if !display_number.is_empty() && display_number.parse::<f32>().is_err() {
+            return true;
+        }
+        match self.identifier.split('|').nth(1) {
+            Some(season) => !season
+                .strip_prefix('S')
+                .is_some_and(|n| n.starts_with(|c: char| c.is_ascii_digit())),
+            None => false,
+        }
+    }
+
+    /// Mark all episodes of this season as watched. Every episode requires a separate request.
+    pub async fn mark_watched(&self) -> Result<()> {
+        for episode in self.episodes().try_collect::<Vec<_>>().await? {
+            episode.mark_watched().await?
+        }
+        Ok(())
+    }
+
+    /// Returns all extra videos (trailers, PVs, clips, ...) of this season. They can be streamed
+    /// like every other episode via [`Episode::stream`].
+    pub async fn extras(&self) -> Result<Vec<Episode>> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/cms/seasons/{}/extra_videos",
+            self.id
+        );
+        let mut extras: Vec<Episode> = request_media(self.executor.clone(), endpoint).await?;
+        for extra in &mut extras {
+            fix_empty_episode_versions(extra);
         }
-        Ok(episodes)
+        Ok(extras)
     }
 
     /// Show in which audios this [`Season`] is also available.
@@ -192,15 +276,8 @@ impl Season {
 #[async_trait::async_trait]
 impl Media for Season {
     async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
-        let mut season: Season = request_media(
-            crunchyroll.executor.clone(),
-            format!(
-                "https://www.crunchyroll.com/content/v2/cms/seasons/{}",
-                id.as_ref()
-            ),
-        )
-        .await?
-        .remove(0);
+        let mut season: Season =
+            request_media_object(crunchyroll.executor.clone(), "seasons", id.as_ref()).await?;
         fix_empty_season_versions(&mut season);
         Ok(season)
     }
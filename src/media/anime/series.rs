@@ -1,25 +1,38 @@
 use crate::categories::Category;
+use crate::common::Pagination;
 use crate::crunchyroll::Executor;
-use crate::media::anime::util::fix_empty_season_versions;
-use crate::media::util::request_media;
+use crate::media::anime::util::{
+    fix_empty_episode_versions, fix_empty_season_versions, is_trailer_title,
+};
+use crate::media::util::{request_media, request_media_object};
+use crate::media::Concurrency;
 use crate::media::{Media, PosterImages};
-use crate::{Crunchyroll, Locale, MusicVideo, Result, Season};
-use chrono::{DateTime, Utc};
+use crate::{Crunchyroll, Episode, Locale, MediaCollection, MusicVideo, Result, Season};
+use chrono::{DateTime, Duration, Utc};
+use futures_util::{stream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
-/// Information about a series which was nominated for the Crunchyroll Anime Awards.
+/// Award badge of a series which was nominated for / won the Crunchyroll Anime Awards.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
 #[cfg_attr(not(feature = "__test_strict"), serde(default))]
-pub struct SeriesAward {
+pub struct Award {
+    /// Url to the badge icon.
     pub icon_url: String,
+    /// Human readable name of the award, e.g. 'Best Action'.
     pub text: String,
 
+    /// If the award is from the latest Anime Awards.
     pub is_current_award: bool,
+    /// If the series won the award. If `false`, the series was only nominated.
     pub is_winner: bool,
 }
 
+#[deprecated(since = "0.12.0", note = "Use `Award` instead")]
+pub type SeriesAward = Award;
+
 #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault)]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
 #[cfg_attr(not(feature = "__test_strict"), serde(default))]
@@ -40,6 +53,91 @@ pub struct SeriesLivestream {
     pub episode_end_date: DateTime<Utc>,
 }
 
+/// All seasons of a series which have the same season number, e.g. the original season and all of
+/// its dubs.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SeasonGroup {
+    pub season_number: u32,
+    /// The seasons with [`SeasonGroup::season_number`] as season number. Usually one per audio
+    /// locale.
+    pub seasons: Vec<Season>,
+}
+
+impl SeasonGroup {
+    /// All audio locales the seasons of this group are available in.
+    pub fn audio_locales(&self) -> Vec<Locale> {
+        let mut audio_locales = vec![];
+        for season in &self.seasons {
+            for audio_locale in &season.audio_locales {
+                if !audio_locales.contains(audio_locale) {
+                    audio_locales.push(audio_locale.clone())
+                }
+            }
+        }
+        audio_locales
+    }
+
+    /// The season of this group with the given audio locale.
+    pub fn season_with_audio(&self, audio_locale: &Locale) -> Option<&Season> {
+        self.seasons
+            .iter()
+            .find(|s| s.audio_locales.contains(audio_locale))
+    }
+
+    /// The original season (mostly the japanese one) of this group. Falls back to the first
+    /// season if no season is marked as original.
+    pub fn original_season(&self) -> Option<&Season> {
+        self.seasons
+            .iter()
+            .find(|s| s.versions.iter().any(|v| v.id == s.id && v.original))
+            .or(self.seasons.first())
+    }
+}
+
+/// Audio and subtitle locales of all seasons with the same season number, returned as part of
+/// [`SeriesAggregate`].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SeasonLocales {
+    pub audio_locales: Vec<Locale>,
+    pub subtitle_locales: Vec<Locale>,
+}
+
+/// Summary of a series, returned by [`Series::aggregate`].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SeriesAggregate {
+    /// Number of episodes per season number. Dubs of a season share the season number with the
+    /// original season and aren't counted separately.
+    pub episodes_per_season: BTreeMap<u32, u32>,
+    /// Total number of episodes (without dubs).
+    pub episode_count: u32,
+    /// All audio locales the series is available in.
+    pub audio_locales: Vec<Locale>,
+    /// All subtitle locales the series is available in.
+    pub subtitle_locales: Vec<Locale>,
+    /// Audio and subtitle locales per season number.
+    pub locales_per_season: BTreeMap<u32, SeasonLocales>,
+    /// If all seasons are complete.
+    pub is_complete: bool,
+    /// Total runtime of all episodes (without dubs). Only set by
+    /// [`Series::aggregate_with_episodes`].
+    pub runtime: Option<Duration>,
+    /// Upload date of the most recently uploaded episode. Only set by
+    /// [`Series::aggregate_with_episodes`].
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+impl SeriesAggregate {
+    /// Check if any season of the series is available with the given audio locale.
+    pub fn has_audio(&self, audio_locale: &Locale) -> bool {
+        self.audio_locales.contains(audio_locale)
+    }
+
+    /// Check if any season of the series is available with the given subtitle locale.
+    pub fn has_subtitle(&self, subtitle_locale: &Locale) -> bool {
+        self.subtitle_locales.contains(subtitle_locale)
+    }
+}
+
 /// Metadata for a series.
 #[allow(dead_code)]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -50,6 +148,10 @@ pub struct Series {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
 
+    #[cfg(feature = "raw-json")]
+    #[serde(skip)]
+    pub(crate) raw: serde_json::Value,
+
     pub id: String,
     pub channel_id: String,
 
@@ -59,6 +161,14 @@ pub struct Series {
     pub slug: String,
     pub title: String,
     pub slug_title: String,
+    /// Title which is optimized for search engines. Might be empty.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub seo_title: String,
+    /// Description which is optimized for search engines. Might be empty.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub seo_description: String,
     pub description: String,
     pub extended_description: String,
 
@@ -73,7 +183,7 @@ pub struct Series {
     pub season_tags: Vec<String>,
     /// Descriptors about the series episodes' content, e.g. 'Violence' or 'Sexualized Imagery'.
     #[serde(default)]
-    pub content_descriptors: Vec<String>,
+    pub content_descriptors: Vec<crate::media::ContentDescriptor>,
 
     pub is_subbed: bool,
     pub is_dubbed: bool,
@@ -92,60 +202,389 @@ pub struct Series {
     #[serde(default)]
     pub keywords: Vec<String>,
 
+    /// Maturity ratings of the content per region.
+    #[serde(default)]
+    pub extended_maturity_rating: crate::media::ExtendedMaturityRatings,
+    /// Maturity ratings of the content, e.g. `TV-14`.
     pub maturity_ratings: Vec<String>,
     pub is_mature: bool,
     pub mature_blocked: bool,
 
     pub availability_notes: String,
 
-    /// Awards for which this anime was nominated at the Crunchyroll Anime Awards.
-    pub awards: Option<Vec<SeriesAward>>,
+    /// Awards for which this anime was nominated at the Crunchyroll Anime Awards. Empty if the
+    /// series has no awards.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_maybe_null_to_default")]
+    pub awards: Vec<Award>,
     /// Information about the livestream of an episode. The livestream may be already over.
     pub livestream: Option<SeriesLivestream>,
 
-    #[cfg(feature = "__test_strict")]
-    extended_maturity_rating: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
+    /// Fields returned by Crunchyroll which aren't modelled by this struct (yet).
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(flatten))]
+    #[cfg_attr(feature = "__test_strict", serde(skip))]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+
+    #[serde(skip_serializing)]
     external_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     last_public: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     linked_resource_key: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     new: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     new_content: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     promo_title: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     promo_description: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     search_metadata: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
+    #[serde(skip_serializing)]
     #[serde(rename = "type")]
     _type: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    seo_title: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    seo_description: Option<crate::StrictValue>,
 }
 
 impl Series {
-    /// Returns all series seasons.
-    pub async fn seasons(&self) -> Result<Vec<Season>> {
+    /// Returns all series seasons. The endpoint returns all seasons at once, so the pagination
+    /// only has a single page.
+    pub fn seasons(&self) -> Pagination<Season> {
+        let executor = self.executor.clone();
         let endpoint = format!(
             "https://www.crunchyroll.com/content/v2/cms/series/{}/seasons",
             self.id
         );
-        let mut seasons: Vec<Season> = request_media(self.executor.clone(), endpoint).await?;
-        for season in &mut seasons {
-            fix_empty_season_versions(season);
+        Pagination::from_future(
+            async move {
+                let mut seasons: Vec<Season> = request_media(executor, endpoint).await?;
+                for season in &mut seasons {
+                    fix_empty_season_versions(season);
+                }
+                Ok(seasons)
+            },
+            self.executor.clone(),
+        )
+    }
+
+    /// Returns all seasons of this series without specials / OVAs. See [`Season::is_special`] how
+    /// specials are detected.
+    pub async fn main_seasons(&self) -> Result<Vec<Season>> {
+        Ok(self
+            .seasons()
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .filter(|s| !s.is_special())
+            .collect())
+    }
+
+    /// Returns all special / OVA seasons of this series. See [`Season::is_special`] how specials
+    /// are detected.
+    pub async fn special_seasons(&self) -> Result<Vec<Season>> {
+        Ok(self
+            .seasons()
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .filter(|s| s.is_special())
+            .collect())
+    }
+
+    /// Mark all episodes of this series as watched. Only one season per season number is marked
+    /// (the original version if available), so dubs aren't marked separately. Every episode
+    /// requires a separate request.
+    pub async fn mark_watched(&self) -> Result<()> {
+        for group in self.season_groups().await? {
+            if let Some(season) = group.original_season() {
+                season.mark_watched().await?
+            }
+        }
+        Ok(())
+    }
+
+    /// Searches all episodes of this series whose title or description contains the given query
+    /// (case-insensitive). Only the original season of every season number is searched, so
+    /// every episode is only returned once and not for every dub. Note that this function makes
+    /// one request per season.
+    pub async fn search_episodes<S: AsRef<str>>(&self, query: S) -> Result<Vec<Episode>> {
+        let query = query.as_ref().to_lowercase();
+        let mut result = vec![];
+        for group in self.season_groups().await? {
+            if let Some(season) = group.original_season() {
+                result.extend(
+                    season
+                        .episodes()
+                        .try_collect::<Vec<_>>()
+                        .await?
+                        .into_iter()
+                        .filter(|e| {
+                            e.title.to_lowercase().contains(&query)
+                                || e.description.to_lowercase().contains(&query)
+                        }),
+                )
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the episode with the given season and episode number, like `S2E5`. Only the
+    /// original version (mostly the japanese one) of the season is searched. Special seasons and
+    /// specials without an episode number are ignored. Seasons which are split into multiple
+    /// cours (listed as separate seasons with the same season number) are treated as one season.
+    /// If the episode numbering restarts in a later cour, episodes are counted across all cours
+    /// (e.g. `E15` is the third episode of the second cour if the first one has 12 episodes).
+    /// Returns [`None`] if no such episode exists. Note that this function makes one request per cour until the episode
+    /// is found.
+    pub async fn episode(
+        &self,
+        season_number: u32,
+        episode_number: u32,
+    ) -> Result<Option<Episode>> {
+        let Some(group) = self
+            .season_groups()
+            .await?
+            .into_iter()
+            .find(|g| g.season_number == season_number)
+        else {
+            return Ok(None);
+        };
+        let Some(original) = group.original_season() else {
+            return Ok(None);
+        };
+        let mut cours: Vec<&Season> = group
+            .seasons
+            .iter()
+            .filter(|s| !s.is_special() && s.audio_locales == original.audio_locales)
+            .collect();
+        cours.sort_by_key(|s| s.season_sequence_number);
+
+        let mut episodes = vec![];
+        let mut numbering_restarts = false;
+        for (i, cour) in cours.into_iter().enumerate() {
+            let cour_episodes: Vec<Episode> = cour
+                .episodes()
+                .try_collect::<Vec<_>>()
+                .await?
+                .into_iter()
+                .filter(|e| e.episode_number.is_some())
+                .collect();
+            if let Some(episode) = cour_episodes
+                .iter()
+                .find(|e| e.episode_number == Some(episode_number))
+            {
+                return Ok(Some(episode.clone()));
+            }
+            numbering_restarts |=
+                i > 0 && cour_episodes.iter().any(|e| e.episode_number == Some(1));
+            episodes.extend(cour_episodes)
+        }
+        // if the numbering restarts in a later cour, the episode is the n-th episode of all cours.
+        // Otherwise, the episode just doesn't exist (e.g. because it's region locked), and the
+        // n-th episode would be a different one
+        if !numbering_restarts {
+            return Ok(None);
+        }
+        Ok(episode_number
+            .checked_sub(1)
+            .and_then(|i| episodes.into_iter().nth(i as usize)))
+    }
+
+    /// Returns all series seasons grouped by their season number. Every group contains all
+    /// seasons (usually one per audio locale) with the same season number.
+    pub async fn season_groups(&self) -> Result<Vec<SeasonGroup>> {
+        let mut groups: Vec<SeasonGroup> = vec![];
+        for season in self.seasons().try_collect::<Vec<_>>().await? {
+            if let Some(group) = groups
+                .iter_mut()
+                .find(|g| g.season_number == season.season_number)
+            {
+                group.seasons.push(season)
+            } else {
+                groups.push(SeasonGroup {
+                    season_number: season.season_number,
+                    seasons: vec![season],
+                })
+            }
+        }
+        Ok(groups)
+    }
+
+    /// Returns a summary of the series. Only needs a single request (the same as
+    /// [`Series::seasons`]). [`SeriesAggregate::runtime`] and [`SeriesAggregate::last_updated`]
+    /// are not set as they require the episodes of every season, use
+    /// [`Series::aggregate_with_episodes`] if you need them.
+    pub async fn aggregate(&self) -> Result<SeriesAggregate> {
+        Ok(Self::aggregate_seasons(
+            &self.seasons().try_collect::<Vec<_>>().await?,
+        ))
+    }
+
+    /// Like [`Series::aggregate`] but also requests the episodes of every season to calculate
+    /// [`SeriesAggregate::runtime`] and [`SeriesAggregate::last_updated`]. Needs one additional
+    /// request per season number, which are made with [`Concurrency::default`] concurrent
+    /// requests.
+    pub async fn aggregate_with_episodes(&self) -> Result<SeriesAggregate> {
+        let seasons = self.seasons().try_collect::<Vec<_>>().await?;
+        let mut aggregate = Self::aggregate_seasons(&seasons);
+
+        // dubs share the season number with the original season, so only the episodes of one
+        // season per season number are counted
+        let mut per_number: BTreeMap<u32, &Season> = BTreeMap::new();
+        for season in &seasons {
+            per_number
+                .entry(season.season_number)
+                .and_modify(|s| {
+                    if season.number_of_episodes > s.number_of_episodes {
+                        *s = season
+                    }
+                })
+                .or_insert(season);
+        }
+        let episodes: Vec<Vec<Episode>> = stream::iter(per_number.into_values())
+            .map(|season| season.episodes().try_collect::<Vec<_>>())
+            .buffered(Concurrency::default().0)
+            .try_collect()
+            .await?;
+
+        let mut runtime = Duration::zero();
+        for episode in episodes.iter().flatten() {
+            runtime += episode.duration;
+            aggregate.last_updated = aggregate.last_updated.max(Some(episode.upload_date))
+        }
+        aggregate.runtime = Some(runtime);
+        Ok(aggregate)
+    }
+
+    fn aggregate_seasons(seasons: &[Season]) -> SeriesAggregate {
+        let mut aggregate = SeriesAggregate {
+            is_complete: true,
+            ..Default::default()
+        };
+        for season in seasons {
+            let episodes = aggregate
+                .episodes_per_season
+                .entry(season.season_number)
+                .or_default();
+            *episodes = (*episodes).max(season.number_of_episodes);
+            let season_locales = aggregate
+                .locales_per_season
+                .entry(season.season_number)
+                .or_default();
+            for audio_locale in season.audio_locales.clone() {
+                if !season_locales.audio_locales.contains(&audio_locale) {
+                    season_locales.audio_locales.push(audio_locale.clone())
+                }
+                if !aggregate.audio_locales.contains(&audio_locale) {
+                    aggregate.audio_locales.push(audio_locale)
+                }
+            }
+            for subtitle_locale in season.subtitle_locales.clone() {
+                if !season_locales.subtitle_locales.contains(&subtitle_locale) {
+                    season_locales
+                        .subtitle_locales
+                        .push(subtitle_locale.clone())
+                }
+                if !aggregate.subtitle_locales.contains(&subtitle_locale) {
+                    aggregate.subtitle_locales.push(subtitle_locale)
+                }
+            }
+            aggregate.is_complete &= season.is_complete;
+        }
+        aggregate.episode_count = aggregate.episodes_per_season.values().sum();
+        aggregate
+    }
+
+    /// Check if any season of this series is available with the given audio locale. Only needs a
+    /// single request (the same as [`Series::seasons`]), use [`Series::aggregate`] to get all
+    /// audio and subtitle locales including a per season breakdown.
+    pub async fn has_audio(&self, audio_locale: &Locale) -> Result<bool> {
+        Ok(self.aggregate().await?.has_audio(audio_locale))
+    }
+
+    /// Returns all series seasons which have the given audio locale.
+    pub async fn seasons_with_audio(&self, audio_locale: Locale) -> Result<Vec<Season>> {
+        Ok(self
+            .seasons()
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .filter(|s| s.audio_locales.contains(&audio_locale))
+            .collect())
+    }
+
+    /// Returns all announced episodes which aren't available yet, sorted by the date they become
+    /// available ([`Episode::premium_available_date`]). Crunchyroll doesn't announce episodes for
+    /// every series, so this might be empty even if the series is still airing. Only seasons which
+    /// aren't complete are checked, every one of them requires a separate request.
+    pub async fn upcoming(&self) -> Result<Vec<Episode>> {
+        let now = Utc::now();
+        let mut upcoming = vec![];
+        for season in self
+            .seasons()
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .filter(|s| !s.is_complete)
+        {
+            upcoming.extend(
+                season
+                    .episodes()
+                    .try_collect::<Vec<_>>()
+                    .await?
+                    .into_iter()
+                    .filter(|e| e.premium_available_date > now),
+            )
+        }
+        upcoming.sort_by_key(|e| e.premium_available_date);
+        Ok(upcoming)
+    }
+
+    /// Returns all extra videos (trailers, PVs, clips, ...) of this series. They can be streamed
+    /// like every other episode via [`Episode::stream`].
+    pub async fn extra_videos(&self) -> Result<Vec<Episode>> {
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/cms/series/{}/extra_videos",
+            self.id
+        );
+        let mut extra_videos: Vec<Episode> = request_media(self.executor.clone(), endpoint).await?;
+        for extra_video in &mut extra_videos {
+            fix_empty_episode_versions(extra_video);
         }
-        Ok(seasons)
+        Ok(extra_videos)
+    }
+
+    /// Returns all trailers (and PVs / teasers) of this series. Trailers are regular extra videos
+    /// (see [`Series::extra_videos`]) and are detected by their title, which may not work for
+    /// every locale. Like every other episode, they can be played / downloaded via
+    /// [`Episode::stream`] (including hardsubs if available).
+    pub async fn trailers(&self) -> Result<Vec<Episode>> {
+        Ok(self
+            .extra_videos()
+            .await?
+            .into_iter()
+            .filter(|e| is_trailer_title(&e.title))
+            .collect())
     }
 
     /// Get music videos which are related to this series.
     pub async fn featured_music(&self) -> Result<Vec<MusicVideo>> {
+        Ok(self
+            .related_music()
+            .await?
+            .into_iter()
+            .filter_map(|m| match m {
+                MediaCollection::MusicVideo(music_video) => Some(music_video),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Get all music related to this series, like opening / ending performances
+    /// ([`MediaCollection::MusicVideo`]) or concerts ([`MediaCollection::Concert`]).
+    pub async fn related_music(&self) -> Result<Vec<MediaCollection>> {
         let endpoint = format!(
             "https://www.crunchyroll.com/content/v2/music/featured/{}",
             self.id
@@ -157,15 +596,7 @@ impl Series {
 #[async_trait::async_trait]
 impl Media for Series {
     async fn from_id(crunchyroll: &Crunchyroll, id: impl AsRef<str> + Send) -> Result<Self> {
-        Ok(request_media(
-            crunchyroll.executor.clone(),
-            format!(
-                "https://www.crunchyroll.com/content/v2/cms/series/{}",
-                id.as_ref()
-            ),
-        )
-        .await?
-        .remove(0))
+        request_media_object(crunchyroll.executor.clone(), "series", id.as_ref()).await
     }
 
     async fn __set_executor(&mut self, executor: Arc<Executor>) {
@@ -175,7 +606,7 @@ impl Media for Series {
     #[cfg(feature = "experimental-stabilizations")]
     async fn __apply_experimental_stabilizations(&mut self) {
         if self.executor.fixes.locale_name_parsing {
-            if let Ok(seasons) = self.seasons().await {
+            if let Ok(seasons) = self.seasons().try_collect::<Vec<_>>().await {
                 let mut locales = vec![];
                 for season in seasons {
                     locales.extend(
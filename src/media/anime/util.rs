@@ -1,5 +1,3 @@
-#[cfg(feature = "__test_strict")]
-use crate::internal::strict::StrictValue;
 use crate::media::{EpisodeVersion, SeasonVersion};
 use crate::{Episode, Locale, Season};
 
@@ -59,8 +57,7 @@ pub(crate) fn fix_empty_season_versions(season: &mut Season) {
                 .clone(),
             original: true,
             restriction_windows: vec![],
-            #[cfg(feature = "__test_strict")]
-            variant: StrictValue::default(),
+            variant: None,
         })
     }
 }
@@ -75,8 +72,19 @@ pub(crate) fn fix_empty_episode_versions(episode: &mut Episode) {
             season_id: episode.season_id.clone(),
             is_premium_only: episode.is_premium_only,
             original: true,
-            #[cfg(feature = "__test_strict")]
-            variant: StrictValue::default(),
+            variant: None,
         })
     }
 }
+
+/// Crunchyroll doesn't mark extra videos with their type, so trailers are detected by their title,
+/// which is most of the time something like 'Trailer', 'Official Trailer' or 'PV 2'.
+pub(crate) fn is_trailer_title<S: AsRef<str>>(title: S) -> bool {
+    let title = title.as_ref().to_lowercase();
+    title.contains("trailer")
+        || title.contains("teaser")
+        || title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter_map(|word| word.strip_prefix("pv"))
+            .any(|number| number.chars().all(|c| c.is_ascii_digit()))
+}
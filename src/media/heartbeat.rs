@@ -0,0 +1,150 @@
+use crate::crunchyroll::RequestPriority;
+use crate::{EmptyJsonProxy, Executor, Result};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Interval which is used if the api doesn't hint one (see
+/// [`crate::media::StreamSession::renew_seconds`]).
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Reports the playback progress of a [`crate::media::Stream`] in the background while it is
+/// played, like the official apps do. Every [`crate::media::StreamSession::renew_seconds`] the
+/// stream session is kept alive (if it counts towards the concurrent stream limit) and the
+/// playhead is updated if it changed. Created via [`crate::media::Stream::heartbeat`].
+///
+/// The reporting stops when this struct is dropped. Use [`PlaybackHeartbeat::stop`] to also
+/// report the last position before stopping. Requires a running tokio runtime.
+#[cfg_attr(docsrs, doc(cfg(feature = "heartbeat")))]
+#[derive(Debug)]
+pub struct PlaybackHeartbeat {
+    executor: Arc<Executor>,
+    id: String,
+
+    position: Arc<AtomicU32>,
+    paused: Arc<AtomicBool>,
+    reported: Arc<AtomicU32>,
+
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl PlaybackHeartbeat {
+    pub(crate) fn start(
+        executor: Arc<Executor>,
+        id: String,
+        token: String,
+        renew_seconds: u32,
+        uses_stream_limits: bool,
+    ) -> Self {
+        let position = Arc::new(AtomicU32::new(0));
+        let paused = Arc::new(AtomicBool::new(false));
+        // `u32::MAX` marks that no playhead was reported yet
+        let reported = Arc::new(AtomicU32::new(u32::MAX));
+
+        let interval = if renew_seconds == 0 {
+            DEFAULT_INTERVAL
+        } else {
+            Duration::from_secs(renew_seconds as u64)
+        };
+
+        let task = tokio::spawn({
+            let executor = executor.clone();
+            let id = id.clone();
+            let position = position.clone();
+            let paused = paused.clone();
+            let reported = reported.clone();
+            async move {
+                let mut ticker = tokio::time::interval(interval);
+                ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                // the first tick completes immediately and the session was just created
+                ticker.tick().await;
+
+                loop {
+                    ticker.tick().await;
+                    let current = position.load(Ordering::Relaxed);
+
+                    // errors are ignored, the next tick tries again. Failed requests can be
+                    // observed via `Crunchyroll::events`
+                    if uses_stream_limits {
+                        let _ = keep_alive(&executor, &id, &token, current).await;
+                    }
+                    if !paused.load(Ordering::Relaxed)
+                        && reported.load(Ordering::Relaxed) != current
+                        && report_playhead(&executor, &id, current).await.is_ok()
+                    {
+                        reported.store(current, Ordering::Relaxed)
+                    }
+                }
+            }
+        });
+
+        Self {
+            executor,
+            id,
+            position,
+            paused,
+            reported,
+            task,
+        }
+    }
+
+    /// Set the current playback position in seconds. It is sent with the next heartbeat.
+    pub fn set_position(&self, seconds: u32) {
+        self.position.store(seconds, Ordering::Relaxed)
+    }
+
+    /// The current playback position in seconds, as set by [`PlaybackHeartbeat::set_position`].
+    pub fn position(&self) -> u32 {
+        self.position.load(Ordering::Relaxed)
+    }
+
+    /// Pause or resume playhead reporting. The stream session is still kept alive while paused.
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed)
+    }
+
+    /// Stop the background reporting and report the last position if it wasn't reported yet.
+    pub async fn stop(self) -> Result<()> {
+        self.task.abort();
+
+        let current = self.position();
+        if self.reported.load(Ordering::Relaxed) != current {
+            report_playhead(&self.executor, &self.id, current).await?
+        }
+        Ok(())
+    }
+}
+
+impl Drop for PlaybackHeartbeat {
+    fn drop(&mut self) {
+        self.task.abort()
+    }
+}
+
+async fn keep_alive(executor: &Arc<Executor>, id: &str, token: &str, position: u32) -> Result<()> {
+    let endpoint =
+        format!("https://cr-play-service.prd.crunchyrollsvc.com/v1/token/{id}/{token}/keepAlive");
+    executor
+        .patch(endpoint)
+        .query(&[("playhead", position)])
+        .priority(RequestPriority::Playback)
+        .request_raw(true)
+        .await?;
+    Ok(())
+}
+
+async fn report_playhead(executor: &Arc<Executor>, id: &str, position: u32) -> Result<()> {
+    // playheads can only be stored for logged in accounts
+    let Some(account_id) = executor.details.account_id.clone().ok() else {
+        return Ok(());
+    };
+    let endpoint = format!("https://www.crunchyroll.com/content/v2/{account_id}/playheads");
+    executor
+        .post(endpoint)
+        .apply_locale_query()
+        .json(&serde_json::json!({"content_id": id, "playhead": position}))
+        .priority(RequestPriority::Playback)
+        .request::<EmptyJsonProxy>()
+        .await?;
+    Ok(())
+}
@@ -1,12 +1,16 @@
 use crate::common::Request;
 use crate::crunchyroll::Executor;
 use crate::error::Error;
-use crate::media::Media;
+#[cfg(feature = "streaming")]
+use crate::media::Stream;
+use crate::media::{LocalizedTitle, Media};
 use crate::{
-    Concert, Crunchyroll, Episode, Movie, MovieListing, MusicVideo, Result, Season, Series,
+    Concert, Crunchyroll, Episode, Locale, Movie, MovieListing, MusicVideo, Result, Season, Series,
 };
+use futures_util::future::try_join_all;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Collection of all media types. Useful in situations where [`Media`] can contain more than one
@@ -50,6 +54,172 @@ impl MediaCollection {
     }
 }
 
+impl MediaCollection {
+    /// The id of the contained media.
+    pub fn id(&self) -> &str {
+        match self {
+            MediaCollection::Series(series) => &series.id,
+            MediaCollection::Season(season) => &season.id,
+            MediaCollection::Episode(episode) => &episode.id,
+            MediaCollection::MovieListing(movie_listing) => &movie_listing.id,
+            MediaCollection::Movie(movie) => &movie.id,
+            MediaCollection::MusicVideo(music_video) => &music_video.id,
+            MediaCollection::Concert(concert) => &concert.id,
+        }
+    }
+
+    /// Requests the stream of the contained media directly, without requesting the full media
+    /// object first. Only works for [`MediaCollection::Episode`], [`MediaCollection::Movie`],
+    /// [`MediaCollection::MusicVideo`] and [`MediaCollection::Concert`].
+    #[cfg(feature = "streaming")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "streaming")))]
+    pub async fn stream(&self) -> Result<Stream> {
+        match self {
+            MediaCollection::Episode(episode) => episode.stream().await,
+            MediaCollection::Movie(movie) => movie.stream().await,
+            MediaCollection::MusicVideo(music_video) => music_video.stream().await,
+            MediaCollection::Concert(concert) => concert.stream().await,
+            _ => Err(Error::Input {
+                message: format!("media with id '{}' has no stream", self.id()),
+            }),
+        }
+    }
+
+    /// Requests the full media object. Useful if the media was obtained from a response which
+    /// only contains a subset of the metadata (e.g. feeds, search results or watchlist entries).
+    pub async fn full(&self) -> Result<MediaCollection> {
+        self.full_with_executor(self.executor()).await
+    }
+
+    /// Requests the title and description of the contained media in all given locales
+    /// concurrently. Fails if any of the requests fails.
+    pub async fn localized_metadata(
+        &self,
+        locales: Vec<Locale>,
+    ) -> Result<HashMap<Locale, LocalizedTitle>> {
+        Ok(self
+            .fetch_localized(&locales)
+            .await?
+            .into_iter()
+            .map(|localized| (localized.locale.clone(), localized))
+            .collect())
+    }
+
+    /// Requests the title and description of the contained media in all given locales
+    /// concurrently. The result has the same order as `locales`.
+    pub(crate) async fn fetch_localized(&self, locales: &[Locale]) -> Result<Vec<LocalizedTitle>> {
+        let executor = self.executor();
+        try_join_all(locales.iter().map(|locale| {
+            let executor = executor.with_locale(locale.clone());
+            async move {
+                let media = self.full_with_executor(executor).await?;
+                Ok(media.localized(locale.clone()))
+            }
+        }))
+        .await
+    }
+
+    async fn full_with_executor(&self, executor: Arc<Executor>) -> Result<MediaCollection> {
+        let crunchyroll = Crunchyroll { executor };
+        Ok(match self {
+            MediaCollection::Series(series) => {
+                MediaCollection::Series(Series::from_id(&crunchyroll, &series.id).await?)
+            }
+            MediaCollection::Season(season) => {
+                MediaCollection::Season(Season::from_id(&crunchyroll, &season.id).await?)
+            }
+            MediaCollection::Episode(episode) => {
+                MediaCollection::Episode(Episode::from_id(&crunchyroll, &episode.id).await?)
+            }
+            MediaCollection::MovieListing(movie_listing) => MediaCollection::MovieListing(
+                MovieListing::from_id(&crunchyroll, &movie_listing.id).await?,
+            ),
+            MediaCollection::Movie(movie) => {
+                MediaCollection::Movie(Movie::from_id(&crunchyroll, &movie.id).await?)
+            }
+            MediaCollection::MusicVideo(music_video) => MediaCollection::MusicVideo(
+                MusicVideo::from_id(&crunchyroll, &music_video.id).await?,
+            ),
+            MediaCollection::Concert(concert) => {
+                MediaCollection::Concert(Concert::from_id(&crunchyroll, &concert.id).await?)
+            }
+        })
+    }
+
+    fn localized(&self, locale: Locale) -> LocalizedTitle {
+        let (title, slug_title, description) = match self {
+            MediaCollection::Series(series) => {
+                (&series.title, &series.slug_title, &series.description)
+            }
+            MediaCollection::Season(season) => {
+                (&season.title, &season.slug_title, &season.description)
+            }
+            MediaCollection::Episode(episode) => {
+                (&episode.title, &episode.slug_title, &episode.description)
+            }
+            MediaCollection::MovieListing(movie_listing) => (
+                &movie_listing.title,
+                &movie_listing.slug_title,
+                &movie_listing.description,
+            ),
+            MediaCollection::Movie(movie) => (&movie.title, &movie.slug_title, &movie.description),
+            MediaCollection::MusicVideo(music_video) => (
+                &music_video.title,
+                &music_video.slug,
+                &music_video.description,
+            ),
+            MediaCollection::Concert(concert) => {
+                (&concert.title, &concert.slug, &concert.description)
+            }
+        };
+        LocalizedTitle {
+            locale,
+            title: title.clone(),
+            slug_title: slug_title.clone(),
+            description: description.clone(),
+        }
+    }
+
+    fn executor(&self) -> Arc<Executor> {
+        match self {
+            MediaCollection::Series(series) => series.executor.clone(),
+            MediaCollection::Season(season) => season.executor.clone(),
+            MediaCollection::Episode(episode) => episode.executor.clone(),
+            MediaCollection::MovieListing(movie_listing) => movie_listing.executor.clone(),
+            MediaCollection::Movie(movie) => movie.executor.clone(),
+            MediaCollection::MusicVideo(music_video) => music_video.executor.clone(),
+            MediaCollection::Concert(concert) => concert.executor.clone(),
+        }
+    }
+}
+
+/// A raw panel object. Feeds, the watchlist, the watch history and crunchylists embed the media
+/// they're referring to as panel, which can be converted into a [`MediaCollection`] via
+/// [`Crunchyroll::media_from_panel`]. Media converted via [`TryFrom`] instead has no session
+/// attached, so requests made from it will fail.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Panel(pub serde_json::Map<String, Value>);
+
+impl Panel {
+    /// Moves the fields of the nested episode metadata object to the top level, which is the
+    /// structure the media structs expect.
+    pub(crate) fn into_value(self) -> Value {
+        let mut as_map = self.0;
+        if let Some(Value::Object(mut episode_metadata)) = as_map.remove("episode_metadata") {
+            as_map.append(&mut episode_metadata)
+        }
+        Value::Object(as_map)
+    }
+}
+
+impl TryFrom<Panel> for MediaCollection {
+    type Error = Error;
+
+    fn try_from(value: Panel) -> Result<Self> {
+        Ok(serde_json::from_value(value.into_value())?)
+    }
+}
+
 impl Default for MediaCollection {
     fn default() -> Self {
         Self::Series(Series::default())
@@ -67,39 +237,39 @@ impl<'de> Deserialize<'de> for MediaCollection {
 
         if as_map.contains_key("series_metadata") || as_map.contains_key("series_launch_year") {
             Ok(MediaCollection::Series(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
+                crate::internal::strict::from_value(Value::from(as_map)).map_err(err_conv)?,
             ))
         } else if as_map.contains_key("season_metadata")
             || as_map.contains_key("number_of_episodes")
         {
             Ok(MediaCollection::Season(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
+                crate::internal::strict::from_value(Value::from(as_map)).map_err(err_conv)?,
             ))
         } else if as_map.contains_key("episode_metadata") || as_map.contains_key("sequence_number")
         {
             Ok(MediaCollection::Episode(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
+                crate::internal::strict::from_value(Value::from(as_map)).map_err(err_conv)?,
             ))
         } else if as_map.contains_key("movie_listing_metadata")
             || as_map.contains_key("movie_release_year")
         {
             Ok(MediaCollection::MovieListing(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
+                crate::internal::strict::from_value(Value::from(as_map)).map_err(err_conv)?,
             ))
         } else if as_map.contains_key("movie_metadata")
             || as_map.contains_key("movie_listing_title")
         {
             Ok(MediaCollection::Movie(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
+                crate::internal::strict::from_value(Value::from(as_map)).map_err(err_conv)?,
             ))
         } else if as_map.contains_key("animeIds") {
             Ok(MediaCollection::MusicVideo(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
+                crate::internal::strict::from_value(Value::from(as_map)).map_err(err_conv)?,
             ))
         // music video contains this field too so music video must be checked before this condition
         } else if as_map.contains_key("availability") {
             Ok(MediaCollection::Concert(
-                serde_json::from_value(Value::from(as_map)).map_err(err_conv)?,
+                crate::internal::strict::from_value(Value::from(as_map)).map_err(err_conv)?,
             ))
         } else {
             Err(serde::de::Error::custom(
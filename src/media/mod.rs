@@ -1,20 +1,28 @@
 //! All media items like series, episodes or movies.
 
 mod anime;
+#[cfg(all(feature = "heartbeat", not(target_arch = "wasm32")))]
+mod heartbeat;
 mod media_collection;
 mod music;
 mod shared;
+#[cfg(feature = "streaming")]
 mod stream;
 mod util;
 
 pub use anime::*;
+#[cfg(all(feature = "heartbeat", not(target_arch = "wasm32")))]
+pub use heartbeat::*;
 pub use media_collection::*;
 pub use music::*;
 pub use shared::*;
+#[cfg(feature = "streaming")]
 pub use stream::*;
 
 use crate::crunchyroll::Executor;
-use crate::{Crunchyroll, Result};
+use crate::error::Error;
+use crate::{Crunchyroll, Locale, Request, Result};
+use futures_util::StreamExt;
 use std::sync::Arc;
 
 crate::enum_values! {
@@ -25,6 +33,49 @@ crate::enum_values! {
     }
 }
 
+/// How many requests [`Crunchyroll::fetch_all`] sends at the same time. `0` is treated as `1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Concurrency(pub usize);
+
+impl Default for Concurrency {
+    fn default() -> Self {
+        Self(8)
+    }
+}
+
+/// A media which couldn't be fetched by [`Crunchyroll::fetch_all`].
+#[derive(Clone, Debug)]
+pub struct FetchFailure {
+    /// Position of the id in the input.
+    pub index: usize,
+    pub id: String,
+    pub error: Error,
+}
+
+/// Result of [`Crunchyroll::fetch_all`].
+#[derive(Clone, Debug)]
+pub struct FetchReport<M> {
+    /// All successfully fetched media, in the order of the input ids.
+    pub fetched: Vec<M>,
+    /// All ids which couldn't be fetched, in the order of the input ids.
+    pub failed: Vec<FetchFailure>,
+}
+
+impl<M> FetchReport<M> {
+    /// If all media was fetched successfully.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Returns all fetched media or the error of the first failed id.
+    pub fn into_result(self) -> Result<Vec<M>> {
+        match self.failed.into_iter().next() {
+            Some(failure) => Err(failure.error),
+            None => Ok(self.fetched),
+        }
+    }
+}
+
 /// Trait every media struct ([`Series`], [`Season`], [`Episode`], [`MovieListing`], [`Movie`],
 /// [`MusicVideo`], [`Concert`]) implements.
 #[async_trait::async_trait]
@@ -51,4 +102,86 @@ impl Crunchyroll {
     pub async fn media_collection_from_id<S: AsRef<str>>(&self, id: S) -> Result<MediaCollection> {
         MediaCollection::from_id(self, id).await
     }
+
+    /// Converts a raw [`Panel`] into a [`MediaCollection`] which is attached to this session, so
+    /// that requests can be made from it (unlike media converted via [`TryFrom`]).
+    pub async fn media_from_panel(&self, panel: Panel) -> Result<MediaCollection> {
+        let mut media = MediaCollection::try_from(panel)?;
+        media.__set_executor(self.executor.clone()).await;
+        Ok(media)
+    }
+
+    /// Fetch multiple media by their ids with at most `concurrency` requests at the same time. The
+    /// order of the input is preserved and failed requests don't abort the other ones but are
+    /// collected in [`FetchReport::failed`].
+    ///
+    /// ```no_run
+    /// use crunchyroll_rs::media::Concurrency;
+    /// use crunchyroll_rs::Episode;
+    ///
+    /// # async fn f(crunchyroll: crunchyroll_rs::Crunchyroll) {
+    /// let report = crunchyroll
+    ///     .fetch_all::<Episode>(["GRDKJZ81Y", "G6QW40DE6"], Concurrency(8))
+    ///     .await;
+    /// for failure in &report.failed {
+    ///     println!("failed to fetch {}: {}", failure.id, failure.error)
+    /// }
+    /// let episodes = report.fetched;
+    /// # }
+    /// ```
+    pub async fn fetch_all<M: Media + Send>(
+        &self,
+        ids: impl IntoIterator<Item = impl AsRef<str>>,
+        concurrency: Concurrency,
+    ) -> FetchReport<M> {
+        let ids: Vec<String> = ids.into_iter().map(|id| id.as_ref().to_string()).collect();
+        let results: Vec<(usize, String, Result<M>)> =
+            futures_util::stream::iter(ids.into_iter().enumerate())
+                .map(|(index, id)| async move {
+                    let result = M::from_id(self, &id).await;
+                    (index, id, result)
+                })
+                .buffered(concurrency.0.max(1))
+                .collect()
+                .await;
+
+        let mut report = FetchReport {
+            fetched: vec![],
+            failed: vec![],
+        };
+        for (index, id, result) in results {
+            match result {
+                Ok(media) => report.fetched.push(media),
+                Err(error) => report.failed.push(FetchFailure { index, id, error }),
+            }
+        }
+        report
+    }
+
+    /// Like [`Crunchyroll::media_from_id`] but the metadata (title, description, ...) is returned
+    /// in the given locale instead of the locale the client was built with. All requests made
+    /// from the returned media (e.g. [`crate::Series::seasons`]) are using this locale too.
+    pub async fn media_with_locale<M: Media>(
+        &self,
+        id: impl AsRef<str> + Send,
+        locale: Locale,
+    ) -> Result<M> {
+        let crunchyroll = Crunchyroll {
+            executor: self.executor.with_locale(locale),
+        };
+        M::from_id(&crunchyroll, id).await
+    }
+
+    /// Like [`Crunchyroll::media_collection_from_id`] but the metadata (title, description, ...)
+    /// is returned in the given locale instead of the locale the client was built with.
+    pub async fn media_collection_with_locale<S: AsRef<str>>(
+        &self,
+        id: S,
+        locale: Locale,
+    ) -> Result<MediaCollection> {
+        let crunchyroll = Crunchyroll {
+            executor: self.executor.with_locale(locale),
+        };
+        MediaCollection::from_id(&crunchyroll, id).await
+    }
 }
@@ -19,6 +19,10 @@ pub struct Concert {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
 
+    #[cfg(feature = "raw-json")]
+    #[serde(skip)]
+    pub(crate) raw: serde_json::Value,
+
     pub id: String,
     #[serde(alias = "streams_link")]
     #[serde(deserialize_with = "crate::internal::serde::deserialize_streams_link")]
@@ -30,7 +34,9 @@ pub struct Concert {
 
     pub sequence_number: f32,
 
+    /// The main artist of the concert. Use [`ArtistPreview::artist`] to get the full artist.
     pub artist: ArtistPreview,
+    /// All artists participating in the concert. Use [`Concert::artists`] to get the full artists.
     pub artists: ArtistsPreviewList,
     pub display_artist_name: String,
     pub display_artist_name_required: bool,
@@ -53,11 +59,28 @@ pub struct Concert {
     #[serde(serialize_with = "crate::internal::serde::serialize_duration_to_millis")]
     #[default(Duration::try_milliseconds(0).unwrap())]
     pub duration: Duration,
+    /// When the concert took place.
     #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
     pub original_release: DateTime<Utc>,
-
+    /// Name of the venue the concert took place at. [`None`] if Crunchyroll doesn't provide it.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_empty_pre_string_to_none")]
+    pub venue: Option<String>,
+    /// City / country of the venue. [`None`] if Crunchyroll doesn't provide it.
+    #[serde(default)]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_empty_pre_string_to_none")]
+    pub location: Option<String>,
+    /// Name of the event, festival or tour the concert is part of. [`None`] if Crunchyroll doesn't
+    /// provide it.
+    #[serde(default)]
+    #[serde(alias = "event_name")]
+    #[serde(deserialize_with = "crate::internal::serde::deserialize_empty_pre_string_to_none")]
+    pub event_name: Option<String>,
+
+    /// Start of the window in which the concert can be streamed.
     #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
     pub availability_starts: DateTime<Utc>,
+    /// End of the window in which the concert can be streamed.
     #[default(DateTime::<Utc>::from(std::time::SystemTime::UNIX_EPOCH))]
     pub availability_ends: DateTime<Utc>,
 
@@ -73,7 +96,14 @@ pub struct Concert {
     /// Yea a hash. Md5. For what every reason.
     pub hash: String,
 
-    #[cfg(feature = "__test_strict")]
+    /// Fields returned by Crunchyroll which aren't modelled by this struct (yet).
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(flatten))]
+    #[cfg_attr(feature = "__test_strict", serde(skip))]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+
+    #[serde(skip_serializing)]
     #[serde(rename = "type")]
     type_: crate::StrictValue,
 }
@@ -84,15 +114,24 @@ impl<'de> Deserialize<'de> for Concert {
         D: Deserializer<'de>,
     {
         let mut as_map = serde_json::Map::deserialize(deserializer)?;
+        #[cfg(feature = "raw-json")]
+        let raw = serde_json::Value::Object(as_map.clone());
 
         availability_object_to_keys(&mut as_map).map_err(|e| Error::custom(e.to_string()))?;
 
-        Concert::deserialize(
-            serde_json::to_value(as_map)
-                .map_err(|e| Error::custom(e.to_string()))?
-                .into_deserializer(),
-        )
-        .map_err(|e| Error::custom(e.to_string()))
+        let value = serde_json::to_value(as_map).map_err(|e| Error::custom(e.to_string()))?;
+        #[allow(unused_mut)]
+        let mut media = if crate::internal::strict::is_active() {
+            crate::internal::strict::deserialize_tracked(value, |d| Concert::deserialize(d))
+        } else {
+            Concert::deserialize(value.into_deserializer())
+        }
+        .map_err(|e| Error::custom(e.to_string()))?;
+        #[cfg(feature = "raw-json")]
+        {
+            media.raw = raw;
+        }
+        Ok(media)
     }
 }
 
@@ -0,0 +1,172 @@
+use crate::common::{Pagination, PaginationBulkResultMeta, V2BulkResult};
+use crate::crunchyroll::Executor;
+use crate::media::util::request_media;
+use crate::media::{Artist, Concert, MusicVideo};
+use crate::{Crunchyroll, Request, Result};
+use futures_util::FutureExt;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::sync::Arc;
+
+/// A curated collection of music videos, concerts or artists on the music feed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+pub struct MusicFeedCollection {
+    #[serde(skip)]
+    executor: Arc<Executor>,
+
+    pub title: String,
+    pub description: String,
+
+    /// Ids of the music videos, concerts or artists in this collection, depending on the
+    /// [`MusicFeed`] variant this collection is contained in.
+    pub ids: Vec<String>,
+
+    #[serde(skip_serializing)]
+    #[serde(rename = "response_type")]
+    _response_type: crate::StrictValue,
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    #[serde(rename = "resource_type")]
+    _resource_type: Option<crate::StrictValue>,
+    #[serde(default)]
+    #[serde(skip_serializing)]
+    #[serde(rename = "id")]
+    _id: Option<crate::StrictValue>,
+}
+
+impl MusicFeedCollection {
+    /// Returns all music videos of this collection. Only returns results if the collection was
+    /// obtained via [`MusicFeed::MusicVideos`].
+    pub async fn music_videos(&self) -> Result<Vec<MusicVideo>> {
+        if self.ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/music/music_videos/{}",
+            self.ids.join(",")
+        );
+        request_media(self.executor.clone(), endpoint).await
+    }
+
+    /// Returns all concerts of this collection. Only returns results if the collection was
+    /// obtained via [`MusicFeed::Concerts`].
+    pub async fn concerts(&self) -> Result<Vec<Concert>> {
+        if self.ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/music/concerts/{}",
+            self.ids.join(",")
+        );
+        request_media(self.executor.clone(), endpoint).await
+    }
+
+    /// Returns all artists of this collection. Only returns results if the collection was
+    /// obtained via [`MusicFeed::Artists`].
+    pub async fn artists(&self) -> Result<Vec<Artist>> {
+        if self.ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let endpoint = format!(
+            "https://www.crunchyroll.com/content/v2/music/artists/{}",
+            self.ids.join(",")
+        );
+        request_media(self.executor.clone(), endpoint).await
+    }
+}
+
+/// Items which can be shown on the music feed.
+#[derive(Clone, Debug, Serialize)]
+pub enum MusicFeed {
+    /// Featured / new / popular music videos. Use [`MusicFeedCollection::music_videos`] to get
+    /// them.
+    MusicVideos(MusicFeedCollection),
+    /// Featured / new / popular concerts. Use [`MusicFeedCollection::concerts`] to get them.
+    Concerts(MusicFeedCollection),
+    /// Featured / popular artists. Use [`MusicFeedCollection::artists`] to get them.
+    Artists(MusicFeedCollection),
+    /// Crunchyroll may update their feed / add new items. This field catches everything which is
+    /// unknown / not implemented in the library.
+    Unknown(serde_json::Map<String, serde_json::Value>),
+}
+
+impl Default for MusicFeed {
+    fn default() -> Self {
+        Self::Unknown(serde_json::Map::default())
+    }
+}
+
+#[async_trait::async_trait]
+impl Request for MusicFeed {
+    async fn __set_executor(&mut self, executor: Arc<Executor>) {
+        match self {
+            MusicFeed::MusicVideos(collection)
+            | MusicFeed::Concerts(collection)
+            | MusicFeed::Artists(collection) => Request::__set_executor(collection, executor).await,
+            MusicFeed::Unknown(_) => (),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MusicFeed {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let as_map = serde_json::Map::deserialize(deserializer)?;
+
+        let map_serde_error = |e: serde_json::Error| Error::custom(e.to_string());
+        let response_type = as_map
+            .get("response_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let collection = || -> std::result::Result<MusicFeedCollection, D::Error> {
+            crate::internal::strict::from_value(serde_json::Value::Object(as_map.clone()))
+                .map_err(map_serde_error)
+        };
+
+        match response_type.as_str() {
+            "music_video" => Ok(Self::MusicVideos(collection()?)),
+            "music_concert" => Ok(Self::Concerts(collection()?)),
+            "artist" => Ok(Self::Artists(collection()?)),
+            #[cfg(feature = "__test_strict")]
+            _ => Err(Error::custom(format!(
+                "cannot parse music feed response type '{response_type}'"
+            ))),
+            #[cfg(not(feature = "__test_strict"))]
+            _ => Ok(Self::Unknown(as_map)),
+        }
+    }
+}
+
+impl Crunchyroll {
+    /// Returns the music feed (shown when visiting the music section of Crunchyroll). It contains
+    /// collections like featured music videos, new releases or popular artists.
+    pub fn music_feed(&self) -> Pagination<MusicFeed> {
+        Pagination::new(
+            |options| {
+                async move {
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content/v2/discover/{}/music_feed",
+                        options.executor.details.account_id.clone()?
+                    );
+                    let result = options
+                        .executor
+                        .get(endpoint)
+                        .query(&[("n", options.page_size), ("start", options.start)])
+                        .apply_locale_query()
+                        .request::<V2BulkResult<MusicFeed, PaginationBulkResultMeta>>()
+                        .await?;
+                    Ok(result.into())
+                }
+                .boxed()
+            },
+            self.executor.clone(),
+            None,
+            None,
+        )
+    }
+}
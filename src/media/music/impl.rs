@@ -1,3 +1,4 @@
+use crate::media::util::request_media;
 use crate::media::Artist;
 use crate::{Concert, MusicVideo, Result};
 
@@ -20,6 +21,26 @@ impl_manual_media_serialize! {
     Concert MusicVideo
 }
 
+macro_rules! impl_media_raw {
+    ($($media:ident)*) => {
+        $(
+            #[cfg(feature = "raw-json")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "raw-json")))]
+            impl $media {
+                /// The raw json this media was deserialized from. Contains all fields returned by
+                /// Crunchyroll, including the ones which aren't modelled by this struct.
+                pub fn raw(&self) -> &serde_json::Value {
+                    &self.raw
+                }
+            }
+        )*
+    }
+}
+
+impl_media_raw! {
+    Concert MusicVideo
+}
+
 macro_rules! impl_media_music {
     ($($media_music:ident)*) => {
         $(
@@ -27,6 +48,8 @@ macro_rules! impl_media_music {
                 /// Streams for this music video / concert.
                 /// All streams are drm encrypted, decryption is not handled in this crate, so you
                 /// must do this yourself.
+                #[cfg(feature = "streaming")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "streaming")))]
                 pub async fn stream(&self) -> Result<$crate::media::Stream> {
                     $crate::media::Stream::from_id_web_chrome(&$crate::Crunchyroll { executor: self.executor.clone() }, &self.id, Some("music".to_string())).await
                 }
@@ -35,6 +58,36 @@ macro_rules! impl_media_music {
                 pub async fn available(&self) -> bool {
                     self.executor.premium().await || !self.is_premium_only
                 }
+
+                /// Check if the current time is in the window in which the music video / concert
+                /// can be streamed.
+                pub fn in_availability_window(&self) -> bool {
+                    let now = chrono::Utc::now();
+                    self.availability_starts <= now && now <= self.availability_ends
+                }
+
+                /// Who can currently play the music video / concert.
+                pub fn playback_availability(&self) -> $crate::media::PlaybackAvailability {
+                    $crate::media::PlaybackAvailability {
+                        premium_only: self.is_premium_only,
+                        free_with_ads: !self.is_premium_only && self.in_availability_window(),
+                        mature: self.is_mature,
+                        mature_blocked: self.mature_blocked,
+                    }
+                }
+
+                /// Get all artists participating in this music video / concert.
+                pub async fn artists(&self) -> Result<Vec<Artist>> {
+                    let ids: Vec<String> = self.artists.main_artist.iter().map(|a| a.id.clone()).collect();
+                    if ids.is_empty() {
+                        return Ok(vec![])
+                    }
+                    let endpoint = format!(
+                        "https://www.crunchyroll.com/content/v2/music/artists/{}",
+                        ids.join(",")
+                    );
+                    request_media(self.executor.clone(), endpoint).await
+                }
             }
         )*
     }
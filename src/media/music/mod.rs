@@ -1,11 +1,13 @@
 mod artist;
 mod concert;
+mod feed;
 mod r#impl;
 mod music_video;
 mod util;
 
 pub use artist::*;
 pub use concert::*;
+pub use feed::*;
 pub use music_video::*;
 
 use crate::Request;
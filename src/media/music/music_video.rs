@@ -19,6 +19,10 @@ pub struct MusicVideo {
     #[serde(skip)]
     pub(crate) executor: Arc<Executor>,
 
+    #[cfg(feature = "raw-json")]
+    #[serde(skip)]
+    pub(crate) raw: serde_json::Value,
+
     pub id: String,
     #[serde(alias = "streams_link")]
     #[serde(deserialize_with = "crate::internal::serde::deserialize_streams_link")]
@@ -75,7 +79,14 @@ pub struct MusicVideo {
     /// Yea a hash. Md5. For what every reason.
     pub hash: String,
 
-    #[cfg(feature = "__test_strict")]
+    /// Fields returned by Crunchyroll which aren't modelled by this struct (yet).
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(flatten))]
+    #[cfg_attr(feature = "__test_strict", serde(skip))]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+
+    #[serde(skip_serializing)]
     #[serde(rename = "type")]
     type_: crate::StrictValue,
 }
@@ -86,15 +97,24 @@ impl<'de> Deserialize<'de> for MusicVideo {
         D: Deserializer<'de>,
     {
         let mut as_map = serde_json::Map::deserialize(deserializer)?;
+        #[cfg(feature = "raw-json")]
+        let raw = serde_json::Value::Object(as_map.clone());
 
         availability_object_to_keys(&mut as_map).map_err(|e| Error::custom(e.to_string()))?;
 
-        MusicVideo::deserialize(
-            serde_json::to_value(as_map)
-                .map_err(|e| Error::custom(e.to_string()))?
-                .into_deserializer(),
-        )
-        .map_err(|e| Error::custom(e.to_string()))
+        let value = serde_json::to_value(as_map).map_err(|e| Error::custom(e.to_string()))?;
+        #[allow(unused_mut)]
+        let mut media = if crate::internal::strict::is_active() {
+            crate::internal::strict::deserialize_tracked(value, |d| MusicVideo::deserialize(d))
+        } else {
+            MusicVideo::deserialize(value.into_deserializer())
+        }
+        .map_err(|e| Error::custom(e.to_string()))?;
+        #[cfg(feature = "raw-json")]
+        {
+            media.raw = raw;
+        }
+        Ok(media)
     }
 }
 
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes who can play a media. Available for episodes, movies, music videos and concerts.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PlaybackAvailability {
+    /// The media can only be watched with premium.
+    pub premium_only: bool,
+    /// The media can be watched without premium (with ads).
+    pub free_with_ads: bool,
+    /// The media is mature.
+    pub mature: bool,
+    /// The media is mature and blocked for the current account, e.g. because the maturity
+    /// settings of the account restrict it or the session is anonymous.
+    pub mature_blocked: bool,
+}
+
+impl PlaybackAvailability {
+    /// Check if the media can be played without premium, e.g. with an anonymous session.
+    pub fn playable_without_premium(&self) -> bool {
+        self.free_with_ads && !self.mature_blocked
+    }
+}
@@ -1,8 +1,145 @@
 use crate::common::Image;
+use crate::error::Error;
+use crate::{Crunchyroll, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 
-/// Images for a [`crate::Movie`] or [`crate::Concert`].
+/// Minimum size an image must have, see [`ImageVariants::at_least`]. A plain `u32` converts to a
+/// minimum width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MinSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl MinSize {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Only require a minimum width.
+    pub fn width(width: u32) -> Self {
+        Self { width, height: 0 }
+    }
+
+    /// Only require a minimum height.
+    pub fn height(height: u32) -> Self {
+        Self { width: 0, height }
+    }
+}
+
+impl From<u32> for MinSize {
+    fn from(width: u32) -> Self {
+        Self::width(width)
+    }
+}
+
+impl Image {
+    /// Download the raw image data.
+    pub async fn download(&self, crunchyroll: &Crunchyroll) -> Result<bytes::Bytes> {
+        crunchyroll
+            .executor
+            .get(&self.source)
+            .request_raw(false)
+            .await
+    }
+
+    /// Download the image and convert it to `format`. If `max_size` is set, the image is resized
+    /// to fit into it while preserving the aspect ratio. Images are never scaled up.
+    #[cfg(feature = "image-convert")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "image-convert")))]
+    pub async fn download_converted(
+        &self,
+        crunchyroll: &Crunchyroll,
+        format: image::ImageFormat,
+        max_size: Option<(u32, u32)>,
+    ) -> Result<Vec<u8>> {
+        let data = self.download(crunchyroll).await?;
+        let url = self.source.clone();
+
+        let convert = move || {
+            let decode_error = |e: image::ImageError| Error::Decode {
+                message: format!("failed to convert image: {e}"),
+                content: vec![],
+                url: url.clone(),
+            };
+
+            let mut image = image::load_from_memory(&data).map_err(decode_error)?;
+            if let Some((width, height)) = max_size {
+                if image.width() > width || image.height() > height {
+                    image = image.resize(width, height, image::imageops::FilterType::Lanczos3)
+                }
+            }
+            let mut converted = std::io::Cursor::new(vec![]);
+            image
+                .write_to(&mut converted, format)
+                .map_err(decode_error)?;
+            Ok(converted.into_inner())
+        };
+
+        // decoding, resizing and encoding is cpu heavy, so it must not block the async runtime.
+        // wasm has no threads to offload it to
+        #[cfg(not(target_arch = "wasm32"))]
+        return tokio::task::spawn_blocking(convert)
+            .await
+            .map_err(|e| Error::Internal {
+                message: format!("image conversion failed: {e}"),
+            })?;
+        #[cfg(target_arch = "wasm32")]
+        convert()
+    }
+}
+
+/// Helper to pick a specific image out of multiple size variants of the same image, like
+/// [`PosterImages::poster_tall`] or [`ThumbnailImages::thumbnail`].
+#[async_trait::async_trait]
+pub trait ImageVariants {
+    /// The image with the largest resolution.
+    fn largest(&self) -> Option<&Image>;
+
+    /// The smallest image which is at least as large as `min_size` in both dimensions. Pass a
+    /// `u32` to only require a minimum width. Falls back to [`ImageVariants::largest`] if no image
+    /// is large enough.
+    fn at_least(&self, min_size: impl Into<MinSize>) -> Option<&Image>;
+
+    /// Download the image which matches `min_size` best, see [`ImageVariants::at_least`].
+    async fn download(
+        &self,
+        crunchyroll: &Crunchyroll,
+        min_size: impl Into<MinSize> + Send,
+    ) -> Result<bytes::Bytes>;
+}
+
+#[async_trait::async_trait]
+impl ImageVariants for [Image] {
+    fn largest(&self) -> Option<&Image> {
+        self.iter().max_by_key(|i| i.width * i.height)
+    }
+
+    fn at_least(&self, min_size: impl Into<MinSize>) -> Option<&Image> {
+        let min_size = min_size.into();
+        self.iter()
+            .filter(|i| i.width >= min_size.width && i.height >= min_size.height)
+            .min_by_key(|i| i.width * i.height)
+            .or_else(|| self.largest())
+    }
+
+    async fn download(
+        &self,
+        crunchyroll: &Crunchyroll,
+        min_size: impl Into<MinSize> + Send,
+    ) -> Result<bytes::Bytes> {
+        let Some(image) = self.at_least(min_size) else {
+            return Err(Error::Input {
+                message: "no image variant available".to_string(),
+            });
+        };
+        image.download(crunchyroll).await
+    }
+}
+
+/// Images for a [`crate::Movie`] or [`crate::Concert`]. Use [`ImageVariants`] to pick a
+/// specific size.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(try_from = "Map<String, Value>")]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
@@ -15,23 +152,14 @@ impl TryFrom<Map<String, Value>> for ThumbnailImages {
     type Error = serde_json::Error;
 
     fn try_from(value: Map<String, Value>) -> Result<Self, Self::Error> {
-        if let Some(thumbnail) = value.get("thumbnail") {
-            if let Ok(thumb) = serde_json::from_value::<Vec<Vec<Image>>>(thumbnail.clone()) {
-                Ok(ThumbnailImages {
-                    thumbnail: thumb.into_iter().flatten().collect::<Vec<Image>>(),
-                })
-            } else {
-                Ok(ThumbnailImages {
-                    thumbnail: serde_json::from_value(thumbnail.clone())?,
-                })
-            }
-        } else {
-            Ok(ThumbnailImages { thumbnail: vec![] })
-        }
+        Ok(ThumbnailImages {
+            thumbnail: image_variants(&value, "thumbnail")?,
+        })
     }
 }
 
-/// Images for [`crate::Series`], [`crate::MovieListing`] or [`crate::media::Artist`].
+/// Images for [`crate::Series`], [`crate::MovieListing`] or [`crate::media::Artist`]. Use
+/// [`ImageVariants`] to pick a specific size.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(try_from = "Map<String, Value>")]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
@@ -45,28 +173,69 @@ impl TryFrom<Map<String, Value>> for PosterImages {
     type Error = serde_json::Error;
 
     fn try_from(value: Map<String, Value>) -> Result<Self, Self::Error> {
-        let tall = if let Some(tall) = value.get("poster_tall") {
-            if let Ok(img) = serde_json::from_value::<Vec<Vec<Image>>>(tall.clone()) {
-                img.into_iter().flatten().collect::<Vec<Image>>()
-            } else {
-                serde_json::from_value(tall.clone())?
-            }
-        } else {
-            vec![]
-        };
-        let wide = if let Some(wide) = value.get("poster_wide") {
-            if let Ok(img) = serde_json::from_value::<Vec<Vec<Image>>>(wide.clone()) {
-                img.into_iter().flatten().collect::<Vec<Image>>()
-            } else {
-                serde_json::from_value(wide.clone())?
-            }
-        } else {
-            vec![]
-        };
+        Ok(Self {
+            poster_tall: image_variants(&value, "poster_tall")?,
+            poster_wide: image_variants(&value, "poster_wide")?,
+        })
+    }
+}
+
+/// All images of a media, each with all its size variants. Use [`ImageVariants`] to pick a
+/// specific size. Media structs contain either [`PosterImages`] or [`ThumbnailImages`] which can
+/// be converted into this struct.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(try_from = "Map<String, Value>")]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+pub struct Images {
+    pub poster_tall: Vec<Image>,
+    pub poster_wide: Vec<Image>,
+    pub thumbnail: Vec<Image>,
+    /// Promotional artwork, e.g. key visuals.
+    pub promo_image: Vec<Image>,
+}
+
+impl TryFrom<Map<String, Value>> for Images {
+    type Error = serde_json::Error;
 
+    fn try_from(value: Map<String, Value>) -> Result<Self, Self::Error> {
         Ok(Self {
-            poster_tall: tall,
-            poster_wide: wide,
+            poster_tall: image_variants(&value, "poster_tall")?,
+            poster_wide: image_variants(&value, "poster_wide")?,
+            thumbnail: image_variants(&value, "thumbnail")?,
+            promo_image: image_variants(&value, "promo_image")?,
         })
     }
 }
+
+impl From<PosterImages> for Images {
+    fn from(images: PosterImages) -> Self {
+        Self {
+            poster_tall: images.poster_tall,
+            poster_wide: images.poster_wide,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ThumbnailImages> for Images {
+    fn from(images: ThumbnailImages) -> Self {
+        Self {
+            thumbnail: images.thumbnail,
+            ..Default::default()
+        }
+    }
+}
+
+/// Get all size variants of the image `key`. Crunchyroll returns them either as list or as list
+/// of lists.
+fn image_variants(value: &Map<String, Value>, key: &str) -> serde_json::Result<Vec<Image>> {
+    let Some(images) = value.get(key) else {
+        return Ok(vec![]);
+    };
+    if let Ok(images) = serde_json::from_value::<Vec<Vec<Image>>>(images.clone()) {
+        Ok(images.into_iter().flatten().collect())
+    } else {
+        serde_json::from_value(images.clone())
+    }
+}
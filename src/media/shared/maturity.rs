@@ -0,0 +1,76 @@
+use crate::enum_values;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+enum_values! {
+    /// Descriptors about media content which might be inappropriate for some viewers.
+    pub enum ContentDescriptor {
+        Violence = "Violence"
+        SexualizedImagery = "Sexualized Imagery"
+        Nudity = "Nudity"
+        Profanity = "Profanity"
+        SubstanceUse = "Substance Use"
+        SelfHarm = "Self-Harm"
+    }
+}
+
+/// Maturity rating of a media in a specific rating system.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+pub struct ExtendedMaturityRating {
+    /// The rating system, e.g. `TV`.
+    pub system: String,
+    /// The rating in [`ExtendedMaturityRating::system`], e.g. `14`.
+    pub rating: String,
+    /// Crunchyroll internal maturity level, e.g. `M2`.
+    pub level: String,
+}
+
+/// Maturity ratings of a media per region. Depending on the endpoint, Crunchyroll either returns
+/// the ratings keyed by region code or only the rating of the region the request was made from.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(try_from = "Map<String, Value>")]
+pub struct ExtendedMaturityRatings {
+    /// Rating in the rating system of the region the request was made from. [`None`] if
+    /// Crunchyroll returned the ratings per region, use [`ExtendedMaturityRatings::regions`]
+    /// instead.
+    pub current: Option<ExtendedMaturityRating>,
+    /// Ratings keyed by region code, e.g. `US`.
+    pub regions: HashMap<String, ExtendedMaturityRating>,
+}
+
+impl ExtendedMaturityRatings {
+    /// Get the rating of a region. Falls back to [`ExtendedMaturityRatings::current`] if no
+    /// per-region ratings are available.
+    pub fn get<S: AsRef<str>>(&self, region: S) -> Option<&ExtendedMaturityRating> {
+        if self.regions.is_empty() {
+            self.current.as_ref()
+        } else {
+            self.regions.get(region.as_ref())
+        }
+    }
+}
+
+impl TryFrom<Map<String, Value>> for ExtendedMaturityRatings {
+    type Error = serde_json::Error;
+
+    fn try_from(value: Map<String, Value>) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Ok(Self::default());
+        }
+        // a single rating has string values, per-region ratings have objects
+        if value.values().all(|v| v.is_object()) {
+            Ok(Self {
+                current: None,
+                regions: crate::internal::strict::from_value(Value::Object(value))?,
+            })
+        } else {
+            Ok(Self {
+                current: Some(crate::internal::strict::from_value(Value::Object(value))?),
+                regions: HashMap::new(),
+            })
+        }
+    }
+}
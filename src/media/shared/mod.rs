@@ -1,3 +1,9 @@
+mod availability;
 mod image;
+mod maturity;
+mod title;
 
+pub use availability::*;
 pub use image::*;
+pub use maturity::*;
+pub use title::*;
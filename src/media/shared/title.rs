@@ -0,0 +1,65 @@
+use crate::media::MediaCollection;
+use crate::{Episode, Locale, MovieListing, Result, Series};
+use serde::{Deserialize, Serialize};
+
+/// The title and description of a media in a specific locale. Music videos and concerts have no
+/// slug title, their slug is used instead.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct LocalizedTitle {
+    pub locale: Locale,
+    pub title: String,
+    pub slug_title: String,
+    pub description: String,
+}
+
+/// Crunchyroll returns the english title if no translation for a locale exists, so the title of
+/// the first locale which differs from the english title (or is english itself) is chosen.
+async fn preferred_title(
+    media: &MediaCollection,
+    locales: &[Locale],
+) -> Result<Option<LocalizedTitle>> {
+    let mut english_title = None;
+    for locale in locales {
+        let localized = fetch_single(media, locale.clone()).await?;
+        if locale == &Locale::en_US {
+            return Ok(Some(localized));
+        }
+        if english_title.is_none() {
+            english_title = Some(fetch_single(media, Locale::en_US).await?.title)
+        }
+        if Some(&localized.title) != english_title.as_ref() {
+            return Ok(Some(localized));
+        }
+    }
+    Ok(None)
+}
+
+async fn fetch_single(media: &MediaCollection, locale: Locale) -> Result<LocalizedTitle> {
+    let mut localized = media.fetch_localized(&[locale]).await?;
+    Ok(localized.remove(0))
+}
+
+macro_rules! impl_localized_title {
+    ($($media:ident)*) => {
+        $(
+            impl $media {
+                /// Returns the title in all given locales. Every locale requires a separate request.
+                /// If no translation exists for a locale, Crunchyroll returns the english title. See
+                /// [`MediaCollection::localized_metadata`] to get the titles keyed by locale.
+                pub async fn localized_titles(&self, locales: &[Locale]) -> Result<Vec<LocalizedTitle>> {
+                    MediaCollection::from(self.clone()).fetch_localized(locales).await
+                }
+
+                /// Returns the title in the first locale of the given preference list in which a
+                /// translated title exists. [`None`] is returned if no locale has a translation.
+                pub async fn preferred_title(&self, locales: &[Locale]) -> Result<Option<LocalizedTitle>> {
+                    preferred_title(&MediaCollection::from(self.clone()), locales).await
+                }
+            }
+        )*
+    }
+}
+
+impl_localized_title! {
+    Series MovieListing Episode
+}
@@ -1,5 +1,10 @@
+use crate::common::CancellationToken;
+use crate::crunchyroll::RequestPriority;
 use crate::error::{is_request_error, Error};
+use crate::events::ClientEvent;
 use crate::{Crunchyroll, Executor, Locale, Request, Result};
+use bytes::Bytes;
+use chrono::Utc;
 use dash_mpd::MPD;
 use reqwest::StatusCode;
 use serde::{Deserialize, Deserializer, Serialize};
@@ -49,11 +54,17 @@ pub struct StreamVersion {
     pub is_premium_only: bool,
     pub original: bool,
 
-    #[cfg(feature = "__test_strict")]
-    variant: crate::StrictValue,
+    #[serde(skip_serializing)]
+    variant: Option<String>,
 }
 
 impl StreamVersion {
+    /// If this version is audio described, i.e. contains a narration of the visual content for
+    /// blind and visually impaired viewers.
+    pub fn is_audio_description(&self) -> bool {
+        crate::media::util::is_audio_description_variant(&self.variant)
+    }
+
     /// Requests an actual [`Stream`] from this version.
     /// This method might throw a too many active streams error. In this case, make sure to
     /// have less/no active other [`Stream`]s open (through this crate or as stream in the browser
@@ -85,7 +96,7 @@ pub struct StreamSession {
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, smart_default::SmartDefault, Request)]
-#[request(executor(versions))]
+#[request(executor(subtitles, captions, versions))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
 #[cfg_attr(not(feature = "__test_strict"), serde(default))]
@@ -118,15 +129,27 @@ pub struct Stream {
     #[serde(skip)]
     optional_media_type: Option<String>,
 
-    #[cfg(feature = "__test_strict")]
-    asset_id: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    playback_type: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    bifs: crate::StrictValue,
+    #[serde(skip_serializing)]
+    #[serde(rename = "assetId")]
+    _asset_id: crate::StrictValue,
+    #[serde(skip_serializing)]
+    #[serde(rename = "playbackType")]
+    _playback_type: Option<crate::StrictValue>,
+    #[serde(skip_serializing)]
+    #[serde(rename = "bifs")]
+    _bifs: crate::StrictValue,
 }
 
 impl Stream {
+    /// Returns all audio described versions of this stream, see
+    /// [`StreamVersion::is_audio_description`].
+    pub fn audio_description_versions(&self) -> Vec<&StreamVersion> {
+        self.versions
+            .iter()
+            .filter(|v| v.is_audio_description())
+            .collect()
+    }
+
     /// Requests a stream from an id via the chrome endpoint.
     pub async fn from_id_web_chrome(
         crunchyroll: &Crunchyroll,
@@ -136,6 +159,16 @@ impl Stream {
         Self::from_id(crunchyroll, id, "web", "chrome", optional_media_type).await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "stream",
+            level = "debug",
+            skip_all,
+            fields(id = id.as_ref(), device = device, platform = platform),
+            err(level = "debug")
+        )
+    )]
     async fn from_id(
         crunchyroll: &Crunchyroll,
         id: impl AsRef<str>,
@@ -155,6 +188,7 @@ impl Stream {
         let mut stream = crunchyroll
             .executor
             .get(endpoint)
+            .priority(RequestPriority::Playback)
             .request::<Stream>()
             .await?;
         stream.__set_executor(crunchyroll.executor.clone()).await;
@@ -169,6 +203,14 @@ impl Stream {
                 .clone_from(&stream.optional_media_type)
         }
 
+        crunchyroll
+            .executor
+            .events
+            .emit(ClientEvent::StreamSessionCreated {
+                id: stream.id.clone(),
+                expires_at: Utc::now()
+                    + chrono::Duration::seconds(stream.session.session_expiration_seconds as i64),
+            });
         Ok(stream)
     }
 
@@ -179,6 +221,15 @@ impl Stream {
     /// data. Crunchyroll only allows a certain amount of stream data to be requested at the same
     /// time, typically the exact amount depends on the type of (premium) subscription you have. You
     /// can use [`Stream::invalidate`] to invalidate all stream data for this stream.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(id = %self.id, hardsub = ?hardsub),
+            err(level = "debug")
+        )
+    )]
     pub async fn stream_data(
         &self,
         hardsub: Option<Locale>,
@@ -204,19 +255,60 @@ impl Stream {
 
     /// Invalidates all the stream data which may be obtained from [`Stream::stream_data`]. You will
     /// run into errors if you request multiple [`Stream::stream_data`]s without invalidating them.
+    /// This should also be called if a download got cancelled (see
+    /// [`StreamSegment::data_with_cancellation`]).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(id = %self.id), err(level = "debug"))
+    )]
     pub async fn invalidate(self) -> Result<()> {
-        if !self.session.uses_stream_limits {
-            return Ok(());
+        if self.session.uses_stream_limits {
+            let endpoint = format!(
+                "https://cr-play-service.prd.crunchyrollsvc.com/v1/token/{}/{}",
+                self.id, self.token
+            );
+
+            self.executor.delete(endpoint).request_raw(true).await?;
         }
 
-        let endpoint = format!(
-            "https://cr-play-service.prd.crunchyrollsvc.com/v1/token/{}/{}",
-            self.id, self.token
-        );
+        self.executor
+            .events
+            .emit(ClientEvent::StreamSessionExpired { id: self.id });
+        Ok(())
+    }
 
-        self.executor.delete(endpoint).request_raw(true).await?;
+    /// Start reporting the playback progress of this stream in the background, see
+    /// [`crate::media::PlaybackHeartbeat`]. Update the position via
+    /// [`crate::media::PlaybackHeartbeat::set_position`] while playing, the reporting stops when
+    /// the returned heartbeat is dropped. Must be called inside a tokio runtime.
+    #[cfg(all(feature = "heartbeat", not(target_arch = "wasm32")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "heartbeat")))]
+    pub fn heartbeat(&self) -> crate::media::PlaybackHeartbeat {
+        crate::media::PlaybackHeartbeat::start(
+            self.executor.clone(),
+            self.id.clone(),
+            self.token.clone(),
+            self.session.renew_seconds,
+            self.session.uses_stream_limits,
+        )
+    }
 
-        Ok(())
+    /// Url of the stream manifest which can be requested without further authentication, e.g.
+    /// by external players. Returns [`None`] if the given hardsub isn't available.
+    #[cfg(feature = "m3u")]
+    pub(crate) fn playback_url(&self, hardsub: Option<&Locale>) -> Option<String> {
+        let url = match hardsub {
+            Some(hardsub) => self.hard_subs.get(hardsub)?,
+            None => &self.url,
+        };
+        let mut url = reqwest::Url::parse(url).ok()?;
+        url.query_pairs_mut()
+            .append_pair(
+                "accountid",
+                &self.executor.details.account_id.clone().unwrap_or_default(),
+            )
+            .append_pair("playbackGuid", &self.token);
+        Some(url.to_string())
     }
 
     /// Show in which audios this [`Stream`] is also available.
@@ -277,7 +369,7 @@ pub struct Subtitle {
 
 impl Subtitle {
     /// Get the subtitle as bytes.
-    pub async fn data(&self) -> Result<Vec<u8>> {
+    pub async fn data(&self) -> Result<Bytes> {
         self.executor.get(&self.url).request_raw(false).await
     }
 }
@@ -337,6 +429,7 @@ impl StreamData {
             message: msg.to_string(),
             status: None,
             url: url.as_ref().to_string(),
+            details: Default::default(),
         };
 
         let raw_mpd = executor
@@ -353,16 +446,17 @@ impl StreamData {
                 ),
                 ("playbackGuid", token.as_ref()),
             ])
+            .priority(RequestPriority::Playback)
             .request_raw(true)
             .await?;
         // if the response is json and not xml it should always be an error
         if let Ok(json) = serde_json::from_slice(&raw_mpd) {
-            is_request_error(json, url.as_ref(), &StatusCode::FORBIDDEN)?;
+            is_request_error(json, url.as_ref(), &StatusCode::FORBIDDEN, None)?;
         }
         let mut mpd: MPD =
             dash_mpd::parse(&String::from_utf8_lossy(&raw_mpd)).map_err(|e| Error::Decode {
                 message: e.to_string(),
-                content: raw_mpd,
+                content: raw_mpd.to_vec(),
                 url: url.as_ref().to_string(),
             })?;
         let period = mpd.periods.remove(0);
@@ -385,8 +479,7 @@ impl StreamData {
                 .segments
                 .iter()
                 .flat_map(|s| {
-                    iter::repeat(s.d as u32)
-                        .take(s.r.unwrap_or_default() as usize + 1)
+                    iter::repeat_n(s.d as u32, s.r.unwrap_or_default() as usize + 1)
                         .collect::<Vec<u32>>()
                 })
                 .collect::<Vec<u32>>();
@@ -604,7 +697,27 @@ pub struct StreamSegment {
 
 impl StreamSegment {
     /// Get the raw data for the current segment.
-    pub async fn data(&self) -> Result<Vec<u8>> {
+    pub async fn data(&self) -> Result<Bytes> {
         self.executor.get(&self.url).request_raw(false).await
     }
+
+    /// Like [`StreamSegment::data`] but with a custom timeout, which overrides the timeout set via
+    /// [`crate::crunchyroll::CrunchyrollBuilder::download_timeout`].
+    pub async fn data_with_timeout(&self, timeout: Duration) -> Result<Bytes> {
+        self.executor
+            .get(&self.url)
+            .timeout(timeout)
+            .request_raw(false)
+            .await
+    }
+
+    /// Like [`StreamSegment::data`] but aborts the request and returns [`Error::Cancelled`] as
+    /// soon as `token` gets cancelled. Use the same token for all segments of a download to stop
+    /// it promptly, and call [`Stream::invalidate`] afterwards to clean up the stream session.
+    pub async fn data_with_cancellation(&self, token: &CancellationToken) -> Result<Bytes> {
+        token
+            .run_until_cancelled(self.data())
+            .await
+            .unwrap_or(Err(Error::Cancelled))
+    }
 }
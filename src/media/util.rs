@@ -16,3 +16,62 @@ pub(crate) async fn request_media<T: Default + DeserializeOwned + Request>(
         .await?;
     Ok(result.data)
 }
+
+/// Requests a single media object by its id from the cms endpoint of `media_type` (e.g. `series`
+/// or `episodes`). If an object cache is set, the object is taken from / stored in it.
+pub(crate) async fn request_media_object<T: Default + DeserializeOwned + Request + RawMedia>(
+    executor: Arc<Executor>,
+    media_type: &str,
+    id: &str,
+) -> Result<T> {
+    #[cfg(feature = "object-cache")]
+    if let Some(object_cache) = &executor.object_cache {
+        let locale = &executor.details.locale;
+        if let Some(value) = object_cache.get(media_type, id, locale) {
+            if let Ok(mut media) = serde_json::from_value::<T>(value) {
+                media.__set_executor(executor.clone()).await;
+                return Ok(media);
+            }
+        }
+        let media: T = request_media(
+            executor.clone(),
+            format!("https://www.crunchyroll.com/content/v2/cms/{media_type}/{id}"),
+        )
+        .await?
+        .remove(0);
+        // the media was received successfully, so a failing cache must not fail the request
+        let _result = object_cache.set(media_type, id, locale, media.raw_json().clone());
+        #[cfg(feature = "tracing")]
+        if let Err(err) = _result {
+            tracing::warn!("failed to store {media_type} '{id}' in object cache: {err}")
+        }
+        return Ok(media);
+    }
+
+    Ok(request_media(
+        executor,
+        format!("https://www.crunchyroll.com/content/v2/cms/{media_type}/{id}"),
+    )
+    .await?
+    .remove(0))
+}
+
+/// Media which keeps the raw json it was deserialized from, required to store it in the object
+/// cache.
+pub(crate) trait RawMedia {
+    #[cfg(feature = "object-cache")]
+    fn raw_json(&self) -> &serde_json::Value;
+}
+
+/// Crunchyroll marks audio described versions via the `variant` field of a version, e.g.
+/// `audio_description`, `audio-description` or `described`.
+pub(crate) fn is_audio_description_variant(variant: &Option<String>) -> bool {
+    let Some(variant) = variant else {
+        return false;
+    };
+    let variant = variant.to_lowercase().replace(['-', ' '], "_");
+    variant == "ad"
+        || variant == "described"
+        || variant.contains("audio_description")
+        || variant.contains("descriptive")
+}
@@ -0,0 +1,196 @@
+//! Collect request metrics like request counts, error rates, downloaded bytes and latencies. Use
+//! [`crate::Crunchyroll::metrics`] to get a snapshot of them.
+
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Upper bounds (in milliseconds) of the buckets of [`LatencyHistogram`].
+pub const LATENCY_BUCKETS_MS: [u64; 10] = [10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+/// Histogram of request latencies.
+#[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LatencyHistogram {
+    /// `buckets[i]` is the number of requests which took at most [`LATENCY_BUCKETS_MS`]`[i]`
+    /// milliseconds (and longer than the previous bound). The last entry contains all requests
+    /// which took longer than the largest bound.
+    pub buckets: [u64; LATENCY_BUCKETS_MS.len() + 1],
+    /// Sum of all latencies.
+    pub sum: Duration,
+    /// Number of recorded latencies.
+    pub count: u64,
+}
+
+impl LatencyHistogram {
+    /// Average latency. [`None`] if no latency was recorded.
+    pub fn mean(&self) -> Option<Duration> {
+        (self.count > 0)
+            .then(|| Duration::from_secs_f64(self.sum.as_secs_f64() / self.count as f64))
+    }
+
+    /// Estimated latency of the given quantile (`0.0` - `1.0`, e.g. `0.95` for the 95th
+    /// percentile). This is the upper bound of the bucket which contains the quantile. Returns
+    /// [`None`] if no latency was recorded or the quantile lies in the last, unbounded bucket.
+    pub fn quantile(&self, quantile: f64) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        let rank = (quantile.clamp(0.0, 1.0) * self.count as f64)
+            .ceil()
+            .max(1.0) as u64;
+        let mut seen = 0;
+        for (i, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return LATENCY_BUCKETS_MS
+                    .get(i)
+                    .map(|ms| Duration::from_millis(*ms));
+            }
+        }
+        None
+    }
+
+    fn record(&mut self, latency: Duration) {
+        let ms = latency.as_millis() as u64;
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.buckets[bucket] += 1;
+        self.sum += latency;
+        self.count += 1;
+    }
+}
+
+/// Metrics of a single endpoint.
+#[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EndpointMetrics {
+    /// Number of sent requests.
+    pub requests: u64,
+    /// Number of requests which failed or returned a non-success status code.
+    pub errors: u64,
+    /// Number of downloaded (body) bytes, if the response size is known.
+    pub bytes: u64,
+    pub latency: LatencyHistogram,
+}
+
+/// Snapshot of all metrics collected by a [`crate::Crunchyroll`] instance.
+#[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    /// Number of sent requests (api requests and downloads). Requests which are answered by the
+    /// response cache or a recording aren't counted.
+    pub requests: u64,
+    /// Number of requests which failed or returned a non-success status code.
+    pub errors: u64,
+    /// Number of downloaded (body) bytes, if the response size is known.
+    pub bytes_downloaded: u64,
+    /// Number of access token refreshes.
+    pub token_refreshes: u64,
+    /// Metrics per endpoint. The key is the method and the url without query, ids and other
+    /// dynamic path segments (segments which contain digits) are replaced with `{id}`, e.g.
+    /// `GET www.crunchyroll.com/content/v2/cms/series/{id}`.
+    pub endpoints: BTreeMap<String, EndpointMetrics>,
+}
+
+impl MetricsSnapshot {
+    /// Ratio of failed requests to all requests (`0.0` - `1.0`).
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            0.0
+        } else {
+            self.errors as f64 / self.requests as f64
+        }
+    }
+}
+
+/// Collects the metrics of an executor.
+#[derive(Debug, Default)]
+pub(crate) struct Metrics(Mutex<MetricsSnapshot>);
+
+impl Metrics {
+    pub(crate) fn snapshot(&self) -> MetricsSnapshot {
+        self.0.lock().unwrap().clone()
+    }
+
+    pub(crate) fn reset(&self) {
+        *self.0.lock().unwrap() = MetricsSnapshot::default()
+    }
+
+    /// Record a finished request. `bytes` is the body size, if known.
+    pub(crate) fn record_request(
+        &self,
+        method: &reqwest::Method,
+        url: &str,
+        start: DateTime<Utc>,
+        success: bool,
+        bytes: Option<u64>,
+    ) {
+        let latency = (Utc::now() - start).to_std().unwrap_or_default();
+        let mut metrics = self.0.lock().unwrap();
+        metrics.requests += 1;
+        metrics.bytes_downloaded += bytes.unwrap_or_default();
+        if !success {
+            metrics.errors += 1
+        }
+
+        let endpoint = metrics
+            .endpoints
+            .entry(format!("{method} {}", normalize_endpoint(url)))
+            .or_default();
+        endpoint.requests += 1;
+        endpoint.bytes += bytes.unwrap_or_default();
+        if !success {
+            endpoint.errors += 1
+        }
+        endpoint.latency.record(latency)
+    }
+
+    pub(crate) fn record_token_refresh(&self) {
+        self.0.lock().unwrap().token_refreshes += 1
+    }
+}
+
+/// Record a request which resulted in `resp` (or failed if [`None`]).
+pub(crate) fn record_response(
+    metrics: &Metrics,
+    method: &reqwest::Method,
+    url: &str,
+    start: DateTime<Utc>,
+    resp: Option<&reqwest::Response>,
+) {
+    let success = resp.is_some_and(|resp| {
+        resp.status().is_success() || resp.status() == reqwest::StatusCode::NOT_MODIFIED
+    });
+    metrics.record_request(
+        method,
+        url,
+        start,
+        success,
+        resp.and_then(|resp| resp.content_length()),
+    )
+}
+
+/// Strip the scheme and query of `url` and replace dynamic path segments with `{id}`.
+fn normalize_endpoint(url: &str) -> String {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    url.split('/')
+        .enumerate()
+        .map(|(i, segment)| {
+            let is_version = segment.len() > 1
+                && segment.starts_with('v')
+                && segment[1..].chars().all(|c| c.is_ascii_digit());
+            // the first segment is the host
+            if i > 0 && !is_version && segment.chars().any(|c| c.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join("/")
+}
@@ -0,0 +1,211 @@
+//! Articles of [Crunchyroll News](https://www.crunchyroll.com/news).
+
+use crate::common::{Pagination, PaginationData, PaginationNextType};
+use crate::crunchyroll::Executor;
+use crate::{options, Crunchyroll, Request, Result};
+use chrono::{DateTime, Utc};
+use futures_util::FutureExt;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+options! {
+    /// Options which news articles to list.
+    NewsOptions;
+    /// Only list articles of the given category, e.g. `announcements` or `latest`.
+    category(String, "category") = None
+}
+
+/// A Crunchyroll News article. Use [`NewsArticle::content`] to get the article text.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+pub struct NewsArticle {
+    #[serde(skip)]
+    executor: Arc<Executor>,
+
+    /// Path of the article, e.g. `news/announcements/2024/1/1/...`. The article is available at
+    /// `https://www.crunchyroll.com/<slug>`.
+    pub slug: String,
+    pub title: String,
+    /// Short summary of the article.
+    pub summary: String,
+    /// Url of the image which is shown on top of the article.
+    pub hero_image: Option<String>,
+    /// [`None`] if the article has no publish date (yet).
+    pub publish_date: Option<DateTime<Utc>>,
+    pub categories: Vec<String>,
+}
+
+impl NewsArticle {
+    /// Url of the article on the Crunchyroll website.
+    pub fn url(&self) -> String {
+        format!("https://www.crunchyroll.com/{}", self.slug)
+    }
+
+    /// Request the content of the article.
+    pub async fn content(&self) -> Result<NewsArticleContent> {
+        news_article(&self.executor, &self.slug).await
+    }
+}
+
+/// Content of a [`NewsArticle`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+pub struct NewsArticleContent {
+    pub article: NewsArticle,
+    /// The article body as rich text document (nodes with a `type`, `content` and for text nodes
+    /// `text`). Use [`NewsArticleContent::text`] to get the plain text.
+    pub body: serde_json::Value,
+}
+
+impl NewsArticleContent {
+    /// The article body as plain text, one paragraph per line.
+    pub fn text(&self) -> String {
+        fn collect(node: &serde_json::Value, text: &mut String) {
+            if let Some(t) = node.get("text").and_then(|t| t.as_str()) {
+                text.push_str(t)
+            }
+            for child in node
+                .get("content")
+                .and_then(|c| c.as_array())
+                .into_iter()
+                .flatten()
+            {
+                collect(child, text)
+            }
+            if matches!(
+                node.get("type").and_then(|t| t.as_str()),
+                Some("paragraph" | "heading" | "hard_break")
+            ) && !text.ends_with('\n')
+            {
+                text.push('\n')
+            }
+        }
+
+        let mut text = String::new();
+        collect(&self.body, &mut text);
+        text.trim_end().to_string()
+    }
+}
+
+/// A story as returned by the news api.
+#[derive(Clone, Debug, Default, Deserialize, Request)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+struct NewsStory {
+    full_slug: String,
+    name: String,
+    first_published_at: Option<DateTime<Utc>>,
+    published_at: Option<DateTime<Utc>>,
+    tag_list: Vec<String>,
+    content: NewsStoryContent,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+struct NewsStoryContent {
+    headline: String,
+    lead: String,
+    thumbnail: Option<NewsStoryImage>,
+    article: serde_json::Value,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+struct NewsStoryImage {
+    filename: String,
+}
+
+impl NewsStory {
+    fn into_article(self, executor: Arc<Executor>) -> (NewsArticle, serde_json::Value) {
+        let article = NewsArticle {
+            executor,
+            slug: self.full_slug,
+            title: if self.content.headline.is_empty() {
+                self.name
+            } else {
+                self.content.headline
+            },
+            summary: self.content.lead,
+            hero_image: self
+                .content
+                .thumbnail
+                .map(|thumbnail| thumbnail.filename)
+                .filter(|filename| !filename.is_empty()),
+            publish_date: self.first_published_at.or(self.published_at),
+            categories: self.tag_list,
+        };
+        (article, self.content.article)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Request)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+struct NewsStories {
+    stories: Vec<NewsStory>,
+    total: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Request)]
+#[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "__test_strict"), serde(default))]
+struct NewsStoryResult {
+    story: NewsStory,
+}
+
+fn news_endpoint(executor: &Executor, path: &str) -> String {
+    format!(
+        "https://cr-news-api-service.prd.crunchyrollsvc.com/v1/{}/{path}",
+        executor.details.locale
+    )
+}
+
+async fn news_article(executor: &Arc<Executor>, slug: &str) -> Result<NewsArticleContent> {
+    let result: NewsStoryResult = executor
+        .get(news_endpoint(executor, "stories"))
+        .query(&[("slug", slug)])
+        .request()
+        .await?;
+    let (article, body) = result.story.into_article(executor.clone());
+    Ok(NewsArticleContent { article, body })
+}
+
+impl Crunchyroll {
+    /// Returns Crunchyroll News articles, latest first.
+    pub fn news_articles(&self, options: NewsOptions) -> Pagination<NewsArticle> {
+        Pagination::new(
+            |options| {
+                async move {
+                    let result: NewsStories = options
+                        .executor
+                        .get(news_endpoint(&options.executor, "stories/search"))
+                        .query(&[("page", options.page), ("page_size", options.page_size)])
+                        .query(&options.query)
+                        .request()
+                        .await?;
+                    Ok(PaginationData {
+                        data: result
+                            .stories
+                            .into_iter()
+                            .map(|story| story.into_article(options.executor.clone()).0)
+                            .collect(),
+                        next_type: PaginationNextType::Total(result.total),
+                    })
+                }
+                .boxed()
+            },
+            self.executor.clone(),
+            Some(options.into_query()),
+            None,
+        )
+    }
+
+    /// Returns the content of the news article with the given slug (see [`NewsArticle::slug`]).
+    pub async fn news_article<S: AsRef<str>>(&self, slug: S) -> Result<NewsArticleContent> {
+        news_article(&self.executor, slug.as_ref()).await
+    }
+}
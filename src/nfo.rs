@@ -0,0 +1,172 @@
+//! Export media metadata as `.nfo` files for media centers like Kodi or Jellyfin.
+
+use crate::internal::xml::escape;
+use crate::media::{ImageVariants, Rating};
+use crate::{Episode, Movie, Season, Series};
+use chrono::{DateTime, Utc};
+use std::fmt::Write;
+
+/// Media which can be exported as `.nfo` xml. Media centers expect the file next to the media
+/// files, for [`Series`] as `tvshow.nfo` in the series directory, for [`Season`] as `season.nfo`
+/// in the season directory and for [`Episode`] / [`Movie`] with the same name as the video file
+/// (e.g. `S01E01.mkv` -> `S01E01.nfo`).
+///
+/// ```no_run
+/// use crunchyroll_rs::nfo::Nfo;
+///
+/// # async fn f(series: crunchyroll_rs::Series) -> Result<(), Box<dyn std::error::Error>> {
+/// let rating = series.rating().await?;
+/// std::fs::write("tvshow.nfo", series.to_nfo_with_rating(&rating))?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "nfo")))]
+pub trait Nfo {
+    /// The root element of the nfo, e.g. `tvshow` or `episodedetails`.
+    const ROOT: &'static str;
+
+    /// Write all metadata elements to `xml`.
+    #[doc(hidden)]
+    fn write_nfo_elements(&self, xml: &mut String);
+
+    /// Render the metadata as nfo xml.
+    fn to_nfo(&self) -> String {
+        render(Self::ROOT, |xml| self.write_nfo_elements(xml))
+    }
+
+    /// Render the metadata as nfo xml including the user rating of Crunchyroll, which can be
+    /// requested via e.g. [`Series::rating`].
+    fn to_nfo_with_rating(&self, rating: &Rating) -> String {
+        render(Self::ROOT, |xml| {
+            self.write_nfo_elements(xml);
+            let _ = write!(
+                xml,
+                r#"<ratings><rating name="crunchyroll" max="5" default="true"><value>{}</value><votes>{}</votes></rating></ratings>"#,
+                rating.average, rating.total
+            );
+        })
+    }
+}
+
+impl Nfo for Series {
+    const ROOT: &'static str = "tvshow";
+
+    fn write_nfo_elements(&self, xml: &mut String) {
+        element(xml, "title", &self.title);
+        element(xml, "plot", &self.description);
+        if let Some(year) = self.series_launch_year {
+            element(xml, "year", &year.to_string())
+        }
+        maturity_rating(xml, &self.maturity_ratings);
+        for category in &self.categories {
+            element(xml, "genre", &category.to_string())
+        }
+        for keyword in &self.keywords {
+            element(xml, "tag", keyword)
+        }
+        if let Some(content_provider) = &self.content_provider {
+            element(xml, "studio", content_provider)
+        }
+        unique_id(xml, &self.id);
+        if let Some(poster) = self.images.poster_tall.largest() {
+            thumb(xml, "poster", &poster.source)
+        }
+        if let Some(fanart) = self.images.poster_wide.largest() {
+            let _ = write!(
+                xml,
+                "<fanart><thumb>{}</thumb></fanart>",
+                escape(&fanart.source)
+            );
+        }
+    }
+}
+
+impl Nfo for Season {
+    const ROOT: &'static str = "season";
+
+    fn write_nfo_elements(&self, xml: &mut String) {
+        element(xml, "title", &self.title);
+        element(xml, "plot", &self.description);
+        element(xml, "seasonnumber", &self.season_number.to_string());
+        maturity_rating(xml, &self.maturity_ratings);
+        unique_id(xml, &self.id);
+    }
+}
+
+impl Nfo for Episode {
+    const ROOT: &'static str = "episodedetails";
+
+    fn write_nfo_elements(&self, xml: &mut String) {
+        element(xml, "title", &self.title);
+        element(xml, "showtitle", &self.series_title);
+        element(xml, "season", &self.season_number.to_string());
+        if let Some(episode_number) = self.episode_number {
+            element(xml, "episode", &episode_number.to_string())
+        }
+        element(xml, "plot", &self.description);
+        date(xml, "aired", &self.episode_air_date);
+        date(xml, "premiered", &self.episode_air_date);
+        element(xml, "runtime", &self.duration.num_minutes().to_string());
+        maturity_rating(xml, &self.maturity_ratings);
+        unique_id(xml, &self.id);
+        if let Some(image) = self.images.largest() {
+            thumb(xml, "thumb", &image.source)
+        }
+    }
+}
+
+impl Nfo for Movie {
+    const ROOT: &'static str = "movie";
+
+    fn write_nfo_elements(&self, xml: &mut String) {
+        element(xml, "title", &self.title);
+        if self.movie_listing_title != self.title {
+            element(xml, "set", &self.movie_listing_title)
+        }
+        element(xml, "plot", &self.description);
+        date(xml, "premiered", &self.premium_available_date);
+        element(xml, "runtime", &self.duration.num_minutes().to_string());
+        unique_id(xml, &self.id);
+        if let Some(image) = self.images.thumbnail.largest() {
+            thumb(xml, "thumb", &image.source)
+        }
+    }
+}
+
+fn render(root: &str, write_elements: impl FnOnce(&mut String)) -> String {
+    let mut xml = format!(r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><{root}>"#);
+    write_elements(&mut xml);
+    let _ = write!(xml, "</{root}>");
+    xml
+}
+
+fn element(xml: &mut String, name: &str, value: &str) {
+    if !value.is_empty() {
+        let _ = write!(xml, "<{name}>{}</{name}>", escape(value));
+    }
+}
+
+fn date(xml: &mut String, name: &str, date: &DateTime<Utc>) {
+    // crunchyroll uses the unix epoch if no date is set
+    if date.timestamp() != 0 {
+        element(xml, name, &date.format("%Y-%m-%d").to_string())
+    }
+}
+
+fn maturity_rating(xml: &mut String, maturity_ratings: &[String]) {
+    if let Some(maturity_rating) = maturity_ratings.first() {
+        element(xml, "mpaa", maturity_rating)
+    }
+}
+
+fn unique_id(xml: &mut String, id: &str) {
+    let _ = write!(
+        xml,
+        r#"<uniqueid type="crunchyroll" default="true">{}</uniqueid>"#,
+        escape(id)
+    );
+}
+
+fn thumb(xml: &mut String, aspect: &str, url: &str) {
+    let _ = write!(xml, r#"<thumb aspect="{aspect}">{}</thumb>"#, escape(url));
+}
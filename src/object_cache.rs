@@ -0,0 +1,136 @@
+//! Persistent on-disk cache of media objects.
+//!
+//! Unlike [`crate::cache::ResponseCache`], which caches raw api responses by url and revalidates
+//! them with Crunchyroll, the object cache stores series, seasons, episodes, movie listings and
+//! movies by their id and uses them without asking Crunchyroll again until their ttl is expired or
+//! they were invalidated explicitly. This makes repeated runs of catalog tools (and offline capable
+//! apps) much faster. Enable it with [`crate::crunchyroll::CrunchyrollBuilder::object_cache`].
+
+use crate::{Locale, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Caches media objects on disk. Objects are stored per id, type and locale, so a directory can be
+/// shared between clients with different locales but shouldn't be shared between multiple
+/// accounts.
+///
+/// ```no_run
+/// use chrono::Duration;
+/// use crunchyroll_rs::object_cache::ObjectCache;
+/// use crunchyroll_rs::{Crunchyroll, Series};
+///
+/// # async fn f() -> Result<(), crunchyroll_rs::error::Error> {
+/// let cache = ObjectCache::new("cache").ttl(Duration::days(7));
+/// let crunchy = Crunchyroll::builder()
+///     .object_cache(cache.clone())
+///     .login_anonymously()
+///     .await?;
+/// // only the first run requests the series from Crunchyroll
+/// let series: Series = crunchy.media_from_id("GY8VEQ95Y").await?;
+/// // request it again on the next run
+/// cache.invalidate("GY8VEQ95Y")?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "object-cache")))]
+#[derive(Clone, Debug)]
+pub struct ObjectCache {
+    directory: PathBuf,
+    ttl: Duration,
+}
+
+#[derive(Deserialize, Serialize)]
+struct ObjectCacheEntry {
+    cached_at: DateTime<Utc>,
+    value: serde_json::Value,
+}
+
+impl ObjectCache {
+    /// Create a new cache which stores objects in `directory`. The directory is created if it
+    /// doesn't exist. Objects are used for one day by default, see [`ObjectCache::ttl`].
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            ttl: Duration::days(1),
+        }
+    }
+
+    /// How long a cached object is used before it's requested from Crunchyroll again.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Remove all cached objects (in all locales) with the given id.
+    pub fn invalidate(&self, id: impl AsRef<str>) -> Result<()> {
+        match std::fs::remove_dir_all(self.directory.join(sanitize(id.as_ref()))) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(io_error(err)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remove all cached objects.
+    pub fn clear(&self) -> Result<()> {
+        let Ok(dir) = std::fs::read_dir(&self.directory) else {
+            return Ok(());
+        };
+        for entry in dir {
+            let path = entry.map_err(io_error)?.path();
+            if path.is_dir() {
+                std::fs::remove_dir_all(path).map_err(io_error)?
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the raw json of the object, if it's cached and not expired.
+    pub(crate) fn get(
+        &self,
+        media_type: &str,
+        id: &str,
+        locale: &Locale,
+    ) -> Option<serde_json::Value> {
+        let raw = std::fs::read(self.path(media_type, id, locale)).ok()?;
+        let entry: ObjectCacheEntry = serde_json::from_slice(&raw).ok()?;
+        (entry.cached_at + self.ttl > Utc::now()).then_some(entry.value)
+    }
+
+    /// Store the raw json of an object.
+    pub(crate) fn set(
+        &self,
+        media_type: &str,
+        id: &str,
+        locale: &Locale,
+        value: serde_json::Value,
+    ) -> Result<()> {
+        let path = self.path(media_type, id, locale);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let entry = ObjectCacheEntry {
+            cached_at: Utc::now(),
+            value,
+        };
+        std::fs::write(path, serde_json::to_vec(&entry)?).map_err(io_error)
+    }
+
+    fn path(&self, media_type: &str, id: &str, locale: &Locale) -> PathBuf {
+        self.directory
+            .join(sanitize(id))
+            .join(format!("{media_type}-{locale}.json"))
+    }
+}
+
+/// Ids are alphanumeric, but make sure that they can't escape the cache directory.
+fn sanitize(id: &str) -> String {
+    id.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn io_error(err: std::io::Error) -> crate::error::Error {
+    crate::error::Error::Internal {
+        message: format!("failed to access object cache: {err}"),
+    }
+}
@@ -0,0 +1,192 @@
+//! Export seasons and crunchylists as M3U playlists.
+
+use crate::common::TryStreamExt;
+use crate::list::Crunchylist;
+use crate::media::Stream;
+use crate::{Episode, Locale, MediaCollection, Movie, Result, Season};
+use std::fmt::{Display, Formatter};
+
+/// Where the entries of a [`M3uPlaylist`] should point to.
+#[cfg_attr(docsrs, doc(cfg(feature = "m3u")))]
+#[allow(clippy::type_complexity)]
+pub enum M3uLocation {
+    /// The resolved stream url of the episode / movie. Every entry requires a separate stream
+    /// session which is invalidated right after its url was read, so that exporting many entries
+    /// doesn't run into the limit of concurrently active sessions. Streams with limits (see
+    /// [`crate::media::StreamSession::uses_stream_limits`]) may therefore not be playable from
+    /// the playlist, but they are DRM protected and can't be played by most players anyway. Use
+    /// [`M3uLocation::File`] if no stream sessions should be created at all.
+    Stream {
+        /// Use the stream with this hardsub instead of the raw stream, if available.
+        hardsub: Option<Locale>,
+    },
+    /// A local file path (or any other url) returned by the callback. Media for which the callback
+    /// returns [`None`] is skipped.
+    File(Box<dyn FnMut(&MediaCollection) -> Option<String> + Send>),
+}
+
+/// A single entry of a [`M3uPlaylist`].
+#[cfg_attr(docsrs, doc(cfg(feature = "m3u")))]
+#[derive(Clone, Debug, Default)]
+pub struct M3uEntry {
+    pub title: String,
+    /// Duration in seconds.
+    pub duration: Option<i64>,
+    /// Path or url of the entry.
+    pub location: String,
+    /// Player options, written as `#EXTVLCOPT` lines (supported by VLC and mpv), e.g.
+    /// `http-user-agent=...`.
+    pub options: Vec<String>,
+}
+
+/// A M3U playlist, e.g. for VLC or mpv. Use [`ToString::to_string`] to render it.
+///
+/// ```no_run
+/// use crunchyroll_rs::playlist::{M3uLocation, M3uPlaylist};
+/// use crunchyroll_rs::MediaCollection;
+///
+/// # async fn f(season: crunchyroll_rs::Season) -> Result<(), Box<dyn std::error::Error>> {
+/// let playlist = M3uPlaylist::from_season(
+///     &season,
+///     M3uLocation::File(Box::new(|media| match media {
+///         MediaCollection::Episode(episode) => Some(format!("{}.mkv", episode.sequence_number)),
+///         _ => None,
+///     })),
+/// )
+/// .await?;
+/// std::fs::write("season.m3u8", playlist.to_string())?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "m3u")))]
+#[derive(Clone, Debug, Default)]
+pub struct M3uPlaylist {
+    pub entries: Vec<M3uEntry>,
+}
+
+impl M3uPlaylist {
+    /// Creates an empty playlist.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a playlist of all episodes of a season.
+    pub async fn from_season(season: &Season, mut location: M3uLocation) -> Result<Self> {
+        let mut playlist = Self::new();
+        for episode in season.episodes().try_collect::<Vec<_>>().await? {
+            playlist.push_episode(&episode, &mut location).await?
+        }
+        Ok(playlist)
+    }
+
+    /// Creates a playlist of all items of a crunchylist. Series are added with all episodes of
+    /// all seasons, movie listings with all movies. Every season / movie listing requires a
+    /// separate request.
+    pub async fn from_crunchylist(
+        crunchylist: &Crunchylist,
+        mut location: M3uLocation,
+    ) -> Result<Self> {
+        let mut playlist = Self::new();
+        for item in &crunchylist.items {
+            match &item.panel {
+                MediaCollection::Series(series) => {
+                    for season in series.seasons().try_collect::<Vec<_>>().await? {
+                        for episode in season.episodes().try_collect::<Vec<_>>().await? {
+                            playlist.push_episode(&episode, &mut location).await?
+                        }
+                    }
+                }
+                MediaCollection::MovieListing(movie_listing) => {
+                    for movie in movie_listing.movies().try_collect::<Vec<_>>().await? {
+                        playlist.push_movie(&movie, &mut location).await?
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(playlist)
+    }
+
+    /// Add an episode to the playlist.
+    pub async fn push_episode(
+        &mut self,
+        episode: &Episode,
+        location: &mut M3uLocation,
+    ) -> Result<()> {
+        let title = episode.display_title();
+        let (location, options) = match location {
+            M3uLocation::Stream { hardsub } => (
+                stream_location(episode.stream().await?, hardsub.as_ref()).await?,
+                stream_options(),
+            ),
+            M3uLocation::File(f) => (f(&MediaCollection::Episode(episode.clone())), vec![]),
+        };
+        self.push(title, episode.duration.num_seconds(), location, options);
+        Ok(())
+    }
+
+    /// Add a movie to the playlist.
+    pub async fn push_movie(&mut self, movie: &Movie, location: &mut M3uLocation) -> Result<()> {
+        let (location, options) = match location {
+            M3uLocation::Stream { hardsub } => (
+                stream_location(movie.stream().await?, hardsub.as_ref()).await?,
+                stream_options(),
+            ),
+            M3uLocation::File(f) => (f(&MediaCollection::Movie(movie.clone())), vec![]),
+        };
+        self.push(
+            movie.title.clone(),
+            movie.duration.num_seconds(),
+            location,
+            options,
+        );
+        Ok(())
+    }
+
+    fn push(
+        &mut self,
+        title: String,
+        duration: i64,
+        location: Option<String>,
+        options: Vec<String>,
+    ) {
+        let Some(location) = location else { return };
+        self.entries.push(M3uEntry {
+            title,
+            duration: Some(duration).filter(|d| *d > 0),
+            location,
+            options,
+        })
+    }
+}
+
+/// Read the playback url of `stream` and invalidate its session afterwards.
+async fn stream_location(stream: Stream, hardsub: Option<&Locale>) -> Result<Option<String>> {
+    let location = stream.playback_url(hardsub);
+    stream.invalidate().await?;
+    Ok(location)
+}
+
+/// Player options which are required to request stream urls.
+fn stream_options() -> Vec<String> {
+    vec![
+        "http-referrer=https://www.crunchyroll.com/".to_string(),
+        format!("http-user-agent={}", crate::crunchyroll::USER_AGENT),
+    ]
+}
+
+impl Display for M3uPlaylist {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "#EXTM3U")?;
+        for entry in &self.entries {
+            // line breaks would break the playlist format
+            let title = entry.title.replace(['\r', '\n'], " ");
+            writeln!(f, "#EXTINF:{},{title}", entry.duration.unwrap_or(-1))?;
+            for option in &entry.options {
+                writeln!(f, "#EXTVLCOPT:{}", option.replace(['\r', '\n'], ""))?;
+            }
+            writeln!(f, "{}", entry.location.replace(['\r', '\n'], ""))?;
+        }
+        Ok(())
+    }
+}
@@ -2,10 +2,11 @@
 
 use crate::crunchyroll::MaturityRating;
 use crate::macros::options;
-use crate::{Crunchyroll, Executor, Locale, Result};
+use crate::{Crunchyroll, EmptyJsonProxy, Executor, Locale, Result};
 use crunchyroll_rs_internal::Request;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::future::Future;
 use std::sync::Arc;
 
 options! {
@@ -46,14 +47,18 @@ pub struct Profile {
     pub preferred_content_audio_language: Option<Locale>,
     pub preferred_content_subtitle_language: Option<Locale>,
 
-    #[cfg(feature = "__test_strict")]
-    account_id: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    extended_maturity_rating: crate::StrictValue,
-    #[cfg(feature = "__test_strict")]
-    do_not_sell: Option<crate::StrictValue>,
-    #[cfg(feature = "__test_strict")]
-    age_consent: Option<crate::StrictValue>,
+    #[serde(skip_serializing)]
+    #[serde(rename = "account_id")]
+    _account_id: Option<crate::StrictValue>,
+    #[serde(skip_serializing)]
+    #[serde(rename = "extended_maturity_rating")]
+    _extended_maturity_rating: crate::StrictValue,
+    #[serde(skip_serializing)]
+    #[serde(rename = "do_not_sell")]
+    _do_not_sell: Option<crate::StrictValue>,
+    #[serde(skip_serializing)]
+    #[serde(rename = "age_consent")]
+    _age_consent: Option<crate::StrictValue>,
 }
 
 impl Profile {
@@ -199,4 +204,60 @@ impl Crunchyroll {
         let endpoint = "https://www.crunchyroll.com/accounts/v1/me/multiprofile";
         self.executor.get(endpoint).request().await
     }
+
+    /// Verifies the parental control PIN of the account, which is required to play mature content
+    /// or switch to a locked profile (see [`crate::error::Error::is_pin_required`]). The unlocked
+    /// state is kept until the session is refreshed (see [`Crunchyroll::pin_unlocked`]).
+    pub async fn verify_pin<S: AsRef<str>>(&self, pin: S) -> Result<()> {
+        let pin = pin.as_ref();
+        self.executor.details.account_id.clone()?;
+        let endpoint = "https://www.crunchyroll.com/accounts/v1/me/parental_controls/pin/verify";
+        self.executor
+            .post(endpoint)
+            .json(&json!({"pin": pin}))
+            .request::<EmptyJsonProxy>()
+            .await?;
+
+        self.executor.config.write().await.pin_unlocked = true;
+        Ok(())
+    }
+
+    /// If the parental control PIN was successfully verified via [`Crunchyroll::verify_pin`] since
+    /// the session was last refreshed.
+    pub async fn pin_unlocked(&self) -> bool {
+        self.executor.config.read().await.pin_unlocked
+    }
+
+    /// Runs `f` and, if it fails because the parental control PIN is required, verifies the PIN
+    /// and runs `f` again. `pin` is only awaited if Crunchyroll requires the PIN, so it can be used
+    /// to prompt the user. If it resolves to [`None`] (e.g. the prompt was cancelled), the
+    /// original error is returned.
+    ///
+    /// ```no_run
+    /// # use crunchyroll_rs::{Crunchyroll, Episode};
+    /// # async fn prompt_pin() -> Option<String> { None }
+    /// # async fn f(crunchyroll: Crunchyroll, episode: Episode) -> Result<(), crunchyroll_rs::error::Error> {
+    /// let stream = crunchyroll
+    ///     .retry_with_pin(async { prompt_pin().await }, || episode.stream())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retry_with_pin<T, P, F, Fut>(&self, pin: P, mut f: F) -> Result<T>
+    where
+        P: Future<Output = Option<String>>,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        match f().await {
+            Err(err) if err.is_pin_required() => {
+                let Some(pin) = pin.await else {
+                    return Err(err);
+                };
+                self.verify_pin(pin).await?;
+                f().await
+            }
+            result => result,
+        }
+    }
 }
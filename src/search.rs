@@ -2,10 +2,18 @@
 
 mod browse {
     use crate::categories::Category;
-    use crate::common::{Pagination, PaginationBulkResultMeta, V2BulkResult};
-    use crate::media::MediaType;
-    use crate::{enum_values, options, Crunchyroll, Locale, MediaCollection, Request, Result};
-    use futures_util::FutureExt;
+    use crate::common::{
+        Pagination, PaginationBulkResultMeta, PaginationData, PaginationNextType, V2BulkResult,
+    };
+    use crate::crunchyroll::MaturityRating;
+    use crate::error::Error;
+    use crate::media::{Concurrency, MediaType};
+    use crate::{
+        enum_values, options, Crunchyroll, Episode, Locale, MediaCollection, Request, Result,
+        Series,
+    };
+    use chrono::{Datelike, NaiveTime, Timelike, Utc, Weekday};
+    use futures_util::{FutureExt, StreamExt, TryStreamExt};
     use serde::{Deserialize, Serialize};
 
     /// Human readable implementation of [`SimulcastSeason`].
@@ -26,6 +34,43 @@ mod browse {
         pub localization: SimulcastSeasonLocalization,
     }
 
+    /// A series which is / was simulcasted in a [`SimulcastSeason`].
+    #[derive(Clone, Debug, Default, Serialize)]
+    pub struct SimulcastCalendarEntry {
+        pub series: Series,
+        /// The latest episode of the series which has already aired. [`None`] if no episode has
+        /// aired yet.
+        pub latest_episode: Option<Episode>,
+    }
+
+    impl SimulcastCalendarEntry {
+        /// The weekday (in UTC) on which new episodes are airing. Derived from the air date of
+        /// [`SimulcastCalendarEntry::latest_episode`].
+        pub fn weekday(&self) -> Option<Weekday> {
+            self.latest_episode
+                .as_ref()
+                .map(|e| e.episode_air_date.weekday())
+        }
+
+        /// The time (in UTC) at which new episodes are airing. Derived from the air date of
+        /// [`SimulcastCalendarEntry::latest_episode`].
+        pub fn air_time(&self) -> Option<NaiveTime> {
+            self.latest_episode.as_ref().map(|e| {
+                NaiveTime::from_hms_opt(e.episode_air_date.hour(), e.episode_air_date.minute(), 0)
+                    .unwrap()
+            })
+        }
+    }
+
+    /// A seasonal tag, identifying a specific anime season (cour), e.g. `fall-2024`.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct SeasonalTag {
+        pub id: String,
+        pub localization: SimulcastSeasonLocalization,
+    }
+
     #[allow(dead_code)]
     #[derive(Clone, Debug, Deserialize, smart_default::SmartDefault, Request)]
     #[request(executor(items))]
@@ -35,7 +80,6 @@ mod browse {
         items: Vec<SimulcastSeason>,
         total: u32,
 
-        #[cfg(feature = "__test_strict")]
         locale: crate::StrictValue,
     }
 
@@ -48,6 +92,25 @@ mod browse {
         }
     }
 
+    enum_values! {
+        /// Whether browsed entries should be free or premium only.
+        pub enum BrowseAvailability {
+            Free = "free"
+            Premium = "premium"
+        }
+    }
+
+    enum_values! {
+        /// Minimum average star rating browsed entries must have.
+        pub enum RatingFilter {
+            OneStar = "1"
+            TwoStars = "2"
+            ThreeStars = "3"
+            FourStars = "4"
+            FiveStars = "5"
+        }
+    }
+
     options! {
         /// Options how to browse.
         BrowseOptions;
@@ -62,14 +125,123 @@ mod browse {
         simulcast_season(String, "seasonal_tag") = None,
         /// Specifies how the entries should be sorted.
         sort(BrowseSortType, "sort_by") = Some(BrowseSortType::NewlyAdded),
-        /// Specifies the media type of the entries.
-        media_type(MediaType, "type") = None
+        /// Specifies the media type of the entries. If not set, series and movie listings are
+        /// returned. Use [`BrowseOptions::media_types`] to specify multiple types.
+        media_type(MediaType, "type") = None,
+        /// Specifies multiple media types of the entries. Mutually exclusive with
+        /// [`BrowseOptions::media_type`].
+        media_types(Vec<MediaType>, "type") = None,
+        /// Specifies whether only free or only premium entries should be returned.
+        availability(BrowseAvailability, "availability") = None,
+        /// Specifies the minimum average star rating the entries must have.
+        min_rating(RatingFilter, "min_rating") = None,
+        /// Specifies whether rating information should be included in the entries.
+        ratings(bool, "ratings") = None,
+        /// Specifies whether only mature (`true`) or only non-mature (`false`) entries should be
+        /// returned. If not set, no filter is sent and only the server-side default filtering of
+        /// Crunchyroll applies. The maturity rating of the current profile isn't read.
+        is_mature(bool, "is_mature") = None,
+        /// Specifies the maximum maturity rating of the entries. If not set, no filter is sent and
+        /// only the server-side default filtering of Crunchyroll applies. Use
+        /// [`crate::profile::Profile::maturity_rating`] to filter by the setting of a profile.
+        maturity_rating(MaturityRating, "maturity_rating") = None
+    }
+
+    impl BrowseOptions {
+        /// Only returns entries which can be watched without premium. Same as
+        /// [`BrowseOptions::availability`] with [`BrowseAvailability::Free`].
+        pub fn free_to_watch(self) -> BrowseOptions {
+            self.availability(BrowseAvailability::Free)
+        }
+
+        /// Specifies the seasonal tag (e.g. `fall-2024`) in which the entries should have been
+        /// aired. Use [`Crunchyroll::seasonal_tags`] to get all valid tags. Same as
+        /// [`BrowseOptions::simulcast_season`].
+        pub fn season_tag<S: AsRef<str>>(self, season_tag: S) -> BrowseOptions {
+            self.simulcast_season(season_tag.as_ref().to_string())
+        }
+
+        /// Check if the options are valid. This is done automatically before browsing, so invalid
+        /// options fail with a descriptive [`Error::Input`] instead of an api error.
+        pub fn validate(&self) -> Result<()> {
+            let invalid = |message: &str| {
+                Err(Error::Input {
+                    message: format!("invalid browse options: {message}"),
+                })
+            };
+
+            if let Some(RatingFilter::Custom(min_rating)) = &self.min_rating {
+                if !min_rating
+                    .parse::<u32>()
+                    .is_ok_and(|min_rating| (1..=5).contains(&min_rating))
+                {
+                    return invalid(&format!(
+                        "min rating must be between 1 and 5, got '{min_rating}'"
+                    ));
+                }
+            }
+            if self.media_type.is_some() && self.media_types.is_some() {
+                return invalid("media type and media types are mutually exclusive");
+            }
+            if self.media_types.as_ref().is_some_and(|m| m.is_empty()) {
+                return invalid("media types must not be empty");
+            }
+            if self.media_types.as_ref().is_some_and(|m| has_duplicates(m)) {
+                return invalid("media types must not contain duplicates");
+            }
+            if self.categories.as_ref().is_some_and(|c| c.is_empty()) {
+                return invalid("categories must not be empty");
+            }
+            if self.categories.as_ref().is_some_and(|c| has_duplicates(c)) {
+                return invalid("categories must not contain duplicates");
+            }
+            if let Err(message) = super::validate_maturity(self.is_mature, &self.maturity_rating) {
+                return invalid(&message);
+            }
+            if self.simulcast_season.as_ref().is_some_and(|s| s.is_empty()) {
+                return invalid("simulcast season must not be empty");
+            }
+            if matches!(&self.sort, Some(BrowseSortType::Custom(s)) if s.is_empty()) {
+                return invalid("sort type must not be empty");
+            }
+            if matches!(&self.media_type, Some(MediaType::Custom(m)) if m.is_empty()) {
+                return invalid("media type must not be empty");
+            }
+            if matches!(&self.availability, Some(BrowseAvailability::Custom(a)) if a.is_empty()) {
+                return invalid("availability must not be empty");
+            }
+            Ok(())
+        }
+    }
+
+    fn has_duplicates<T: ToString>(values: &[T]) -> bool {
+        let mut seen = std::collections::HashSet::new();
+        !values.iter().all(|value| seen.insert(value.to_string()))
+    }
+
+    /// A section of the alphabetical (A-Z) browse index.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct BrowseIndex {
+        /// The starting letter of all titles in this section. `#` is used for titles which don't
+        /// start with a letter.
+        pub prefix: String,
+        /// Position of the first title of this section in the alphabetically sorted browse
+        /// results.
+        pub offset: u32,
+        /// Number of titles in this section.
+        pub total: u32,
     }
 
     impl Crunchyroll {
         /// Browses the crunchyroll catalog filtered by the specified options and returns all found
-        /// series and movies.
+        /// series and movies. If the options are invalid (see [`BrowseOptions::validate`]), the
+        /// first item of the pagination is an error.
         pub fn browse(&self, options: BrowseOptions) -> Pagination<MediaCollection> {
+            if let Err(err) = options.validate() {
+                return Pagination::from_future(async move { Err(err) }, self.executor.clone());
+            }
             Pagination::new(
                 |options| {
                     async move {
@@ -95,6 +267,81 @@ mod browse {
             )
         }
 
+        /// Returns the alphabetical browse index, containing every starting letter together with
+        /// the number of titles starting with it. [`BrowseOptions::sort`] is ignored as the index
+        /// is always sorted alphabetically.
+        pub async fn browse_index(&self, options: BrowseOptions) -> Result<Vec<BrowseIndex>> {
+            options.validate()?;
+            let endpoint = "https://www.crunchyroll.com/content/v2/discover/browse/index";
+            Ok(self
+                .executor
+                .get(endpoint)
+                .query(&options.sort(BrowseSortType::Alphabetical).into_query())
+                .apply_locale_query()
+                .apply_preferred_audio_locale_query()
+                .request::<V2BulkResult<BrowseIndex>>()
+                .await?
+                .data)
+        }
+
+        /// Browses all titles starting with the given prefix (a letter or `#` for titles which
+        /// don't start with a letter). Use [`Crunchyroll::browse_index`] to get all available
+        /// prefixes. [`BrowseOptions::sort`] is ignored as the results are always sorted
+        /// alphabetically.
+        pub async fn browse_alphabetical<S: AsRef<str>>(
+            &self,
+            prefix: S,
+            options: BrowseOptions,
+        ) -> Result<Pagination<MediaCollection>> {
+            let options = options.sort(BrowseSortType::Alphabetical);
+            let index = self
+                .browse_index(options.clone())
+                .await?
+                .into_iter()
+                .find(|i| i.prefix.eq_ignore_ascii_case(prefix.as_ref()))
+                .ok_or_else(|| Error::Input {
+                    message: format!("no browse index with prefix '{}' found", prefix.as_ref()),
+                })?;
+
+            Ok(Pagination::new(
+                |options| {
+                    async move {
+                        let endpoint = "https://www.crunchyroll.com/content/v2/discover/browse";
+                        let offset: u32 = options.extra.get("offset").unwrap().parse().unwrap();
+                        let total: u32 = options.extra.get("total").unwrap().parse().unwrap();
+                        let result: V2BulkResult<MediaCollection, PaginationBulkResultMeta> =
+                            options
+                                .executor
+                                .clone()
+                                .get(endpoint)
+                                .query(&options.query)
+                                .query(&[
+                                    (
+                                        "n",
+                                        options.page_size.min(total.saturating_sub(options.start)),
+                                    ),
+                                    ("start", offset + options.start),
+                                ])
+                                .apply_locale_query()
+                                .apply_preferred_audio_locale_query()
+                                .request()
+                                .await?;
+                        Ok(PaginationData {
+                            data: result.data,
+                            next_type: PaginationNextType::Total(total),
+                        })
+                    }
+                    .boxed()
+                },
+                self.executor.clone(),
+                Some(options.into_query()),
+                Some(vec![
+                    ("offset", index.offset.to_string()),
+                    ("total", index.total.to_string()),
+                ]),
+            ))
+        }
+
         /// Returns all simulcast seasons. The locale specified which language the localization /
         /// human readable name ([`SimulcastSeasonLocalization::title`]) has.
         pub async fn simulcast_seasons(&self, locale: Locale) -> Result<Vec<SimulcastSeason>> {
@@ -107,16 +354,172 @@ mod browse {
                 .await?
                 .items)
         }
+
+        /// Returns all seasonal tags which can be used with [`BrowseOptions::season_tag`] to browse
+        /// the titles of a specific anime season.
+        pub async fn seasonal_tags(&self) -> Result<Vec<SeasonalTag>> {
+            let endpoint = "https://www.crunchyroll.com/content/v2/discover/seasonal_tags";
+            Ok(self
+                .executor
+                .get(endpoint)
+                .apply_locale_query()
+                .request::<V2BulkResult<SeasonalTag>>()
+                .await?
+                .data)
+        }
+
+        /// Returns all series which are / were simulcasted in the given simulcast season together
+        /// with their latest aired episode. Note that this function makes multiple requests per
+        /// series in the season. The series are processed concurrently (see
+        /// [`Concurrency::default`]), but it may still take some time to complete.
+        pub async fn simulcast_calendar(
+            &self,
+            simulcast_season: &SimulcastSeason,
+        ) -> Result<Vec<SimulcastCalendarEntry>> {
+            let media: Vec<MediaCollection> = self
+                .browse(
+                    BrowseOptions::default()
+                        .simulcast_season(simulcast_season.id.clone())
+                        .media_type(MediaType::Series),
+                )
+                .try_collect()
+                .await?;
+
+            futures_util::stream::iter(media.into_iter().filter_map(|m| match m {
+                MediaCollection::Series(series) => Some(series),
+                _ => None,
+            }))
+            .map(|series| async move {
+                let mut latest_episode = None;
+                let seasons = series.seasons().try_collect::<Vec<_>>().await?;
+                if let Some(season) = seasons
+                    .iter()
+                    .rev()
+                    .find(|s| s.is_simulcast)
+                    .or(seasons.last())
+                {
+                    let now = Utc::now();
+                    latest_episode = season
+                        .episodes()
+                        .try_collect::<Vec<_>>()
+                        .await?
+                        .into_iter()
+                        .filter(|e| e.episode_air_date <= now)
+                        .max_by_key(|e| e.episode_air_date)
+                }
+                Ok(SimulcastCalendarEntry {
+                    series,
+                    latest_episode,
+                })
+            })
+            .buffered(Concurrency::default().0)
+            .try_collect()
+            .await
+        }
+    }
+}
+
+/// Check that the maturity filters of browse and query options don't contradict each other.
+fn validate_maturity(
+    is_mature: Option<bool>,
+    maturity_rating: &Option<crate::crunchyroll::MaturityRating>,
+) -> std::result::Result<(), String> {
+    use crate::crunchyroll::MaturityRating;
+
+    match maturity_rating {
+        Some(MaturityRating::Custom(maturity_rating)) if maturity_rating.is_empty() => {
+            Err("maturity rating must not be empty".to_string())
+        }
+        Some(MaturityRating::NotMature) if is_mature == Some(true) => Err(
+            "only mature entries can't be requested with a non-mature maximum maturity rating"
+                .to_string(),
+        ),
+        _ => Ok(()),
     }
 }
 
 mod query {
     use crate::common::{Pagination, V2BulkResult, V2TypeBulkResult};
-    use crate::media::{Episode, MovieListing, Series};
-    use crate::{Crunchyroll, MediaCollection, MusicVideo};
-    use futures_util::FutureExt;
+    use crate::crunchyroll::{Executor, MaturityRating};
+    use crate::error::Error;
+    use crate::media::{Episode, MediaType, MovieListing, Series};
+    use crate::{enum_values, options, Crunchyroll, MediaCollection, MusicVideo, Request, Result};
+    use futures_util::stream::BoxStream;
+    use futures_util::{FutureExt, StreamExt};
+    use serde::de::DeserializeOwned;
+    use serde::{Deserialize, Serialize};
+    use std::sync::Arc;
+
+    enum_values! {
+        /// Type of search results.
+        pub enum QueryType {
+            TopResults = "top_results"
+            Series = "series"
+            MovieListing = "movie_listing"
+            Episode = "episode"
+            Music = "music"
+        }
+    }
+
+    options! {
+        /// Options how to query. The search endpoint has no sort or order parameter, results are
+        /// always ordered by relevance. Use [`crate::search::BrowseOptions::sort`] to sort browse
+        /// results and [`crate::common::Pagination::order`] to order list endpoints like
+        /// [`Crunchyroll::watch_history`].
+        QueryOptions;
+        /// Specifies whether only mature (`true`) or only non-mature (`false`) results should be
+        /// returned. If not set, no filter is sent and only the server-side default filtering of
+        /// Crunchyroll applies. The maturity rating of the current profile isn't read.
+        is_mature(bool, "is_mature") = None,
+        /// Specifies the maximum maturity rating of the results. If not set, no filter is sent and
+        /// only the server-side default filtering of Crunchyroll applies. Use
+        /// [`crate::profile::Profile::maturity_rating`] to filter by the setting of a profile.
+        maturity_rating(MaturityRating, "maturity_rating") = None
+    }
+
+    impl QueryOptions {
+        /// Check if the options are valid. This is done automatically before querying, so invalid
+        /// options fail with a descriptive [`Error::Input`] instead of an api error.
+        pub fn validate(&self) -> Result<()> {
+            super::validate_maturity(self.is_mature, &self.maturity_rating).map_err(|message| {
+                Error::Input {
+                    message: format!("invalid query options: {message}"),
+                }
+            })
+        }
+    }
 
-    /// Results when querying Crunchyroll.
+    /// A title suggestion for a (incomplete) search query. Use [`SearchSuggestion::media`] to get
+    /// the actual media.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Request)]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct SearchSuggestion {
+        #[serde(skip)]
+        executor: Arc<Executor>,
+
+        pub id: String,
+        pub title: String,
+        pub slug_title: String,
+
+        #[serde(rename = "type")]
+        pub media_type: MediaType,
+    }
+
+    impl SearchSuggestion {
+        /// Get the media this suggestion refers to.
+        pub async fn media(&self) -> Result<MediaCollection> {
+            MediaCollection::from_id(
+                &Crunchyroll {
+                    executor: self.executor.clone(),
+                },
+                &self.id,
+            )
+            .await
+        }
+    }
+
+    /// Results when querying Crunchyroll, grouped by their type.
     pub struct QueryResults {
         pub top_results: Pagination<MediaCollection>,
         pub series: Pagination<Series>,
@@ -125,143 +528,230 @@ mod query {
         pub music: Pagination<MusicVideo>,
     }
 
+    /// A batch of search results of a single type, see [`Crunchyroll::query_incremental`].
+    #[derive(Clone, Debug)]
+    pub struct QueryBatch {
+        pub query_type: QueryType,
+        /// Position of the first item of this batch in all results of this type.
+        pub offset: u32,
+        /// Total number of results of this type.
+        pub total: u32,
+        pub items: Vec<MediaCollection>,
+    }
+
     impl Crunchyroll {
-        /// Search the Crunchyroll catalog by a given query / string.
+        /// Returns title suggestions for a given (incomplete) query, ordered by relevance. This is
+        /// much more lightweight than [`Crunchyroll::query`] and intended to be used for
+        /// search-as-you-type.
+        pub async fn search_suggestions<S: AsRef<str>>(
+            &self,
+            query: S,
+        ) -> Result<Vec<SearchSuggestion>> {
+            let endpoint = "https://www.crunchyroll.com/content/v2/discover/autocomplete";
+            Ok(self
+                .executor
+                .get(endpoint)
+                .query(&[("q", query.as_ref())])
+                .apply_locale_query()
+                .request::<V2BulkResult<SearchSuggestion>>()
+                .await?
+                .data)
+        }
+
+        /// Search the Crunchyroll catalog by a given query / string. Every media type has its own
+        /// bucket which can be paginated independently.
         pub fn query<S: AsRef<str>>(&self, query: S) -> QueryResults {
+            self.query_with_options(query, QueryOptions::default())
+        }
+
+        /// Like [`Crunchyroll::query`] but filters the results by the given options, e.g. to only
+        /// return non-mature results regardless of the profile settings. If the options are
+        /// invalid (see [`QueryOptions::validate`]), the first item of every pagination is an
+        /// error.
+        pub fn query_with_options<S: AsRef<str>>(
+            &self,
+            query: S,
+            options: QueryOptions,
+        ) -> QueryResults {
+            if let Err(err) = options.validate() {
+                return QueryResults {
+                    top_results: failed_bucket(self, err.clone()),
+                    series: failed_bucket(self, err.clone()),
+                    movie_listing: failed_bucket(self, err.clone()),
+                    episode: failed_bucket(self, err.clone()),
+                    music: failed_bucket(self, err),
+                };
+            }
+            let (query, options) = (query.as_ref(), options.into_query());
             QueryResults {
-                top_results: Pagination::new(
-                    |options| {
-                        async move {
-                            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
-                            let result: V2BulkResult<V2TypeBulkResult<MediaCollection>> = options
-                                .executor
-                                .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
-                                .query(&[("type", "top_results")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
-                                .apply_locale_query()
-                                .request()
-                                .await?;
-                            let top_results = result
-                                .data
-                                .into_iter()
-                                .find(|r| r.result_type == "top_results")
-                                .unwrap_or_default();
-                            Ok(top_results.into())
-                        }
-                        .boxed()
-                    },
-                    self.executor.clone(),
-                    None,
-                    Some(vec![("q", query.as_ref().to_string())]),
-                ),
-                series: Pagination::new(
-                    |options| {
-                        async move {
-                            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
-                            let result: V2BulkResult<V2TypeBulkResult<Series>> = options
-                                .executor
-                                .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
-                                .query(&[("type", "series")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
-                                .apply_locale_query()
-                                .request()
-                                .await?;
-                            let series_results = result
-                                .data
-                                .into_iter()
-                                .find(|r| r.result_type == "series")
-                                .unwrap_or_default();
-                            Ok(series_results.into())
-                        }
-                        .boxed()
-                    },
-                    self.executor.clone(),
-                    None,
-                    Some(vec![("q", query.as_ref().to_string())]),
-                ),
-                movie_listing: Pagination::new(
-                    |options| {
-                        async move {
-                            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
-                            let result: V2BulkResult<V2TypeBulkResult<MovieListing>> = options
-                                .executor
-                                .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
-                                .query(&[("type", "movie_listing")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
-                                .apply_locale_query()
-                                .request()
-                                .await?;
-                            let movie_listing_results = result
-                                .data
-                                .into_iter()
-                                .find(|r| r.result_type == "movie_listing")
-                                .unwrap_or_default();
-                            Ok(movie_listing_results.into())
-                        }
-                        .boxed()
-                    },
-                    self.executor.clone(),
-                    None,
-                    Some(vec![("q", query.as_ref().to_string())]),
-                ),
-                episode: Pagination::new(
-                    |options| {
-                        async move {
-                            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
-                            let result: V2BulkResult<V2TypeBulkResult<Episode>> = options
-                                .executor
-                                .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
-                                .query(&[("type", "episode")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
-                                .apply_locale_query()
-                                .request()
-                                .await?;
-                            let episode_results = result
-                                .data
-                                .into_iter()
-                                .find(|r| r.result_type == "episode")
-                                .unwrap_or_default();
-                            Ok(episode_results.into())
-                        }
-                        .boxed()
-                    },
-                    self.executor.clone(),
-                    None,
-                    Some(vec![("q", query.as_ref().to_string())]),
-                ),
-                music: Pagination::new(
-                    |options| {
-                        async move {
-                            let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
-                            let result: V2BulkResult<V2TypeBulkResult<MusicVideo>> = options
-                                .executor
-                                .get(endpoint)
-                                .query(&[("q", options.extra.get("q").unwrap())])
-                                .query(&[("type", "music")])
-                                .query(&[("limit", options.page_size), ("start", options.start)])
-                                .apply_locale_query()
-                                .request()
-                                .await?;
-                            let music_results = result
-                                .data
-                                .into_iter()
-                                .find(|r| r.result_type == "episode")
-                                .unwrap_or_default();
-                            Ok(music_results.into())
-                        }
-                        .boxed()
-                    },
+                top_results: query_bucket(self, query, QueryType::TopResults, options.clone()),
+                series: query_bucket(self, query, QueryType::Series, options.clone()),
+                movie_listing: query_bucket(self, query, QueryType::MovieListing, options.clone()),
+                episode: query_bucket(self, query, QueryType::Episode, options.clone()),
+                music: query_bucket(self, query, QueryType::Music, options),
+            }
+        }
+
+        /// Like [`Crunchyroll::query`] but requests the series, movie listing, episode and music
+        /// results concurrently and yields them in batches of `batch_size` as soon as a request has
+        /// finished, so results can be rendered progressively on slow connections. Batches of
+        /// different types arrive in no particular order, batches of the same type are in order.
+        /// Every type is paginated until `max_results` results were returned or no results are
+        /// left. A failed request ends the results of its type.
+        ///
+        /// ```no_run
+        /// use futures_util::StreamExt;
+        ///
+        /// # async fn f(crunchyroll: crunchyroll_rs::Crunchyroll) {
+        /// let mut results = crunchyroll.query_incremental("darling", 10, 50);
+        /// while let Some(Ok(batch)) = results.next().await {
+        ///     println!("{} {} results", batch.items.len(), batch.query_type)
+        /// }
+        /// # }
+        /// ```
+        pub fn query_incremental<S: AsRef<str>>(
+            &self,
+            query: S,
+            batch_size: u32,
+            max_results: u32,
+        ) -> BoxStream<'static, Result<QueryBatch>> {
+            let buckets = [
+                QueryType::Series,
+                QueryType::MovieListing,
+                QueryType::Episode,
+                QueryType::Music,
+            ]
+            .map(|query_type| {
+                query_batches(
                     self.executor.clone(),
-                    None,
-                    Some(vec![("q", query.as_ref().to_string())]),
-                ),
+                    query.as_ref().to_string(),
+                    query_type,
+                    batch_size.max(1),
+                    max_results,
+                )
+            });
+            futures_util::stream::select_all(buckets).boxed()
+        }
+
+        /// Search the Crunchyroll catalog by a given query / string, but only return results of
+        /// the given type. Use this instead of [`Crunchyroll::query`] if you are only interested
+        /// in a specific type of media.
+        pub fn query_type<S: AsRef<str>>(
+            &self,
+            query: S,
+            query_type: QueryType,
+        ) -> Pagination<MediaCollection> {
+            self.query_type_with_options(query, query_type, QueryOptions::default())
+        }
+
+        /// Like [`Crunchyroll::query_type`] but filters the results by the given options. If the
+        /// options are invalid (see [`QueryOptions::validate`]), the first item of the pagination
+        /// is an error.
+        pub fn query_type_with_options<S: AsRef<str>>(
+            &self,
+            query: S,
+            query_type: QueryType,
+            options: QueryOptions,
+        ) -> Pagination<MediaCollection> {
+            if let Err(err) = options.validate() {
+                return failed_bucket(self, err);
             }
+            query_bucket(self, query.as_ref(), query_type, options.into_query())
         }
     }
+
+    fn query_batches(
+        executor: Arc<Executor>,
+        query: String,
+        query_type: QueryType,
+        batch_size: u32,
+        max_results: u32,
+    ) -> BoxStream<'static, Result<QueryBatch>> {
+        futures_util::stream::unfold(Some(0), move |offset| {
+            let (executor, query, query_type) =
+                (executor.clone(), query.clone(), query_type.clone());
+            async move {
+                let offset = offset.filter(|offset| *offset < max_results)?;
+                let result = executor
+                    .get("https://www.crunchyroll.com/content/v2/discover/search")
+                    .query(&[("q", &query), ("type", &query_type.to_string())])
+                    .query(&[
+                        ("limit", batch_size.min(max_results - offset)),
+                        ("start", offset),
+                    ])
+                    .apply_locale_query()
+                    .request::<V2BulkResult<V2TypeBulkResult<MediaCollection>>>()
+                    .await;
+                let bucket = match result {
+                    Ok(result) => result
+                        .data
+                        .into_iter()
+                        .find(|r| r.result_type == query_type.to_string())
+                        .unwrap_or_default(),
+                    Err(err) => return Some((Err(err), None)),
+                };
+
+                let next = offset + bucket.items.len() as u32;
+                if bucket.items.is_empty() {
+                    return None;
+                }
+                let batch = QueryBatch {
+                    query_type,
+                    offset,
+                    total: bucket.total,
+                    items: bucket.items,
+                };
+                Some((Ok(batch), (next < bucket.total).then_some(next)))
+            }
+        })
+        .boxed()
+    }
+
+    fn failed_bucket<T: Default + DeserializeOwned + Request + Send + 'static>(
+        crunchyroll: &Crunchyroll,
+        err: Error,
+    ) -> Pagination<T> {
+        Pagination::from_future(async move { Err(err) }, crunchyroll.executor.clone())
+    }
+
+    fn query_bucket<T: Default + DeserializeOwned + Request + Send + 'static>(
+        crunchyroll: &Crunchyroll,
+        query: &str,
+        query_type: QueryType,
+        options: Vec<(String, String)>,
+    ) -> Pagination<T> {
+        Pagination::new(
+            |options| {
+                async move {
+                    let endpoint = "https://www.crunchyroll.com/content/v2/discover/search";
+                    let query_type = options.extra.get("type").unwrap().clone();
+                    let result: V2BulkResult<V2TypeBulkResult<T>> = options
+                        .executor
+                        .get(endpoint)
+                        .query(&[("q", options.extra.get("q").unwrap())])
+                        .query(&[("type", &query_type)])
+                        .query(&options.query)
+                        .query(&[("limit", options.page_size), ("start", options.start)])
+                        .apply_locale_query()
+                        .request()
+                        .await?;
+                    let results = result
+                        .data
+                        .into_iter()
+                        .find(|r| r.result_type == query_type)
+                        .unwrap_or_default();
+                    Ok(results.into())
+                }
+                .boxed()
+            },
+            crunchyroll.executor.clone(),
+            Some(options),
+            Some(vec![
+                ("q", query.to_string()),
+                ("type", query_type.to_string()),
+            ]),
+        )
+    }
 }
 
 pub use browse::*;
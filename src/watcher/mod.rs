@@ -0,0 +1,255 @@
+//! Watch series for new episodes, seasons and dubs.
+
+#[cfg(feature = "rss")]
+mod rss;
+
+#[cfg(feature = "rss")]
+pub use rss::*;
+
+use crate::common::{StreamExt, TryStreamExt};
+use crate::error::Error;
+use crate::list::WatchlistOptions;
+use crate::{Crunchyroll, Episode, Locale, MediaCollection, Result, Season, Series};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// Event emitted by a [`Watcher`].
+#[cfg_attr(docsrs, doc(cfg(feature = "watcher")))]
+#[derive(Clone, Debug)]
+pub enum WatcherEvent {
+    /// A new episode was released. Also emitted for every episode of a new season or dub.
+    NewEpisode(Box<Episode>),
+    /// A new season was added to a series.
+    NewSeason(Box<Season>),
+    /// A season got a new audio locale.
+    NewDub {
+        season: Box<Season>,
+        audio_locale: Locale,
+    },
+}
+
+/// Result of a single [`Watcher::check`].
+#[cfg_attr(docsrs, doc(cfg(feature = "watcher")))]
+#[derive(Debug, Default)]
+pub struct WatcherCheck {
+    /// All changes of the series which could be checked.
+    pub events: Vec<WatcherEvent>,
+    /// Id and error of every series which couldn't be checked. The state of these series isn't
+    /// updated, so their changes are reported by the next successful check.
+    pub errors: Vec<(String, Error)>,
+}
+
+/// State of a single watched series.
+#[derive(Clone, Default)]
+struct SeriesState {
+    /// Season id to the audio locales and number of episodes of the season.
+    seasons: HashMap<String, (Vec<Locale>, u32)>,
+    /// Ids of all seasons and their versions.
+    season_ids: HashSet<String>,
+    episode_ids: HashSet<String>,
+}
+
+/// Periodically checks series for new episodes, seasons and dubs and emits [`WatcherEvent`]s.
+///
+/// The first check of a series only records its current state, events are emitted for changes
+/// detected by all following checks. Every check requires one request per series plus one request
+/// per season which isn't complete yet (or changed since the last check).
+///
+/// ```no_run
+/// use crunchyroll_rs::watcher::{Watcher, WatcherEvent};
+/// use std::time::Duration;
+///
+/// # async fn f(crunchyroll: crunchyroll_rs::Crunchyroll) {
+/// let (sender, mut receiver) = tokio::sync::mpsc::channel(32);
+/// let watcher = Watcher::new(&crunchyroll)
+///     .series("GY8VEQ95Y")
+///     .interval(Duration::from_secs(10 * 60));
+/// tokio::spawn(watcher.run(sender));
+///
+/// while let Some(event) = receiver.recv().await {
+///     if let Ok(WatcherEvent::NewEpisode(episode)) = event {
+///         println!("{} is out", episode.title)
+///     }
+/// }
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "watcher")))]
+pub struct Watcher {
+    crunchyroll: Crunchyroll,
+    series: Vec<String>,
+    watchlist: bool,
+    interval: Duration,
+
+    state: HashMap<String, SeriesState>,
+}
+
+impl Watcher {
+    /// Creates a new watcher which doesn't watch anything yet. The default check interval is 5
+    /// minutes.
+    pub fn new(crunchyroll: &Crunchyroll) -> Self {
+        Self {
+            crunchyroll: crunchyroll.clone(),
+            series: vec![],
+            watchlist: false,
+            interval: Duration::from_secs(5 * 60),
+            state: HashMap::new(),
+        }
+    }
+
+    /// Watch the series with the given id.
+    pub fn series(mut self, id: impl AsRef<str>) -> Self {
+        let id = id.as_ref().to_string();
+        if !self.series.contains(&id) {
+            self.series.push(id)
+        }
+        self
+    }
+
+    /// Watch all series on the watchlist of the logged-in account. The watchlist is re-read on
+    /// every check, series which are added to the watchlist later are watched too.
+    pub fn watchlist(mut self, watchlist: bool) -> Self {
+        self.watchlist = watchlist;
+        self
+    }
+
+    /// Set the interval between two checks.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Check all watched series once and return all changes since the last check. Use this instead
+    /// of [`Watcher::run`] if you want to schedule the checks yourself. A series which fails to be
+    /// checked doesn't affect the other series, see [`WatcherCheck::errors`]. Only fails if the
+    /// watchlist can't be read.
+    pub async fn check(&mut self) -> Result<WatcherCheck> {
+        let mut series_ids = self.series.clone();
+        if self.watchlist {
+            let mut watchlist = self.crunchyroll.watchlist(WatchlistOptions::default());
+            while let Some(entry) = watchlist.next().await {
+                if let MediaCollection::Series(series) = entry?.panel {
+                    if !series_ids.contains(&series.id) {
+                        series_ids.push(series.id)
+                    }
+                }
+            }
+        }
+
+        let mut result = WatcherCheck::default();
+        for id in series_ids {
+            let first_check = !self.state.contains_key(&id);
+            // the state is only updated if the whole series was checked, otherwise changes which
+            // were detected before the error would never be reported
+            let mut state = self.state.get(&id).cloned().unwrap_or_default();
+            let mut events = vec![];
+            match check_series(&self.crunchyroll, &id, &mut state, first_check, &mut events).await {
+                Ok(()) => {
+                    self.state.insert(id, state);
+                    result.events.extend(events)
+                }
+                Err(err) => result.errors.push((id, err)),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Check all watched series every [`Watcher::interval`] and send the events to `sender`. Errors
+    /// which occur while checking are sent too, the watcher keeps running afterwards. Returns when
+    /// the receiver of `sender` is dropped. Not supported on wasm.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn run(mut self, sender: tokio::sync::mpsc::Sender<Result<WatcherEvent>>) {
+        loop {
+            let events: Vec<Result<WatcherEvent>> = match self.check().await {
+                Ok(check) => check
+                    .events
+                    .into_iter()
+                    .map(Ok)
+                    .chain(check.errors.into_iter().map(|(_, err)| Err(err)))
+                    .collect(),
+                Err(e) => vec![Err(e)],
+            };
+            for event in events {
+                if sender.send(event).await.is_err() {
+                    return;
+                }
+            }
+
+            let sleep = std::pin::pin!(tokio::time::sleep(self.interval));
+            let closed = std::pin::pin!(sender.closed());
+            if let futures_util::future::Either::Right(_) =
+                futures_util::future::select(sleep, closed).await
+            {
+                return;
+            }
+        }
+    }
+}
+
+async fn check_series(
+    crunchyroll: &Crunchyroll,
+    id: &str,
+    state: &mut SeriesState,
+    first_check: bool,
+    events: &mut Vec<WatcherEvent>,
+) -> Result<()> {
+    let mut series = Series::default();
+    series.executor = crunchyroll.executor.clone();
+    series.id = id.to_string();
+
+    for season in series.seasons().try_collect::<Vec<_>>().await? {
+        let mut audio_locales: Vec<Locale> = season
+            .versions
+            .iter()
+            .map(|v| v.audio_locale.clone())
+            .collect();
+        if audio_locales.is_empty() {
+            audio_locales.clone_from(&season.audio_locales)
+        }
+
+        let fetch_episodes = match state.seasons.get(&season.id) {
+            Some((known_locales, number_of_episodes)) => {
+                for audio_locale in &audio_locales {
+                    if !known_locales.contains(audio_locale) {
+                        events.push(WatcherEvent::NewDub {
+                            season: Box::new(season.clone()),
+                            audio_locale: audio_locale.clone(),
+                        })
+                    }
+                }
+                !season.is_complete || *number_of_episodes != season.number_of_episodes
+            }
+            None => {
+                // seasons which are a version of an already known season are new dubs, which
+                // are reported via the known season
+                let is_version = state.season_ids.contains(&season.id)
+                    || season
+                        .versions
+                        .iter()
+                        .any(|v| state.season_ids.contains(&v.id));
+                if !first_check && !is_version {
+                    events.push(WatcherEvent::NewSeason(Box::new(season.clone())))
+                }
+                true
+            }
+        };
+
+        state.season_ids.insert(season.id.clone());
+        state
+            .season_ids
+            .extend(season.versions.iter().map(|v| v.id.clone()));
+        state.seasons.insert(
+            season.id.clone(),
+            (audio_locales, season.number_of_episodes),
+        );
+
+        if fetch_episodes {
+            for episode in season.episodes().try_collect::<Vec<_>>().await? {
+                if state.episode_ids.insert(episode.id.clone()) && !first_check {
+                    events.push(WatcherEvent::NewEpisode(Box::new(episode)))
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use crate::internal::xml::escape;
+use crate::watcher::WatcherEvent;
+use crate::Episode;
+use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+/// Renders new episodes, e.g. emitted as [`WatcherEvent::NewEpisode`] by a
+/// [`crate::watcher::Watcher`], as RSS 2.0 or Atom feed. The feed keeps the newest
+/// [`EpisodeFeed::max_items`] episodes.
+///
+/// ```no_run
+/// use crunchyroll_rs::watcher::{EpisodeFeed, Watcher};
+///
+/// # async fn f(crunchyroll: crunchyroll_rs::Crunchyroll) -> Result<(), crunchyroll_rs::error::Error> {
+/// let mut watcher = Watcher::new(&crunchyroll).watchlist(true);
+/// let mut feed = EpisodeFeed::new("Crunchyroll watchlist");
+/// loop {
+///     feed.extend(watcher.check().await?.events);
+///     std::fs::write("feed.xml", feed.to_rss()).unwrap();
+///     tokio::time::sleep(std::time::Duration::from_secs(10 * 60)).await
+/// }
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rss")))]
+#[derive(Clone, Debug)]
+pub struct EpisodeFeed {
+    title: String,
+    link: String,
+    description: String,
+    author: String,
+    max_items: usize,
+
+    /// Newest episode first.
+    episodes: VecDeque<Episode>,
+}
+
+impl EpisodeFeed {
+    /// Creates a new, empty feed with the given title. By default, the feed links to
+    /// `https://www.crunchyroll.com`, has `Crunchyroll` as author and keeps 50 episodes.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            link: "https://www.crunchyroll.com".to_string(),
+            description: String::new(),
+            author: "Crunchyroll".to_string(),
+            max_items: 50,
+            episodes: VecDeque::new(),
+        }
+    }
+
+    /// Set the link of the feed itself.
+    pub fn link(mut self, link: impl Into<String>) -> Self {
+        self.link = link.into();
+        self
+    }
+
+    /// Set the description of the feed.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Set the author of the feed. Only used by [`EpisodeFeed::to_atom`], RSS has no equivalent.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = author.into();
+        self
+    }
+
+    /// Set how many episodes the feed keeps. Older episodes are removed when new ones are added.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = max_items;
+        self.episodes.truncate(max_items);
+        self
+    }
+
+    /// Add an episode to the feed. Episodes which are already part of the feed are ignored.
+    pub fn push(&mut self, episode: Episode) {
+        if self.episodes.iter().any(|e| e.id == episode.id) {
+            return;
+        }
+        self.episodes.push_front(episode);
+        self.episodes.truncate(self.max_items)
+    }
+
+    /// All episodes of the feed, newest first.
+    pub fn episodes(&self) -> impl Iterator<Item = &Episode> {
+        self.episodes.iter()
+    }
+
+    /// Render the feed as RSS 2.0 xml.
+    pub fn to_rss(&self) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        xml.push_str(r#"<rss version="2.0"><channel>"#);
+        let _ = write!(
+            xml,
+            "<title>{}</title><link>{}</link><description>{}</description><lastBuildDate>{}</lastBuildDate>",
+            escape(&self.title),
+            escape(&self.link),
+            escape(&self.description),
+            self.updated().to_rfc2822()
+        );
+        for episode in &self.episodes {
+            let _ = write!(
+                xml,
+                r#"<item><title>{}</title><link>{}</link><guid isPermaLink="false">{}</guid><description>{}</description><category>{}</category><pubDate>{}</pubDate></item>"#,
+                escape(&episode.display_title()),
+                escape(&episode_link(episode)),
+                escape(&episode.id),
+                escape(&episode.description),
+                escape(&episode.series_title),
+                episode.premium_available_date.to_rfc2822()
+            );
+        }
+        xml.push_str("</channel></rss>");
+        xml
+    }
+
+    /// Render the feed as Atom xml.
+    pub fn to_atom(&self) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        xml.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+        let _ = write!(
+            xml,
+            r#"<id>{}</id><title>{}</title><subtitle>{}</subtitle><link href="{}"/><author><name>{}</name></author><updated>{}</updated>"#,
+            escape(&self.link),
+            escape(&self.title),
+            escape(&self.description),
+            escape(&self.link),
+            escape(&self.author),
+            self.updated().to_rfc3339()
+        );
+        for episode in &self.episodes {
+            let _ = write!(
+                xml,
+                r#"<entry><id>urn:crunchyroll:episode:{}</id><title>{}</title><link href="{}"/><summary>{}</summary><category term="{}"/><updated>{}</updated></entry>"#,
+                escape(&episode.id),
+                escape(&episode.display_title()),
+                escape(&episode_link(episode)),
+                escape(&episode.description),
+                escape(&episode.series_title),
+                episode.premium_available_date.to_rfc3339()
+            );
+        }
+        xml.push_str("</feed>");
+        xml
+    }
+
+    /// Date of the newest episode or now if the feed is empty.
+    fn updated(&self) -> DateTime<Utc> {
+        self.episodes
+            .iter()
+            .map(|e| e.premium_available_date)
+            .max()
+            .unwrap_or_else(Utc::now)
+    }
+}
+
+impl Extend<Episode> for EpisodeFeed {
+    fn extend<T: IntoIterator<Item = Episode>>(&mut self, iter: T) {
+        for episode in iter {
+            self.push(episode)
+        }
+    }
+}
+
+/// Adds the episodes of all [`WatcherEvent::NewEpisode`] events, other events are ignored.
+impl Extend<WatcherEvent> for EpisodeFeed {
+    fn extend<T: IntoIterator<Item = WatcherEvent>>(&mut self, iter: T) {
+        for event in iter {
+            if let WatcherEvent::NewEpisode(episode) = event {
+                self.push(*episode)
+            }
+        }
+    }
+}
+
+fn episode_link(episode: &Episode) -> String {
+    format!(
+        "https://www.crunchyroll.com/watch/{}/{}",
+        episode.id, episode.slug_title
+    )
+}
@@ -2,6 +2,8 @@
 /// Begins with an underscore because this must be the first file to be called
 mod utils;
 
+use crunchyroll_rs::crunchyroll::{CrunchyrollBuilder, Registration};
+use crunchyroll_rs::error::Error;
 use crunchyroll_rs::Crunchyroll;
 use std::env;
 
@@ -103,3 +105,64 @@ async fn login_anonymously() {
         utils::session::set_session(crunchy.unwrap()).await.unwrap()
     }
 }
+
+#[tokio::test]
+async fn login_dns_overrides_with_custom_client() {
+    let crunchy = Crunchyroll::builder()
+        .client(
+            CrunchyrollBuilder::predefined_client_builder()
+                .build()
+                .unwrap(),
+        )
+        .resolve("www.crunchyroll.com", ["127.0.0.1:443".parse().unwrap()])
+        .login_anonymously()
+        .await;
+
+    assert!(matches!(crunchy, Err(Error::Input { .. })))
+}
+
+#[tokio::test]
+async fn login_invalid_root_certificate() {
+    let crunchy = Crunchyroll::builder()
+        .root_certificate(b"not a certificate".to_vec())
+        .login_anonymously()
+        .await;
+
+    assert!(matches!(crunchy, Err(Error::Input { .. })))
+}
+
+#[tokio::test]
+async fn login_accept_invalid_certs_with_custom_client() {
+    let crunchy = Crunchyroll::builder()
+        .client(
+            CrunchyrollBuilder::predefined_client_builder()
+                .build()
+                .unwrap(),
+        )
+        .danger_accept_invalid_certs(true)
+        .login_anonymously()
+        .await;
+
+    assert!(matches!(crunchy, Err(Error::Input { .. })))
+}
+
+#[tokio::test]
+async fn register_invalid() {
+    let crunchy = Crunchyroll::builder()
+        .register(Registration::new("me@example.com", "password"))
+        .await;
+    assert!(matches!(crunchy, Err(Error::Input { .. })));
+
+    let crunchy = Crunchyroll::builder()
+        .register(Registration::new("not an email", "password").accept_terms(true))
+        .await;
+    assert!(matches!(crunchy, Err(Error::Input { .. })))
+}
+
+#[test]
+fn registration_debug_redacts_password() {
+    let registration = Registration::new("me@example.com", "secret-password");
+    let debug = format!("{registration:?}");
+    assert!(debug.contains("me@example.com"));
+    assert!(!debug.contains("secret-password"))
+}
@@ -0,0 +1,38 @@
+use crate::utils::{Store, SESSION};
+use crunchyroll_rs::media::Artist;
+use futures_util::StreamExt;
+
+mod utils;
+
+static ARTIST: Store<Artist> = Store::new(|| {
+    Box::pin(async {
+        let crunchy = SESSION.get().await?;
+        let artist = crunchy.artist("MA179CB50D").await?;
+        Ok(artist)
+    })
+});
+
+#[tokio::test]
+async fn artist_from_id() {
+    assert_result!(ARTIST.get().await)
+}
+
+#[tokio::test]
+async fn artist_concerts() {
+    let mut concerts = ARTIST.get().await.unwrap().concerts();
+    if let Some(concert) = concerts.next().await {
+        assert_result!(concert)
+    }
+}
+
+#[tokio::test]
+async fn artist_music_videos() {
+    assert_result!(ARTIST
+        .get()
+        .await
+        .unwrap()
+        .music_videos()
+        .next()
+        .await
+        .unwrap())
+}
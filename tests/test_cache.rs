@@ -0,0 +1,72 @@
+use crate::utils::login_with_session;
+use chrono::{Duration, Utc};
+use crunchyroll_rs::cache::{
+    CacheStorage, CachedResponse, DiskCacheStorage, MemoryCacheStorage, ResponseCache,
+};
+use crunchyroll_rs::{Crunchyroll, Series};
+
+mod utils;
+
+fn cached_response() -> CachedResponse {
+    CachedResponse {
+        body: b"{}".to_vec(),
+        etag: Some("\"etag\"".to_string()),
+        last_modified: None,
+        fresh_until: Some(Utc::now() + Duration::minutes(1)),
+    }
+}
+
+#[tokio::test]
+async fn memory_cache_storage() {
+    let storage = MemoryCacheStorage::default();
+    storage.set("key", cached_response()).await.unwrap();
+
+    let cached = storage.get("key").await.unwrap();
+    assert!(cached.is_fresh());
+    assert!(cached.has_validators());
+    assert!(storage.get("other").await.is_none());
+
+    storage.clear().await.unwrap();
+    assert!(storage.get("key").await.is_none())
+}
+
+#[tokio::test]
+async fn memory_cache_storage_capacity() {
+    let storage = MemoryCacheStorage::with_capacity(2);
+    storage.set("first", cached_response()).await.unwrap();
+    storage.set("second", cached_response()).await.unwrap();
+    // "first" is now used more recently than "second"
+    assert!(storage.get("first").await.is_some());
+
+    storage.set("third", cached_response()).await.unwrap();
+    assert!(storage.get("first").await.is_some());
+    assert!(storage.get("second").await.is_none());
+    assert!(storage.get("third").await.is_some())
+}
+
+#[tokio::test]
+async fn disk_cache_storage() {
+    let directory =
+        std::env::temp_dir().join(format!("crunchyroll-rs-cache-{}", rand::random::<u32>()));
+    let storage = DiskCacheStorage::new(&directory);
+    storage.set("key", cached_response()).await.unwrap();
+
+    assert_eq!(storage.get("key").await.unwrap().body, b"{}");
+    assert!(storage.get("other").await.is_none());
+
+    storage.clear().await.unwrap();
+    assert!(storage.get("key").await.is_none());
+    std::fs::remove_dir_all(directory).unwrap()
+}
+
+#[tokio::test]
+async fn cached_request() {
+    let builder =
+        Crunchyroll::builder().cache(ResponseCache::memory().default_ttl(Duration::minutes(1)));
+    let crunchy = login_with_session(builder).await;
+
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    let cached_series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    assert_eq!(series.id, cached_series.id);
+    assert_eq!(series.title, cached_series.title)
+}
@@ -1,4 +1,6 @@
 use crate::utils::SESSION;
+use crunchyroll_rs::search::BrowseOptions;
+use futures_util::StreamExt;
 
 mod utils;
 
@@ -14,3 +16,80 @@ async fn sub_categories() {
     let categories = crunchy.categories().await.unwrap();
     assert_result!(categories.first().unwrap().sub_categories().await)
 }
+
+#[tokio::test]
+async fn browse_sub_category() {
+    let crunchy = SESSION.get().await.unwrap();
+    let categories = crunchy.categories().await.unwrap();
+    let sub_category = categories
+        .into_iter()
+        .find_map(|c| c.sub_category_information.into_iter().next())
+        .unwrap();
+    let result = crunchy
+        .browse(BrowseOptions::default().categories(vec![sub_category.into()]))
+        .next()
+        .await
+        .unwrap();
+    assert_result!(result)
+}
+
+#[tokio::test]
+async fn category_statistics() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert_result!(crunchy.category_statistics().await)
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn replay_category_statistics() {
+    use crunchyroll_rs::categories::Category;
+
+    let browse = |category: &str, media_type: &str, total: u32| {
+        (
+            format!("GET https://www.crunchyroll.com/content/v2/discover/browse?categories={category}&sort_by=newly_added&type={media_type}&n=1&locale=en-US"),
+            format!(r#"{{"total":{total},"data":[],"meta":{{}}}}"#),
+        )
+    };
+    let responses = [
+        (
+            "GET https://www.crunchyroll.com/content/v2/discover/categories?include_subcategories=true&locale=en-US".to_string(),
+            r#"{"total":2,"data":[
+                {"id":"action","children":[{"id":"mecha","parent_category_id":"action"},{"id":"isekai","parent_category_id":"action"}]},
+                {"id":"drama","children":[]}
+            ],"meta":{}}"#
+                .to_string(),
+        ),
+        browse("action", "series", 1),
+        browse("action", "movie_listing", 2),
+        browse("mecha", "series", 3),
+        browse("mecha", "movie_listing", 4),
+        browse("isekai", "series", 5),
+        browse("isekai", "movie_listing", 6),
+        browse("drama", "series", 7),
+        browse("drama", "movie_listing", 8),
+    ];
+    let responses: Vec<_> = responses
+        .iter()
+        .map(|(request, body)| (request.as_str(), body.as_str()))
+        .collect();
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&responses).await;
+
+    // the statistics keep the order of the categories although they're requested concurrently
+    let statistics = crunchy.category_statistics().await.unwrap();
+    let counts = |statistics: &[crunchyroll_rs::categories::CategoryStatistics]| {
+        statistics
+            .iter()
+            .map(|s| (s.category.clone(), s.series_count, s.movie_listing_count))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        counts(&statistics),
+        [(Category::Action, 1, 2), (Category::Drama, 7, 8)]
+    );
+    assert_eq!(
+        counts(&statistics[0].sub_categories),
+        [(Category::Mecha, 3, 4), (Category::Isekai, 5, 6)]
+    );
+    assert!(statistics[1].sub_categories.is_empty());
+}
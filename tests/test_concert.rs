@@ -16,8 +16,33 @@ async fn concert_from_id() {
     assert_result!(CONCERT.get().await)
 }
 
+#[cfg(feature = "streaming")]
 #[tokio::test]
 async fn concert_stream() {
     let stream = CONCERT.get().await.unwrap().stream().await.unwrap();
     stream.invalidate().await.unwrap()
 }
+
+#[tokio::test]
+async fn concert_artists() {
+    assert_result!(CONCERT.get().await.unwrap().artists().await)
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn concert_venue() {
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&[(
+        "GET https://www.crunchyroll.com/content/v2/music/concerts/MC27E95748?locale=en-US",
+        r#"{"total":1,"data":[{"id":"MC27E95748","title":"Live","originalRelease":"2023-08-26T00:00:00Z","venue":"Makuhari Messe","location":"","eventName":"Summer Tour"}],"meta":{}}"#,
+    )])
+    .await;
+
+    let concert: Concert = crunchy.media_from_id("MC27E95748").await.unwrap();
+    assert_eq!(concert.venue.as_deref(), Some("Makuhari Messe"));
+    assert_eq!(concert.location, None);
+    assert_eq!(concert.event_name.as_deref(), Some("Summer Tour"));
+    assert_eq!(
+        concert.original_release.to_rfc3339(),
+        "2023-08-26T00:00:00+00:00"
+    )
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "crawler")]
+
+use crate::utils::SESSION;
+use crunchyroll_rs::categories::Category;
+use crunchyroll_rs::crawler::{Crawler, CrawlerCheckpoint};
+use std::time::Duration;
+
+mod utils;
+
+#[test]
+fn crawler_checkpoint() {
+    let mut checkpoint = CrawlerCheckpoint::default();
+    assert!(!checkpoint.is_finished());
+
+    checkpoint.categories = Some(vec![Category::Action, Category::Isekai]);
+    checkpoint.category_index = 1;
+    checkpoint.offset = 50;
+    checkpoint.visited.insert("GY8VEQ95Y".to_string());
+    assert!(!checkpoint.is_finished());
+
+    let json = serde_json::to_string(&checkpoint).unwrap();
+    assert_eq!(
+        serde_json::from_str::<CrawlerCheckpoint>(&json).unwrap(),
+        checkpoint
+    );
+
+    checkpoint.category_index = 2;
+    assert!(checkpoint.is_finished())
+}
+
+#[tokio::test]
+async fn crawl_category() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut crawler = Crawler::new(crunchy)
+        .categories(vec![Category::PostApocalyptic])
+        .page_size(50)
+        .delay(Duration::ZERO);
+    let mut visited = 0;
+    let result = crawler.run(|_| visited += 1).await;
+
+    assert_result!(result);
+    assert!(crawler.checkpoint().is_finished());
+    assert_eq!(crawler.checkpoint().visited.len(), visited)
+}
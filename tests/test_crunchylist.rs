@@ -1,6 +1,6 @@
 use crate::utils::{Store, SESSION};
 use crunchyroll_rs::list::Crunchylists;
-use crunchyroll_rs::{MediaCollection, Series};
+use crunchyroll_rs::{MediaCollection, MovieListing, Series};
 
 mod utils;
 
@@ -24,7 +24,8 @@ async fn modify_crunchylist() {
     let new_crunchylist_preview = crunchylists.create("test").await;
     assert_result!(new_crunchylist_preview);
 
-    let new_crunchylist = new_crunchylist_preview.unwrap().crunchylist().await;
+    let crunchylist_preview = new_crunchylist_preview.unwrap();
+    let new_crunchylist = crunchylist_preview.crunchylist().await;
     assert_result!(new_crunchylist);
 
     let crunchylist = new_crunchylist.unwrap();
@@ -39,8 +40,68 @@ async fn modify_crunchylist() {
     let crunchylist_add_result = crunchylist.add(MediaCollection::from(series)).await;
     assert_result!(crunchylist_add_result);
 
+    let movie_listing: MovieListing = SESSION
+        .get()
+        .await
+        .unwrap()
+        .media_from_id("G6MG10746")
+        .await
+        .unwrap();
+    let crunchylist_add_result = crunchylist.add(MediaCollection::from(movie_listing)).await;
+    assert_result!(crunchylist_add_result);
+
+    let entries = crunchylist_preview.crunchylist().await.unwrap().items;
+    assert_result!(entries[1].move_before(&entries[0]).await);
+
+    let crunchylist_with_items = crunchylist_preview.crunchylist().await.unwrap();
+    let mut reversed = crunchylist_with_items.items.clone();
+    reversed.reverse();
+    assert_result!(crunchylist_with_items.reorder(&reversed).await);
+
     assert_result!(crunchylist.rename("test1").await);
 
     let crunchylist_delete_result = crunchylist.delete().await;
     assert_result!(crunchylist_delete_result);
 }
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn replay_reorder_skips_entries_in_position() {
+    let entry = |id: &str| {
+        format!(
+            r#"{{"id":"{id}","list_id":"list","panel":{{"id":"{id}","series_metadata":{{}}}}}}"#
+        )
+    };
+    let list = format!(
+        r#"{{"total":3,"data":[{},{},{}],"meta":{{"title":"list","is_public":false,"modified_at":"2024-01-01T00:00:00Z","max":100}}}}"#,
+        entry("a"),
+        entry("b"),
+        entry("c")
+    );
+    // only `c` is out of position, moving `b` would fail as its request isn't recorded
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&[
+        (
+            "GET https://www.crunchyroll.com/content/v2/account/custom-lists?locale=en-US",
+            r#"{"total":1,"data":[{"list_id":"list","title":"list","total":3}],"meta":{"total_private":1,"max_private":10,"total_public":0}}"#,
+        ),
+        (
+            "GET https://www.crunchyroll.com/content/v2/account/custom-lists/list?locale=en-US",
+            &list,
+        ),
+        (
+            "PUT https://www.crunchyroll.com/content/v2/account/custom-lists/list/c/position?locale=en-US",
+            "{}",
+        ),
+    ])
+    .await;
+
+    let crunchylist = crunchy.crunchylists().await.unwrap().items[0]
+        .crunchylist()
+        .await
+        .unwrap();
+    let items = &crunchylist.items;
+    let order = [items[0].clone(), items[2].clone(), items[1].clone()];
+    crunchylist.reorder(&order).await.unwrap();
+    // already in order, no request is made
+    crunchylist.reorder(items).await.unwrap();
+}
@@ -0,0 +1,27 @@
+use crate::utils::login_with_session;
+use crunchyroll_rs::{Crunchyroll, Series};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+mod utils;
+
+#[tokio::test]
+async fn deduplicated_request() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let requests_clone = requests.clone();
+    let builder = Crunchyroll::builder()
+        .deduplicate_requests(true)
+        .request_hook(move |summary| {
+            if summary.url.contains("GY8VEQ95Y") {
+                requests_clone.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+    let crunchy = login_with_session(builder).await;
+
+    let (first, second) = tokio::join!(
+        crunchy.media_from_id::<Series>("GY8VEQ95Y"),
+        crunchy.media_from_id::<Series>("GY8VEQ95Y")
+    );
+    assert_eq!(first.unwrap().id, second.unwrap().id);
+    assert_eq!(requests.load(Ordering::SeqCst), 1)
+}
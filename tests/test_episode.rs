@@ -1,6 +1,6 @@
 use crate::utils::Store;
 use crate::utils::SESSION;
-use crunchyroll_rs::Episode;
+use crunchyroll_rs::{Episode, MediaCollection};
 
 mod utils;
 
@@ -24,14 +24,94 @@ async fn episode_from_id() {
     assert_result!(START_EPISODE.get().await)
 }
 
+#[cfg(all(
+    feature = "streaming",
+    feature = "replay",
+    not(feature = "__test_strict")
+))]
 #[tokio::test]
 async fn episode_stream() {
-    let episode = START_EPISODE.get().await.unwrap();
+    // the stream token is redacted in recordings, so requests containing it are recorded with
+    // `REDACTED` too
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&[
+        (
+            "GET https://cr-play-service.prd.crunchyrollsvc.com/v1/GRDKJZ81Y/web/chrome/play",
+            r#"{"url":"https://example.com/manifest.mpd","token":"REDACTED","session":{"renewSeconds":30,"noNetworkRetryIntervalSeconds":30,"noNetworkTimeoutSeconds":30,"maximumPauseSeconds":30,"endOfVideoUnloadSeconds":30,"sessionExpirationSeconds":30,"usesStreamLimits":true}}"#,
+        ),
+        (
+            "DELETE https://cr-play-service.prd.crunchyrollsvc.com/v1/token/GRDKJZ81Y/REDACTED",
+            "",
+        ),
+    ])
+    .await;
+    let mut episode: Episode =
+        serde_json::from_value(serde_json::json!({"id": "GRDKJZ81Y"})).unwrap();
+    crunchy.attach(&mut episode).await;
 
     let stream = episode.stream().await.unwrap();
+    assert_eq!(stream.url, "https://example.com/manifest.mpd");
     stream.invalidate().await.unwrap()
 }
 
+#[cfg(feature = "streaming")]
+#[tokio::test]
+async fn episode_download_all_subtitles() {
+    let episode = START_EPISODE.get().await.unwrap();
+    let directory = std::env::temp_dir().join(format!(
+        "crunchyroll-rs-subtitles-{}",
+        rand::random::<u32>()
+    ));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    let subtitles = episode.download_all_subtitles(&directory).await.unwrap();
+    assert!(!subtitles.is_empty());
+    for path in subtitles.into_values() {
+        assert!(path.unwrap().is_file())
+    }
+
+    std::fs::remove_dir_all(directory).unwrap()
+}
+
+#[cfg(all(
+    feature = "streaming",
+    feature = "replay",
+    not(feature = "__test_strict")
+))]
+#[tokio::test]
+async fn episode_download_all_subtitles_replay() {
+    use crunchyroll_rs::error::Error;
+    use crunchyroll_rs::Locale;
+
+    // the stream invalidation isn't recorded and fails, which must not discard the subtitles
+    let (crunchy, fixture) = crate::utils::replay::replay_client(&[
+        (
+            "GET https://cr-play-service.prd.crunchyrollsvc.com/v1/GRDKJZ81Y/web/chrome/play",
+            r#"{"url":"https://example.com/manifest.mpd","token":"REDACTED","subtitles":{"en-US":{"language":"en-US","url":"https://example.com/en-US.ass","format":"ass"}},"session":{"renewSeconds":30,"noNetworkRetryIntervalSeconds":30,"noNetworkTimeoutSeconds":30,"maximumPauseSeconds":30,"endOfVideoUnloadSeconds":30,"sessionExpirationSeconds":30,"usesStreamLimits":true}}"#,
+        ),
+        ("GET https://example.com/en-US.ass", "subtitle"),
+    ])
+    .await;
+    let mut episode: Episode =
+        serde_json::from_value(serde_json::json!({"id": "GRDKJZ81Y"})).unwrap();
+    crunchy.attach(&mut episode).await;
+
+    let directory = fixture.directory.join("subtitles");
+    std::fs::create_dir_all(&directory).unwrap();
+    let mut subtitles = episode.download_all_subtitles(&directory).await.unwrap();
+    let path = subtitles.remove(&Locale::en_US).unwrap().unwrap();
+    assert_eq!(path, directory.join("GRDKJZ81Y.en-US.ass"));
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "subtitle");
+
+    let mut subtitles = episode
+        .download_all_subtitles(fixture.directory.join("missing"))
+        .await
+        .unwrap();
+    assert!(matches!(
+        subtitles.remove(&Locale::en_US).unwrap(),
+        Err(Error::Io { .. })
+    ))
+}
+
 #[tokio::test]
 async fn episode_get_playhead() {
     let episode = START_EPISODE.get().await.unwrap();
@@ -39,6 +119,13 @@ async fn episode_get_playhead() {
     assert_result!(episode.playhead().await)
 }
 
+#[tokio::test]
+async fn episode_resume_position() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    assert_result!(episode.resume_position().await)
+}
+
 #[tokio::test]
 async fn episode_set_playhead() {
     let episode = START_EPISODE.get().await.unwrap();
@@ -74,8 +161,140 @@ async fn episode_none_next() {
     assert_result!(episode.next().await)
 }
 
+#[tokio::test]
+async fn episode_next_episode() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    assert!(episode.next_episode().await.unwrap().is_some())
+}
+
+#[tokio::test]
+async fn episode_previous_episode() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    assert_result!(episode.previous_episode().await)
+}
+
 #[tokio::test]
 async fn episode_skip_events() {
     let episode = START_EPISODE.get().await.unwrap();
     episode.skip_events().await.unwrap();
 }
+
+#[tokio::test]
+async fn episode_mark_watched() {
+    let episode = START_EPISODE.get().await.unwrap();
+
+    assert_result!(episode.mark_watched().await)
+}
+
+#[tokio::test]
+async fn episode_mark_unwatched() {
+    let episode = END_EPISODE.get().await.unwrap();
+
+    assert_result!(episode.mark_unwatched().await)
+}
+
+#[tokio::test]
+async fn episode_bulk_playheads() {
+    let crunchy = SESSION.get().await.unwrap();
+    let start_episode = START_EPISODE.get().await.unwrap();
+    let end_episode = END_EPISODE.get().await.unwrap();
+
+    assert_result!(
+        crunchy
+            .playheads(&[&start_episode.id, &end_episode.id])
+            .await
+    )
+}
+
+#[tokio::test]
+async fn episode_free_available_at() {
+    let episode = START_EPISODE.get().await.unwrap();
+    if let Some(free_available_at) = episode.free_available_at() {
+        assert!(free_available_at >= episode.premium_available_at())
+    }
+}
+
+#[tokio::test]
+async fn episode_original_version() {
+    let episode = START_EPISODE.get().await.unwrap();
+    assert_result!(episode.original_version().unwrap().episode().await)
+}
+
+#[tokio::test]
+async fn episode_media_collection_stream() {
+    let media_collection = MediaCollection::from(START_EPISODE.get().await.unwrap().clone());
+    #[cfg(feature = "streaming")]
+    {
+        let stream = media_collection.stream().await.unwrap();
+        stream.invalidate().await.unwrap();
+    }
+    assert_result!(media_collection.full().await)
+}
+
+#[tokio::test]
+async fn episode_related_music() {
+    assert_result!(START_EPISODE.get().await.unwrap().related_music().await)
+}
+
+#[tokio::test]
+async fn episode_playback_availability() {
+    let episode = START_EPISODE.get().await.unwrap();
+    let availability = episode.playback_availability();
+    assert_eq!(availability.premium_only, episode.is_premium_only);
+    if availability.premium_only {
+        assert!(!availability.playable_without_premium())
+    }
+}
+
+#[test]
+fn episode_early_access() {
+    let episode: Episode = serde_json::from_value(serde_json::json!({
+        "availability_starts": "2000-01-01T00:00:00Z",
+        "premium_available_date": "2999-01-01T00:00:00Z"
+    }))
+    .unwrap();
+    assert!(episode.is_early_access());
+    assert_eq!(
+        episode.early_access_ends().unwrap().to_rfc3339(),
+        "2999-01-01T00:00:00+00:00"
+    );
+
+    let episode: Episode = serde_json::from_value(serde_json::json!({
+        "availability_starts": "2000-01-01T00:00:00Z",
+        "premium_available_date": "2000-01-08T00:00:00Z"
+    }))
+    .unwrap();
+    assert!(!episode.is_early_access());
+    assert!(episode.early_access_ends().is_none())
+}
+
+#[test]
+fn episode_version_audio_description() {
+    let version: crunchyroll_rs::media::EpisodeVersion = serde_json::from_value(
+        serde_json::json!({ "audio_locale": "en-US", "variant": "audio_description" }),
+    )
+    .unwrap();
+    assert!(version.is_audio_description());
+
+    let version: crunchyroll_rs::media::EpisodeVersion =
+        serde_json::from_value(serde_json::json!({ "audio_locale": "en-US", "variant": "" }))
+            .unwrap();
+    assert!(!version.is_audio_description())
+}
+
+#[test]
+fn episode_listing_dates() {
+    let episode: Episode = serde_json::from_value(serde_json::json!({
+        "available_date": "2024-01-02T03:04:05Z",
+        "premium_date": null
+    }))
+    .unwrap();
+    assert_eq!(episode.available_date.raw(), "2024-01-02T03:04:05Z");
+    assert_eq!(
+        episode.available_date.get().unwrap().to_rfc3339(),
+        "2024-01-02T03:04:05+00:00"
+    );
+    assert!(episode.premium_date.get().is_none())
+}
@@ -0,0 +1,86 @@
+use crate::utils::SESSION;
+use crunchyroll_rs::events::ClientEvent;
+use crunchyroll_rs::media::ImageVariants;
+use crunchyroll_rs::Series;
+use futures_util::StreamExt;
+
+mod utils;
+
+#[tokio::test]
+async fn events_download() {
+    let crunchy = SESSION.get().await.unwrap();
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    let poster = series.images.poster_tall.largest().unwrap();
+
+    let mut events = crunchy.events();
+    let data = poster.download(crunchy).await.unwrap();
+
+    assert_eq!(
+        events.next().await,
+        Some(ClientEvent::DownloadStarted {
+            url: poster.source.clone()
+        })
+    );
+    assert_eq!(
+        events.next().await,
+        Some(ClientEvent::DownloadFinished {
+            url: poster.source.clone(),
+            bytes: Some(data.len())
+        })
+    )
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn events_login() {
+    let fixture = crate::utils::replay::ReplayFixture::new(&[]).await;
+
+    let builder = crunchyroll_rs::Crunchyroll::builder();
+    let mut events = builder.events();
+    fixture.login(builder).await;
+
+    assert_eq!(
+        events.next().await,
+        Some(ClientEvent::LoggedIn {
+            account_id: Some("account".to_string())
+        })
+    )
+}
+
+#[cfg(all(
+    feature = "replay",
+    feature = "streaming",
+    not(feature = "__test_strict")
+))]
+#[tokio::test]
+async fn events_stream_session() {
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&[
+        (
+            "GET https://cr-play-service.prd.crunchyrollsvc.com/v1/GRDKJZ81Y/web/chrome/play",
+            r#"{"url":"https://example.com/manifest.mpd","token":"REDACTED","session":{"renewSeconds":30,"noNetworkRetryIntervalSeconds":30,"noNetworkTimeoutSeconds":30,"maximumPauseSeconds":30,"endOfVideoUnloadSeconds":30,"sessionExpirationSeconds":30,"usesStreamLimits":true}}"#,
+        ),
+        (
+            "DELETE https://cr-play-service.prd.crunchyrollsvc.com/v1/token/GRDKJZ81Y/REDACTED",
+            "",
+        ),
+    ])
+    .await;
+    let mut episode: crunchyroll_rs::Episode =
+        serde_json::from_value(serde_json::json!({"id": "GRDKJZ81Y"})).unwrap();
+    crunchy.attach(&mut episode).await;
+
+    let mut events = crunchy.events();
+    episode.stream().await.unwrap().invalidate().await.unwrap();
+
+    // the session token isn't part of the events
+    let Some(ClientEvent::StreamSessionCreated { id, .. }) = events.next().await else {
+        panic!("stream session wasn't created")
+    };
+    assert_eq!(id, "GRDKJZ81Y");
+    assert_eq!(
+        events.next().await,
+        Some(ClientEvent::StreamSessionExpired {
+            id: "GRDKJZ81Y".to_string()
+        })
+    )
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "external-ids")]
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use crunchyroll_rs::external::{
+    ExternalDatabase, ExternalId, ExternalIdMapper, ExternalIdQuery, ExternalIdResolver,
+    StaticExternalIdResolver, TitleExternalIdResolver, TitleMapping,
+};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn resolve_static_external_ids() {
+    let resolver = StaticExternalIdResolver {
+        mappings: HashMap::from([(
+            "GY8VEQ95Y".to_string(),
+            vec![
+                ExternalId {
+                    database: ExternalDatabase::MyAnimeList,
+                    id: "35849".to_string(),
+                },
+                ExternalId {
+                    database: ExternalDatabase::AniList,
+                    id: "99423".to_string(),
+                },
+            ],
+        )]),
+    };
+    let duplicate_resolver = StaticExternalIdResolver {
+        mappings: HashMap::from([(
+            "GY8VEQ95Y".to_string(),
+            vec![ExternalId {
+                database: ExternalDatabase::MyAnimeList,
+                id: "0".to_string(),
+            }],
+        )]),
+    };
+    let mapper = ExternalIdMapper::default()
+        .resolver(resolver)
+        .resolver(duplicate_resolver);
+
+    let query = ExternalIdQuery {
+        crunchyroll_id: "GY8VEQ95Y".to_string(),
+        ..Default::default()
+    };
+    let external_ids = mapper.resolve(&query).await.unwrap();
+    assert_eq!(external_ids.len(), 2);
+    assert_eq!(external_ids[0].id, "35849");
+
+    let unknown_query = ExternalIdQuery {
+        crunchyroll_id: "unknown".to_string(),
+        ..Default::default()
+    };
+    assert!(mapper.resolve(&unknown_query).await.unwrap().is_empty())
+}
+
+#[tokio::test]
+async fn resolve_title_external_ids() {
+    let id = |id: &str| {
+        vec![ExternalId {
+            database: ExternalDatabase::AniList,
+            id: id.to_string(),
+        }]
+    };
+    let resolver = TitleExternalIdResolver {
+        mappings: vec![
+            TitleMapping {
+                title: "Remake".to_string(),
+                first_air_date: NaiveDate::from_ymd_opt(2019, 1, 1),
+                ids: id("remake"),
+                ..Default::default()
+            },
+            TitleMapping {
+                title: "Remake".to_string(),
+                first_air_date: NaiveDate::from_ymd_opt(2004, 1, 1),
+                ids: id("original"),
+                ..Default::default()
+            },
+            TitleMapping {
+                title: "Other title".to_string(),
+                slug_title: Some("slug-title".to_string()),
+                ids: id("slug"),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let query = ExternalIdQuery {
+        title: "remake!".to_string(),
+        first_air_date: Some(Utc.with_ymd_and_hms(2004, 1, 1, 15, 0, 0).unwrap()),
+        ..Default::default()
+    };
+    assert_eq!(resolver.resolve(&query).await.unwrap(), id("original"));
+
+    let query = ExternalIdQuery {
+        title: "Something else".to_string(),
+        slug_title: "slug-title".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(resolver.resolve(&query).await.unwrap(), id("slug"));
+
+    let query = ExternalIdQuery {
+        title: "Unknown".to_string(),
+        ..Default::default()
+    };
+    assert!(resolver.resolve(&query).await.unwrap().is_empty())
+}
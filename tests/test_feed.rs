@@ -31,6 +31,19 @@ async fn news_feed() {
         .unwrap())
 }
 
+#[tokio::test]
+async fn recently_added() {
+    assert_result!(SESSION
+        .get()
+        .await
+        .unwrap()
+        .recently_added()
+        .episodes
+        .next()
+        .await
+        .unwrap())
+}
+
 #[tokio::test]
 async fn recommendations() {
     assert_result!(SESSION
@@ -42,3 +55,90 @@ async fn recommendations() {
         .await
         .unwrap())
 }
+
+#[tokio::test]
+async fn home_feed_series_feed() {
+    let mut home_feed = SESSION.get().await.unwrap().home_feed();
+    while let Some(item) = home_feed.next().await {
+        if let HomeFeed::SeriesFeed(series_feed) = item.unwrap() {
+            assert_result!(series_feed.series().await);
+            break;
+        }
+    }
+}
+
+#[tokio::test]
+async fn music_feed() {
+    assert_result!(SESSION
+        .get()
+        .await
+        .unwrap()
+        .music_feed()
+        .next()
+        .await
+        .unwrap())
+}
+
+#[tokio::test]
+async fn recommendation_feeds() {
+    assert_result!(SESSION
+        .get()
+        .await
+        .unwrap()
+        .recommendation_feeds()
+        .await
+        .map(|feeds| feeds.len()))
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn replay_catalog_delta() {
+    use crunchyroll_rs::feed::CatalogSnapshot;
+
+    let title = |id: &str| format!(r#"{{"id":"{id}","series_metadata":{{}}}}"#);
+    let titles = format!(
+        r#"{{"total":3,"data":[{},{},{}],"meta":{{}}}}"#,
+        title("new"),
+        title("old1"),
+        title("old2")
+    );
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&[
+        (
+            "GET https://www.crunchyroll.com/content/v2/discover/browse?type=episode&sort_by=newly_added&n=20&start=0&locale=en-US",
+            r#"{"total":2,"data":[
+                {"id":"new_episode","sequence_number":1,"premium_available_date":"2024-02-01T00:00:00Z"},
+                {"id":"old_episode","sequence_number":1,"premium_available_date":"2023-12-01T00:00:00Z"}
+            ],"meta":{}}"#,
+        ),
+        (
+            "GET https://www.crunchyroll.com/content/v2/discover/browse?sort_by=newly_added&n=20&start=0&locale=en-US",
+            &titles,
+        ),
+        (
+            "GET https://www.crunchyroll.com/content/v2/discover/browse?sort_by=newly_added&n=100&start=0&locale=en-US",
+            &titles,
+        ),
+    ])
+    .await;
+
+    let snapshot: CatalogSnapshot = serde_json::from_value(serde_json::json!({
+        "taken_at": "2024-01-01T00:00:00Z",
+        "title_ids": ["old1", "old2", "removed"]
+    }))
+    .unwrap();
+
+    // the incremental delta stops at the first known title and can't detect removed titles
+    let (delta, next) = crunchy.catalog_delta(&snapshot).await.unwrap();
+    assert_eq!(delta.new_episodes.len(), 1);
+    assert_eq!(delta.new_episodes[0].id, "new_episode");
+    assert_eq!(delta.new_titles.len(), 1);
+    assert_eq!(delta.new_titles[0].id(), "new");
+    assert!(delta.removed_title_ids.is_empty());
+    assert!(next.title_ids.contains("new") && next.title_ids.contains("removed"));
+
+    let (delta, next) = crunchy.catalog_delta_full(&snapshot).await.unwrap();
+    assert_eq!(delta.new_episodes.len(), 1);
+    assert_eq!(delta.new_titles.len(), 1);
+    assert_eq!(delta.removed_title_ids, ["removed"]);
+    assert!(!next.title_ids.contains("removed"));
+}
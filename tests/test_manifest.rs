@@ -0,0 +1,45 @@
+#![cfg(feature = "manifest")]
+
+use crunchyroll_rs::manifest::{DownloadManifest, ManifestEntry};
+
+#[test]
+fn manifest_entry() {
+    let entry = ManifestEntry::new("https://example.com/segment-1.m4s?Policy=abc", b"abc");
+    assert_eq!(entry.url, "https://example.com/segment-1.m4s");
+    assert_eq!(entry.size, 3);
+    assert_eq!(
+        entry.sha256,
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert!(entry.verify(b"abc"));
+    assert!(!entry.verify(b"abd"))
+}
+
+#[test]
+fn manifest_verify_files() {
+    let directory =
+        std::env::temp_dir().join(format!("crunchyroll-rs-manifest-{}", rand::random::<u32>()));
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(directory.join("valid.m4s"), b"valid").unwrap();
+    std::fs::write(directory.join("corrupt.m4s"), b"corrupt").unwrap();
+
+    let mut manifest = DownloadManifest::new();
+    manifest.push(ManifestEntry::new("https://example.com/valid.m4s", b"valid").path("valid.m4s"));
+    manifest.push(
+        ManifestEntry::new("https://example.com/corrupt.m4s", b"original").path("corrupt.m4s"),
+    );
+    manifest.push(
+        ManifestEntry::new("https://example.com/missing.m4s", b"missing").path("missing.m4s"),
+    );
+    manifest.push(ManifestEntry::new("https://example.com/no-path.m4s", b""));
+
+    let manifest = DownloadManifest::from_json(manifest.to_json()).unwrap();
+    let invalid: Vec<&str> = manifest
+        .verify_files(&directory)
+        .into_iter()
+        .map(|entry| entry.path.as_deref().unwrap())
+        .collect();
+    assert_eq!(invalid, vec!["corrupt.m4s", "missing.m4s"]);
+
+    std::fs::remove_dir_all(directory).unwrap()
+}
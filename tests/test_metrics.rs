@@ -0,0 +1,55 @@
+#![cfg(feature = "metrics")]
+
+use crate::utils::SESSION;
+use crunchyroll_rs::media::Media;
+use crunchyroll_rs::metrics::{LatencyHistogram, MetricsSnapshot};
+use crunchyroll_rs::Series;
+use std::time::Duration;
+
+mod utils;
+
+#[test]
+fn latency_histogram() {
+    let mut histogram = LatencyHistogram::default();
+    assert_eq!(histogram.mean(), None);
+    assert_eq!(histogram.quantile(0.5), None);
+
+    // 8 requests <= 50ms, 2 requests <= 1000ms
+    histogram.buckets[2] = 8;
+    histogram.buckets[6] = 2;
+    histogram.count = 10;
+    histogram.sum = Duration::from_millis(1000);
+    assert_eq!(histogram.mean(), Some(Duration::from_millis(100)));
+    assert_eq!(histogram.quantile(0.5), Some(Duration::from_millis(50)));
+    assert_eq!(histogram.quantile(0.95), Some(Duration::from_millis(1000)));
+
+    histogram.buckets[10] = 1;
+    histogram.count = 11;
+    assert_eq!(histogram.quantile(1.0), None)
+}
+
+#[test]
+fn error_rate() {
+    let snapshot = MetricsSnapshot {
+        requests: 4,
+        errors: 1,
+        ..Default::default()
+    };
+    assert_eq!(snapshot.error_rate(), 0.25);
+    assert_eq!(MetricsSnapshot::default().error_rate(), 0.0)
+}
+
+#[tokio::test]
+async fn metrics_after_request() {
+    let crunchy = SESSION.get().await.unwrap();
+    crunchy.reset_metrics();
+
+    let series = Series::from_id(crunchy, "GY8VEQ95Y").await;
+    assert_result!(series);
+
+    let metrics = crunchy.metrics();
+    assert_eq!(metrics.requests, 1);
+    assert!(metrics
+        .endpoints
+        .contains_key("GET www.crunchyroll.com/content/v2/cms/series/{id}"))
+}
@@ -17,8 +17,21 @@ async fn movie_from_id() {
     assert_result!(MOVIE.get().await)
 }
 
+#[cfg(all(
+    feature = "streaming",
+    feature = "replay",
+    not(feature = "__test_strict")
+))]
 #[tokio::test]
 async fn movie_stream() {
-    let stream = MOVIE.get().await.unwrap().stream().await.unwrap();
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&[(
+        "GET https://cr-play-service.prd.crunchyrollsvc.com/v1/G71F4DJ24/web/chrome/play",
+        r#"{"url":"https://example.com/manifest.mpd","token":"REDACTED","session":{"renewSeconds":30,"noNetworkRetryIntervalSeconds":30,"noNetworkTimeoutSeconds":30,"maximumPauseSeconds":30,"endOfVideoUnloadSeconds":30,"sessionExpirationSeconds":30,"usesStreamLimits":false}}"#,
+    )])
+    .await;
+    let mut movie: Movie = serde_json::from_value(serde_json::json!({"id": "G71F4DJ24"})).unwrap();
+    crunchy.attach(&mut movie).await;
+
+    let stream = movie.stream().await.unwrap();
     stream.invalidate().await.unwrap()
 }
@@ -1,6 +1,7 @@
 use crate::utils::Store;
 use crate::utils::SESSION;
 use crunchyroll_rs::MovieListing;
+use futures_util::{StreamExt, TryStreamExt};
 
 mod utils;
 
@@ -19,5 +20,42 @@ async fn movie_listing_from_id() {
 
 #[tokio::test]
 async fn movies() {
-    assert_result!(MOVIE_LISTING.get().await.unwrap().movies().await)
+    assert_result!(
+        MOVIE_LISTING
+            .get()
+            .await
+            .unwrap()
+            .movies()
+            .try_collect::<Vec<_>>()
+            .await
+    )
+}
+
+#[tokio::test]
+async fn movie_listing_extra_videos() {
+    assert_result!(MOVIE_LISTING.get().await.unwrap().extra_videos().await)
+}
+
+#[tokio::test]
+async fn movie_listing_similar() {
+    assert_result!(MOVIE_LISTING
+        .get()
+        .await
+        .unwrap()
+        .similar()
+        .next()
+        .await
+        .unwrap())
+}
+
+#[tokio::test]
+async fn movies_of_all_versions() {
+    assert_result!(
+        MOVIE_LISTING
+            .get()
+            .await
+            .unwrap()
+            .movies_of_all_versions()
+            .await
+    )
 }
@@ -16,6 +16,7 @@ async fn music_video_from_id() {
     assert_result!(MUSIC_VIDEO.get().await)
 }
 
+#[cfg(feature = "streaming")]
 #[tokio::test]
 async fn music_video_stream() {
     let stream = MUSIC_VIDEO.get().await.unwrap().stream().await.unwrap();
@@ -0,0 +1,90 @@
+use crate::utils::SESSION;
+use crunchyroll_rs::news::NewsOptions;
+use futures_util::StreamExt;
+
+mod utils;
+
+#[tokio::test]
+async fn news_articles() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert_result!(crunchy
+        .news_articles(NewsOptions::default())
+        .next()
+        .await
+        .unwrap())
+}
+
+#[tokio::test]
+async fn news_article_content() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let article = crunchy
+        .news_articles(NewsOptions::default())
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_result!(article.content().await)
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn news_replay() {
+    use crate::utils::replay::replay_client;
+
+    let story = r#"{
+        "name": "Fallback",
+        "full_slug": "news/announcements/2024/1/2/example",
+        "first_published_at": "2024-01-02T03:04:05.000Z",
+        "tag_list": ["announcements"],
+        "content": {
+            "headline": "Example Article",
+            "lead": "Summary",
+            "thumbnail": {"filename": "https://a.storyblok.com/example.jpg"},
+            "article": {"type": "doc", "content": [
+                {"type": "paragraph", "content": [{"type": "text", "text": "First"}]},
+                {"type": "paragraph", "content": [{"type": "text", "text": "Second"}]}
+            ]}
+        }
+    }"#;
+    let search = format!(r#"{{"stories":[{story}],"total":1}}"#);
+    let single = format!(r#"{{"story":{story}}}"#);
+    let (crunchy, _fixture) = replay_client(&[
+        (
+            "GET https://cr-news-api-service.prd.crunchyrollsvc.com/v1/en-US/stories/search?page=1&page_size=20&category=announcements",
+            &search,
+        ),
+        (
+            "GET https://cr-news-api-service.prd.crunchyrollsvc.com/v1/en-US/stories?slug=news%2Fannouncements%2F2024%2F1%2F2%2Fexample",
+            &single,
+        ),
+    ])
+    .await;
+
+    let articles = crunchy
+        .news_articles(NewsOptions::default().category("announcements".to_string()))
+        .collect_n(5)
+        .await
+        .unwrap();
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+    assert_eq!(article.title, "Example Article");
+    assert_eq!(article.summary, "Summary");
+    assert_eq!(
+        article.hero_image.as_deref(),
+        Some("https://a.storyblok.com/example.jpg")
+    );
+    assert_eq!(article.categories, ["announcements"]);
+    assert_eq!(
+        article.publish_date.unwrap().to_rfc3339(),
+        "2024-01-02T03:04:05+00:00"
+    );
+    assert_eq!(
+        article.url(),
+        "https://www.crunchyroll.com/news/announcements/2024/1/2/example"
+    );
+
+    let content = article.content().await.unwrap();
+    assert_eq!(content.text(), "First\nSecond")
+}
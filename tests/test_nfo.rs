@@ -0,0 +1,44 @@
+#![cfg(feature = "nfo")]
+
+use crunchyroll_rs::media::Rating;
+use crunchyroll_rs::nfo::Nfo;
+use crunchyroll_rs::{Episode, Series};
+
+#[test]
+fn series_nfo() {
+    let mut series = Series::default();
+    series.id = "GY8VEQ95Y".to_string();
+    series.title = "Darling in the FranXX".to_string();
+    series.description = "Pistils & Stamens".to_string();
+    series.series_launch_year = Some(2018);
+
+    let nfo = series.to_nfo();
+    assert!(nfo.contains("<tvshow><title>Darling in the FranXX</title>"));
+    assert!(nfo.contains("<plot>Pistils &amp; Stamens</plot>"));
+    assert!(nfo.contains("<year>2018</year>"));
+    assert!(nfo.contains(r#"<uniqueid type="crunchyroll" default="true">GY8VEQ95Y</uniqueid>"#));
+    assert!(nfo.ends_with("</tvshow>"));
+
+    let rating = Rating {
+        average: 4.8,
+        total: 1000,
+        ..Default::default()
+    };
+    assert!(series
+        .to_nfo_with_rating(&rating)
+        .contains("<value>4.8</value><votes>1000</votes>"))
+}
+
+#[test]
+fn episode_nfo() {
+    let mut episode = Episode::default();
+    episode.title = "Alone and Lonesome".to_string();
+    episode.episode_number = Some(1);
+    episode.season_number = 1;
+
+    let nfo = episode.to_nfo();
+    assert!(nfo.contains("<episodedetails><title>Alone and Lonesome</title>"));
+    assert!(nfo.contains("<season>1</season><episode>1</episode>"));
+    // unset dates are not exported
+    assert!(!nfo.contains("<aired>"))
+}
@@ -0,0 +1,72 @@
+#![cfg(all(
+    feature = "object-cache",
+    feature = "replay",
+    not(feature = "__test_strict")
+))]
+
+use crate::utils::replay::ReplayFixture;
+use crunchyroll_rs::object_cache::ObjectCache;
+use crunchyroll_rs::{Crunchyroll, Locale, Series};
+
+mod utils;
+
+#[tokio::test]
+async fn object_cache_series() {
+    let directory = std::env::temp_dir().join(format!(
+        "crunchyroll-rs-object-cache-{}",
+        rand::random::<u32>()
+    ));
+    let object_cache = ObjectCache::new(&directory);
+    let builder = || {
+        Crunchyroll::builder()
+            .locale(Locale::en_US)
+            .object_cache(object_cache.clone())
+    };
+
+    let recording = ReplayFixture::new(&[(
+        "GET https://www.crunchyroll.com/content/v2/cms/series/GY8VEQ95Y?locale=en-US",
+        r#"{"total":1,"data":[{"id":"GY8VEQ95Y","title":"Recorded"}],"meta":{}}"#,
+    )])
+    .await;
+    let crunchy = recording.login(builder()).await;
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    assert_eq!(series.title, "Recorded");
+
+    // the empty recording has no response for the series, so it must be taken from the cache
+    let empty = ReplayFixture::new(&[]).await;
+    let crunchy = empty.login(builder()).await;
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    assert_eq!(series.title, "Recorded");
+
+    object_cache.invalidate("GY8VEQ95Y").unwrap();
+    assert!(crunchy.media_from_id::<Series>("GY8VEQ95Y").await.is_err());
+
+    std::fs::remove_dir_all(directory).unwrap()
+}
+
+#[tokio::test]
+async fn object_cache_unwritable() {
+    // a file instead of a directory makes storing objects fail
+    let path = std::env::temp_dir().join(format!(
+        "crunchyroll-rs-object-cache-{}",
+        rand::random::<u32>()
+    ));
+    std::fs::write(&path, "").unwrap();
+
+    let recording = ReplayFixture::new(&[(
+        "GET https://www.crunchyroll.com/content/v2/cms/series/GY8VEQ95Y?locale=en-US",
+        r#"{"total":1,"data":[{"id":"GY8VEQ95Y","title":"Recorded"}],"meta":{}}"#,
+    )])
+    .await;
+    let crunchy = recording
+        .login(
+            Crunchyroll::builder()
+                .locale(Locale::en_US)
+                .object_cache(ObjectCache::new(&path)),
+        )
+        .await;
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    assert_eq!(series.title, "Recorded");
+
+    std::fs::remove_file(path).unwrap()
+}
@@ -0,0 +1,89 @@
+use crunchyroll_rs::common::{CancellationToken, StreamExt};
+use crunchyroll_rs::error::Error;
+use crunchyroll_rs::Series;
+
+mod utils;
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+async fn replay_series(
+    responses: &[(&str, &str)],
+) -> (Series, crate::utils::replay::ReplayFixture) {
+    let (crunchy, fixture) = crate::utils::replay::replay_client(responses).await;
+    let mut series: Series =
+        serde_json::from_value(serde_json::json!({"id": "GY8VEQ95Y"})).unwrap();
+    crunchy.attach(&mut series).await;
+    (series, fixture)
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+const SEASONS_REQUEST: &str =
+    "GET https://www.crunchyroll.com/content/v2/cms/series/GY8VEQ95Y/seasons?locale=en-US";
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+const SEASONS_RESPONSE: &str = r#"{"total":2,"data":[{"id":"first"},{"id":"second"}],"meta":{}}"#;
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn pagination_single_page() {
+    let (series, _fixture) = replay_series(&[(SEASONS_REQUEST, SEASONS_RESPONSE)]).await;
+    let mut pagination = series.seasons();
+
+    assert_eq!(pagination.total().await, None);
+    assert_eq!(pagination.collect_n(5).await.unwrap().len(), 2);
+    assert!(pagination.next().await.is_none())
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn pagination_single_page_prefetch() {
+    let (series, _fixture) = replay_series(&[(SEASONS_REQUEST, SEASONS_RESPONSE)]).await;
+    let mut pagination = series.seasons();
+    pagination.prefetch(3);
+
+    assert_eq!(pagination.collect_n(5).await.unwrap().len(), 2);
+    assert!(pagination.next().await.is_none())
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn pagination_take_pages_overflow() {
+    let (series, _fixture) = replay_series(&[(SEASONS_REQUEST, SEASONS_RESPONSE)]).await;
+    let mut pagination = series.seasons();
+
+    // the item count of this many pages doesn't fit into an u32
+    assert_eq!(pagination.take_pages(u32::MAX).await.unwrap().len(), 2)
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn pagination_single_page_error() {
+    let (series, _fixture) = replay_series(&[]).await;
+    let mut pagination = series.seasons();
+
+    assert!(pagination.next().await.unwrap().is_err())
+}
+
+#[tokio::test]
+async fn pagination_cancel_on() {
+    let token = CancellationToken::new();
+    let mut pagination = Series::default().seasons();
+    pagination.cancel_on(token.clone());
+
+    token.cancel();
+    assert!(matches!(
+        pagination.next().await.unwrap(),
+        Err(Error::Cancelled)
+    ));
+    assert!(pagination.next().await.is_none())
+}
+
+#[tokio::test]
+async fn pagination_zero_page_size() {
+    let mut pagination = Series::default().seasons();
+    pagination.page_size(0);
+
+    assert!(matches!(
+        pagination.next().await.unwrap(),
+        Err(Error::Input { .. })
+    ));
+    assert!(pagination.next().await.is_none())
+}
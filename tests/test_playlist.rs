@@ -0,0 +1,68 @@
+#![cfg(feature = "m3u")]
+
+use crunchyroll_rs::playlist::{M3uEntry, M3uPlaylist};
+
+mod utils;
+
+#[test]
+fn m3u_playlist() {
+    let mut playlist = M3uPlaylist::new();
+    playlist.entries.push(M3uEntry {
+        title: "Episode\n1".to_string(),
+        duration: Some(1420),
+        location: "S01E01.mkv\r\n".to_string(),
+        options: vec![],
+    });
+    playlist.entries.push(M3uEntry {
+        title: "Episode 2".to_string(),
+        duration: None,
+        location: "https://example.com/manifest.mpd".to_string(),
+        options: vec!["http-referrer=https://www.crunchyroll.com/".to_string()],
+    });
+
+    assert_eq!(
+        playlist.to_string(),
+        "#EXTM3U\n\
+        #EXTINF:1420,Episode 1\n\
+        S01E01.mkv\n\
+        #EXTINF:-1,Episode 2\n\
+        #EXTVLCOPT:http-referrer=https://www.crunchyroll.com/\n\
+        https://example.com/manifest.mpd\n"
+    )
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn m3u_playlist_stream_location() {
+    use crunchyroll_rs::playlist::M3uLocation;
+    use crunchyroll_rs::Season;
+
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&[
+        (
+            "GET https://www.crunchyroll.com/content/v2/cms/seasons/SEASON/episodes?locale=en-US",
+            r#"{"total":1,"data":[{"id":"EPISODE","title":"Episode\r\n1"}],"meta":{}}"#,
+        ),
+        (
+            "GET https://cr-play-service.prd.crunchyrollsvc.com/v1/EPISODE/web/chrome/play",
+            r#"{"url":"https://example.com/manifest.mpd","token":"REDACTED","session":{"renewSeconds":30,"noNetworkRetryIntervalSeconds":30,"noNetworkTimeoutSeconds":30,"maximumPauseSeconds":30,"endOfVideoUnloadSeconds":30,"sessionExpirationSeconds":30,"usesStreamLimits":true}}"#,
+        ),
+        // the stream session is invalidated after its url was read. Without this recording the
+        // playlist creation would fail
+        (
+            "DELETE https://cr-play-service.prd.crunchyrollsvc.com/v1/token/EPISODE/REDACTED",
+            "",
+        ),
+    ])
+    .await;
+    let mut season: Season = serde_json::from_value(serde_json::json!({"id": "SEASON"})).unwrap();
+    crunchy.attach(&mut season).await;
+
+    let playlist = M3uPlaylist::from_season(&season, M3uLocation::Stream { hardsub: None })
+        .await
+        .unwrap();
+    assert_eq!(playlist.entries.len(), 1);
+    assert!(playlist.entries[0]
+        .location
+        .starts_with("https://example.com/manifest.mpd?"));
+    assert!(!playlist.to_string().contains("Episode\r\n1"))
+}
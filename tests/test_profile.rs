@@ -70,3 +70,49 @@ async fn modify_profile() {
 
     assert_result!(profile.clone().delete().await)
 }
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn replay_pin_required() {
+    use crate::utils::replay::replay_client;
+
+    let (crunchy, _fixture) = replay_client(&[
+        (
+            "GET https://www.crunchyroll.com/locked?locale=en-US",
+            r#"{"code":"playback.pin_required","context":[]}"#,
+        ),
+        (
+            "POST https://www.crunchyroll.com/accounts/v1/me/parental_controls/pin/verify",
+            "{}",
+        ),
+    ])
+    .await;
+    let locked = || {
+        crunchy.custom_request::<serde_json::Value, _>(reqwest::Method::GET, "locked", &(), None)
+    };
+
+    assert!(locked().await.unwrap_err().is_pin_required());
+
+    // a cancelled prompt returns the original error and doesn't unlock the session
+    let result = crunchy.retry_with_pin(async { None }, locked).await;
+    assert!(result.unwrap_err().is_pin_required());
+    assert!(!crunchy.pin_unlocked().await);
+
+    // the pin is verified and the request retried. the recorded response is still locked, so the
+    // error is returned after one retry
+    let result = crunchy
+        .retry_with_pin(async { Some("1234".to_string()) }, locked)
+        .await;
+    assert!(result.unwrap_err().is_pin_required());
+    assert!(crunchy.pin_unlocked().await);
+
+    // the pin is requested again if crunchyroll still requires it
+    let prompted = std::sync::atomic::AtomicBool::new(false);
+    let prompt = async {
+        prompted.store(true, std::sync::atomic::Ordering::Relaxed);
+        Some("1234".to_string())
+    };
+    let result = crunchy.retry_with_pin(prompt, locked).await;
+    assert!(result.unwrap_err().is_pin_required());
+    assert!(prompted.load(std::sync::atomic::Ordering::Relaxed))
+}
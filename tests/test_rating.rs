@@ -1,5 +1,7 @@
 use crate::utils::{Store, SESSION};
-use crunchyroll_rs::Series;
+use crunchyroll_rs::media::RatingStar;
+use crunchyroll_rs::{Episode, Series};
+use futures_util::StreamExt;
 
 mod utils;
 
@@ -15,3 +17,30 @@ static SERIES: Store<Series> = Store::new(|| {
 async fn rating() {
     assert_result!(SERIES.get().await.unwrap().rating().await);
 }
+
+#[tokio::test]
+async fn rate_and_remove_rating() {
+    let series = SERIES.get().await.unwrap();
+    assert_result!(series.rate(RatingStar::FiveStars).await);
+    assert_result!(series.remove_rating().await);
+}
+
+#[tokio::test]
+async fn reviews() {
+    let series = SERIES.get().await.unwrap();
+    if let Some(review) = series.reviews(Default::default()).next().await {
+        assert_result!(review)
+    }
+}
+
+#[tokio::test]
+async fn own_review() {
+    assert_result!(SERIES.get().await.unwrap().own_review().await);
+}
+
+#[tokio::test]
+async fn episode_rating() {
+    let crunchy = SESSION.get().await.unwrap();
+    let episode: Episode = crunchy.media_from_id("GRDKJZ81Y").await.unwrap();
+    assert_result!(episode.rating().await);
+}
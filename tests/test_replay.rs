@@ -0,0 +1,443 @@
+#![cfg(all(feature = "replay", not(feature = "__test_strict")))]
+
+#[cfg(all(unix, feature = "streaming"))]
+use crate::utils::replay::HeldResponse;
+use crate::utils::replay::{replay_client, ReplayFixture};
+use crunchyroll_rs::common::{LazyDateTime, PaginationOrder, TryStreamExt};
+use crunchyroll_rs::error::Error;
+use crunchyroll_rs::media::{Concurrency, Panel};
+use crunchyroll_rs::{Crunchyroll, Locale, MediaCollection, Series};
+
+mod utils;
+
+const SERIES_REQUEST: &str =
+    "GET https://www.crunchyroll.com/content/v2/cms/series/GY8VEQ95Y?locale=en-US";
+const SERIES_RESPONSE: &str =
+    r#"{"total":1,"data":[{"id":"GY8VEQ95Y","title":"Recorded"}],"meta":{}}"#;
+
+#[tokio::test]
+async fn replay_recorded_responses() {
+    let fixture =
+        ReplayFixture::with_benefits(&["cr_premium"], &[(SERIES_REQUEST, SERIES_RESPONSE)]).await;
+    let crunchy = fixture
+        .login(Crunchyroll::builder().locale(Locale::en_US))
+        .await;
+    assert!(crunchy.premium().await);
+
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    assert_eq!(series.title, "Recorded");
+    #[cfg(feature = "raw-json")]
+    assert_eq!(series.raw()["title"], "Recorded");
+    assert!(crunchy.media_from_id::<Series>("G0000000").await.is_err());
+}
+
+#[tokio::test]
+async fn replay_strict_deserialization() {
+    let fixture = ReplayFixture::new(&[(
+        SERIES_REQUEST,
+        // `linked_resource_key` is known but unused, its content must not be reported
+        r#"{"total":1,"data":[{"id":"GY8VEQ95Y","linked_resource_key":{"nested":[1]},"new_field":true}],"meta":{}}"#,
+    )])
+    .await;
+
+    let crunchy = fixture
+        .login(Crunchyroll::builder().locale(Locale::en_US))
+        .await;
+    let _series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    #[cfg(feature = "extra-fields")]
+    assert_eq!(_series.extra["new_field"], true);
+
+    // fields which are captured by `extra-fields` aren't reported as unknown
+    #[cfg(not(feature = "extra-fields"))]
+    {
+        let strict_crunchy = fixture
+            .login(
+                Crunchyroll::builder()
+                    .locale(Locale::en_US)
+                    .strict_deserialization(true),
+            )
+            .await;
+        let err = strict_crunchy
+            .media_from_id::<Series>("GY8VEQ95Y")
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("data[0]: unknown fields: new_field ("),
+            "unexpected error: {err}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn replay_detach_media() {
+    let (crunchy, _fixture) = replay_client(&[(SERIES_REQUEST, SERIES_RESPONSE)]).await;
+    let weak = crunchy.downgrade();
+
+    let mut series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    drop(crunchy);
+    // the series still keeps the session alive
+    let crunchy = weak.upgrade().unwrap();
+
+    Crunchyroll::detach(&mut series).await;
+    drop(crunchy);
+    assert!(weak.upgrade().is_none());
+    assert_eq!(series.title, "Recorded");
+}
+
+#[tokio::test]
+async fn replay_detached_request() {
+    let (crunchy, _fixture) = replay_client(&[(SERIES_REQUEST, SERIES_RESPONSE)]).await;
+
+    let mut series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    Crunchyroll::detach(&mut series).await;
+
+    // the request must fail before it reaches any client, recorded or not
+    match series.featured_music().await {
+        Err(Error::Input { message }) => assert!(message.contains("detached"), "{message}"),
+        result => panic!("expected a detached input error, got {result:?}"),
+    }
+}
+
+#[tokio::test]
+async fn replay_response_transform() {
+    let fixture = ReplayFixture::new(&[(SERIES_REQUEST, SERIES_RESPONSE)]).await;
+    let crunchy = fixture
+        .login(
+            Crunchyroll::builder()
+                .locale(Locale::en_US)
+                .response_transform(|url, value| {
+                    if url.contains("/series/") {
+                        value["data"][0]["title"] = "Transformed".into()
+                    }
+                }),
+        )
+        .await;
+
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+    assert_eq!(series.title, "Transformed");
+}
+
+#[tokio::test]
+async fn replay_fetch_all() {
+    let (crunchy, _fixture) = replay_client(&[(SERIES_REQUEST, SERIES_RESPONSE)]).await;
+
+    let report = crunchy
+        .fetch_all::<Series>(["GY8VEQ95Y", "G0000000", "GY8VEQ95Y"], Concurrency(2))
+        .await;
+    assert!(!report.is_complete());
+    assert_eq!(report.fetched.len(), 2);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].index, 1);
+    assert_eq!(report.failed[0].id, "G0000000");
+    assert!(report.into_result().is_err());
+}
+
+#[tokio::test]
+async fn replay_custom_request() {
+    let (crunchy, _fixture) = replay_client(&[(
+        "GET https://www.crunchyroll.com/content/v2/unmodeled?n=5&locale=en-US",
+        r#"{"total":1,"data":[{"id":"custom"}]}"#,
+    )])
+    .await;
+
+    #[derive(serde::Deserialize)]
+    struct Unmodeled {
+        total: u32,
+        data: Vec<serde_json::Value>,
+    }
+    let result: Unmodeled = crunchy
+        .custom_request(
+            reqwest::Method::GET,
+            "/content/v2/unmodeled",
+            &[("n", "5")],
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.total, 1);
+    assert_eq!(result.data[0]["id"], "custom");
+
+    let err = crunchy
+        .custom_request::<Vec<u32>, _>(
+            reqwest::Method::GET,
+            "content/v2/unmodeled",
+            &[("n", "5")],
+            None,
+        )
+        .await
+        .unwrap_err();
+    let crunchyroll_rs::error::Error::Decode { url, .. } = err else {
+        panic!("unexpected error: {err}")
+    };
+    assert_eq!(url, "https://www.crunchyroll.com/content/v2/unmodeled");
+
+    let result = crunchy
+        .custom_request::<Unmodeled, _>(
+            reqwest::Method::GET,
+            "https://www.crunchyroll.com/content/v2/unmodeled",
+            &(),
+            None,
+        )
+        .await;
+    assert!(result.is_err());
+
+    // the session token must not be sent to other hosts
+    for url in [
+        "http://www.crunchyroll.com/content/v2/unmodeled",
+        "https://example.com/content/v2/unmodeled",
+        "https://crunchyroll.com.example.com/content/v2/unmodeled",
+    ] {
+        let result = crunchy
+            .custom_request::<Unmodeled, _>(reqwest::Method::GET, url, &(), None)
+            .await;
+        assert!(
+            matches!(result, Err(crunchyroll_rs::error::Error::Input { .. })),
+            "{url} wasn't rejected"
+        );
+    }
+}
+
+#[tokio::test]
+async fn replay_pagination_order() {
+    let (crunchy, _fixture) = replay_client(&[(
+        "GET https://www.crunchyroll.com/content/v2/account/watch-history?page=1&page_size=20&order=asc&locale=en-US",
+        r#"{"total":2,"data":[{"id":"oldest"},{"id":"newest"}],"meta":{}}"#,
+    )])
+    .await;
+
+    let mut history = crunchy.watch_history();
+    history.order(PaginationOrder::Ascending).unwrap();
+    let ids: Vec<String> = history
+        .collect_n(2)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.id)
+        .collect();
+    assert_eq!(ids, ["oldest", "newest"]);
+
+    // the order can't be changed after the first page was requested
+    assert!(history.order(PaginationOrder::Descending).is_err());
+    // the browse endpoint doesn't support ordering
+    assert!(crunchy
+        .browse(Default::default())
+        .order(PaginationOrder::Ascending)
+        .is_err());
+}
+
+#[tokio::test]
+async fn replay_lazy_datetimes() {
+    let (crunchy, _fixture) = replay_client(&[(
+        "GET https://www.crunchyroll.com/content/v2/dates?locale=en-US",
+        r#"{"valid":"2024-01-02T03:04:05Z","invalid":"yesterday"}"#,
+    )])
+    .await;
+
+    #[derive(serde::Deserialize)]
+    struct Dates {
+        valid: LazyDateTime,
+        invalid: LazyDateTime,
+    }
+
+    let dates: Dates = crunchy
+        .custom_request(reqwest::Method::GET, "/content/v2/dates", &(), None)
+        .await
+        .unwrap();
+    assert_eq!(dates.valid.raw(), "2024-01-02T03:04:05Z");
+    assert_eq!(
+        dates.valid.get().unwrap().to_rfc3339(),
+        "2024-01-02T03:04:05+00:00"
+    );
+    // malformed timestamps don't fail the request
+    assert_eq!(dates.invalid.raw(), "yesterday");
+    assert!(dates.invalid.get().is_none());
+}
+
+#[tokio::test]
+async fn replay_media_from_panel() {
+    let (crunchy, _fixture) = replay_client(&[(
+        "GET https://www.crunchyroll.com/content/v2/cms/series/GY8VEQ95Y/seasons?locale=en-US",
+        r#"{"total":1,"data":[{"id":"season","number_of_episodes":12}],"meta":{}}"#,
+    )])
+    .await;
+
+    let panel: Panel = serde_json::from_value(serde_json::json!({
+        "id": "GY8VEQ95Y",
+        "title": "Panel",
+        "series_metadata": {}
+    }))
+    .unwrap();
+    let MediaCollection::Series(series) = crunchy.media_from_panel(panel).await.unwrap() else {
+        panic!("panel is not a series")
+    };
+    assert_eq!(series.title, "Panel");
+    let seasons = series.seasons().try_collect::<Vec<_>>().await.unwrap();
+    assert_eq!(seasons[0].id, "season");
+}
+
+#[tokio::test]
+async fn replay_json_decoding() {
+    // escapes, unicode and numbers must be decoded the same by serde_json and simd-json
+    const BODY: &str = r#"{"text":"\"quoted\" \\ äあ 😀","ascii":"line\nbreak\ttab","int":-9007199254740993,"big":18446744073709551615,"float":0.1,"exp":1.5e-7,"nested":[{"empty":{}},[],null,true]}"#;
+    let (crunchy, _fixture) = replay_client(&[(
+        "GET https://www.crunchyroll.com/content/v2/decoding?locale=en-US",
+        BODY,
+    )])
+    .await;
+
+    let value: serde_json::Value = crunchy
+        .custom_request(reqwest::Method::GET, "/content/v2/decoding", &(), None)
+        .await
+        .unwrap();
+    assert_eq!(
+        value,
+        serde_json::from_str::<serde_json::Value>(BODY).unwrap()
+    );
+
+    // simd-json parses the body in place, so it's only part of the error with serde_json
+    let (crunchy, _fixture) = replay_client(&[(
+        "GET https://www.crunchyroll.com/content/v2/invalid?locale=en-US",
+        "{invalid",
+    )])
+    .await;
+    let err = crunchy
+        .custom_request::<serde_json::Value, _>(
+            reqwest::Method::GET,
+            "/content/v2/invalid",
+            &(),
+            None,
+        )
+        .await
+        .unwrap_err();
+    let crunchyroll_rs::error::Error::Decode { content, .. } = err else {
+        panic!("unexpected error: {err}")
+    };
+    if cfg!(feature = "simd-json") {
+        assert!(content.is_empty())
+    } else {
+        assert_eq!(content, b"{invalid")
+    }
+}
+
+#[tokio::test]
+async fn replay_localized_titles() {
+    let (crunchy, _fixture) = replay_client(&[
+        (SERIES_REQUEST, r#"{"total":1,"data":[{"id":"GY8VEQ95Y","title":"Recorded","slug_title":"recorded","description":"english"}],"meta":{}}"#),
+        (
+            "GET https://www.crunchyroll.com/content/v2/cms/series/GY8VEQ95Y?locale=de-DE",
+            r#"{"total":1,"data":[{"id":"GY8VEQ95Y","title":"Aufgezeichnet","slug_title":"aufgezeichnet","description":"deutsch"}],"meta":{}}"#,
+        ),
+    ])
+    .await;
+    let series: Series = crunchy.media_from_id("GY8VEQ95Y").await.unwrap();
+
+    let titles = series
+        .localized_titles(&[Locale::de_DE, Locale::en_US])
+        .await
+        .unwrap();
+    assert_eq!(titles[0].locale, Locale::de_DE);
+    assert_eq!(titles[0].title, "Aufgezeichnet");
+    assert_eq!(titles[0].slug_title, "aufgezeichnet");
+    assert_eq!(titles[1].description, "english");
+
+    let metadata = MediaCollection::from(series.clone())
+        .localized_metadata(vec![Locale::en_US, Locale::de_DE])
+        .await
+        .unwrap();
+    assert_eq!(metadata[&Locale::de_DE], titles[0]);
+    assert_eq!(metadata[&Locale::en_US], titles[1]);
+
+    let preferred = series
+        .preferred_title(&[Locale::de_DE, Locale::en_US])
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(preferred.title, "Aufgezeichnet");
+}
+
+#[cfg(all(unix, feature = "streaming"))]
+#[tokio::test]
+async fn replay_request_priority() {
+    let fixture = ReplayFixture::new(&[
+        ("GET https://example.com/holder.png", "image"),
+        ("GET https://example.com/background.png", "image"),
+        (
+            "GET https://cr-play-service.prd.crunchyrollsvc.com/v1/GRDKJZ81Y/web/chrome/play",
+            r#"{"url":"https://example.com/manifest.mpd","token":"REDACTED","session":{"renewSeconds":30,"noNetworkRetryIntervalSeconds":30,"noNetworkTimeoutSeconds":30,"maximumPauseSeconds":30,"endOfVideoUnloadSeconds":30,"sessionExpirationSeconds":30,"usesStreamLimits":true}}"#,
+        ),
+    ])
+    .await;
+    let held = HeldResponse::new(&fixture, "GET https://example.com/holder.png");
+
+    // without prioritization, the slot is handed out in request order
+    assert_eq!(
+        dispatch_order(&fixture, &held, false).await,
+        ["holder", "background 1", "background 2", "playback"]
+    );
+    // the stream request skips the queued downloads
+    assert_eq!(
+        dispatch_order(&fixture, &held, true).await,
+        ["holder", "playback", "background 1", "background 2"]
+    );
+}
+
+/// Requests three downloads and a stream concurrently with a limit of one request at a time and
+/// returns the order in which they were dispatched. The first download occupies the slot until
+/// all other requests are queued.
+#[cfg(all(unix, feature = "streaming"))]
+async fn dispatch_order(
+    fixture: &ReplayFixture,
+    held: &HeldResponse,
+    prioritize: bool,
+) -> Vec<&'static str> {
+    use crunchyroll_rs::common::Image;
+    use crunchyroll_rs::Episode;
+
+    let crunchy = fixture
+        .login(
+            Crunchyroll::builder()
+                .locale(Locale::en_US)
+                .max_concurrent_requests(1)
+                .prioritize_requests(prioritize),
+        )
+        .await;
+    let mut episode: Episode =
+        serde_json::from_value(serde_json::json!({"id": "GRDKJZ81Y"})).unwrap();
+    crunchy.attach(&mut episode).await;
+    let image = |source: &str| -> Image {
+        serde_json::from_value(serde_json::json!({ "source": source })).unwrap()
+    };
+    let (holder, background) = (
+        image("https://example.com/holder.png"),
+        image("https://example.com/background.png"),
+    );
+
+    // requests run one at a time and nothing is awaited after a request finished, so the order
+    // in which they finish is the order in which they were dispatched
+    let finished = std::sync::Mutex::new(vec![]);
+    let download = |image: &Image, name: &'static str| {
+        let (crunchy, image, finished) = (&crunchy, image.clone(), &finished);
+        async move {
+            image.download(crunchy).await.unwrap();
+            finished.lock().unwrap().push(name)
+        }
+    };
+    tokio::join!(
+        download(&holder, "holder"),
+        download(&background, "background 1"),
+        download(&background, "background 2"),
+        async {
+            episode.stream().await.unwrap();
+            finished.lock().unwrap().push("playback")
+        },
+        async {
+            // give the other requests time to queue up
+            for _ in 0..10 {
+                tokio::task::yield_now().await
+            }
+            held.release().await
+        }
+    );
+    finished.into_inner().unwrap()
+}
@@ -0,0 +1,15 @@
+use crate::utils::login_with_session;
+use crunchyroll_rs::{Crunchyroll, Series};
+use std::time::Duration;
+
+mod utils;
+
+#[tokio::test]
+async fn retry_server_errors() {
+    let builder = Crunchyroll::builder()
+        .retry_rate_limited(3, Duration::from_secs(10))
+        .retry_server_errors(3, 0.1);
+    let crunchy = login_with_session(builder).await;
+
+    assert_result!(crunchy.media_from_id::<Series>("GY8VEQ95Y").await)
+}
@@ -0,0 +1,19 @@
+use crate::utils::login_with_session;
+use crunchyroll_rs::{Crunchyroll, Season, Series};
+
+mod utils;
+
+#[tokio::test]
+async fn prioritized_requests() {
+    let builder = Crunchyroll::builder()
+        .max_concurrent_requests(1)
+        .prioritize_requests(true);
+    let crunchy = login_with_session(builder).await;
+
+    let (series, season) = tokio::join!(
+        crunchy.media_from_id::<Series>("GY8VEQ95Y"),
+        crunchy.media_from_id::<Season>("GRZX8KNGY")
+    );
+    assert_result!(series);
+    assert_result!(season)
+}
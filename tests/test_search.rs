@@ -1,4 +1,9 @@
 use crate::utils::SESSION;
+use crunchyroll_rs::crunchyroll::MaturityRating;
+use crunchyroll_rs::media::MediaType;
+use crunchyroll_rs::search::{
+    BrowseAvailability, BrowseOptions, BrowseSortType, QueryOptions, QueryType, RatingFilter,
+};
 use crunchyroll_rs::Locale;
 use futures_util::StreamExt;
 
@@ -11,6 +16,167 @@ async fn by_browse() {
     assert_result!(crunchy.browse(Default::default()).next().await.unwrap());
 }
 
+#[tokio::test]
+async fn by_browse_start_at() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut pagination = crunchy.browse(Default::default());
+    pagination.skip_to(pagination.get_page_size() * 2);
+    let result = pagination.next().await.unwrap();
+    assert_result!(result);
+    assert_eq!(pagination.offset(), pagination.get_page_size() * 2 + 1)
+}
+
+#[tokio::test]
+async fn by_browse_skip_to() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut pagination = crunchy.browse(Default::default());
+    let offset = pagination.get_page_size() * 2 + 3;
+    pagination.skip_to(offset);
+    let result = pagination.next().await.unwrap();
+    assert_result!(result);
+    assert_eq!(pagination.offset(), offset + 1);
+    assert!(pagination.total().await.unwrap() > offset)
+}
+
+#[tokio::test]
+async fn by_browse_collect_n() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut pagination = crunchy.browse(Default::default());
+    pagination.page_size(10);
+    let result = pagination.collect_n(15).await;
+    assert_result!(result.as_ref());
+    assert_eq!(result.unwrap().len(), 15);
+
+    let result = pagination.take_pages(1).await;
+    assert_result!(result.as_ref());
+    assert_eq!(result.unwrap().len(), 10);
+    assert_eq!(pagination.offset(), 25)
+}
+
+#[tokio::test]
+async fn by_browse_prefetch() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut pagination = crunchy.browse(Default::default());
+    pagination.page_size(10);
+    pagination.prefetch(2);
+    let result = pagination.collect_n(35).await;
+    assert_result!(result.as_ref());
+    assert_eq!(result.unwrap().len(), 35);
+    assert_eq!(pagination.offset(), 35)
+}
+
+#[tokio::test]
+async fn by_browse_filtered() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let options = BrowseOptions::default()
+        .sort(BrowseSortType::Popularity)
+        .availability(BrowseAvailability::Free)
+        .min_rating(RatingFilter::FourStars)
+        .ratings(true);
+    let result = crunchy.browse(options).next().await.unwrap();
+    assert_result!(result);
+}
+
+#[tokio::test]
+async fn by_browse_not_mature() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let options = BrowseOptions::default()
+        .is_mature(false)
+        .maturity_rating(MaturityRating::NotMature);
+    let result = crunchy.browse(options).next().await.unwrap();
+    assert_result!(result)
+}
+
+#[test]
+fn browse_options_validation() {
+    assert!(BrowseOptions::default().validate().is_ok());
+    assert!(BrowseOptions::default()
+        .min_rating(RatingFilter::Custom("6".to_string()))
+        .validate()
+        .is_err());
+    assert!(BrowseOptions::default()
+        .min_rating(RatingFilter::Custom("3".to_string()))
+        .validate()
+        .is_ok());
+    assert!(BrowseOptions::default()
+        .categories(vec![])
+        .validate()
+        .is_err());
+    assert!(BrowseOptions::default()
+        .sort(BrowseSortType::Custom(String::new()))
+        .validate()
+        .is_err());
+    assert!(BrowseOptions::default()
+        .maturity_rating(MaturityRating::Custom(String::new()))
+        .validate()
+        .is_err());
+    assert!(BrowseOptions::default()
+        .media_types(vec![MediaType::Series, MediaType::Movie])
+        .validate()
+        .is_ok());
+    assert!(BrowseOptions::default()
+        .media_types(vec![])
+        .validate()
+        .is_err());
+    assert!(BrowseOptions::default()
+        .media_type(MediaType::Series)
+        .media_types(vec![MediaType::Movie])
+        .validate()
+        .is_err());
+    assert!(BrowseOptions::default()
+        .media_types(vec![MediaType::Series, MediaType::Series])
+        .validate()
+        .is_err());
+    assert!(BrowseOptions::default()
+        .is_mature(true)
+        .maturity_rating(MaturityRating::NotMature)
+        .validate()
+        .is_err());
+    assert!(BrowseOptions::default()
+        .is_mature(false)
+        .maturity_rating(MaturityRating::Mature)
+        .validate()
+        .is_ok())
+}
+
+#[test]
+fn query_options_validation() {
+    assert!(QueryOptions::default().validate().is_ok());
+    assert!(QueryOptions::default()
+        .is_mature(true)
+        .maturity_rating(MaturityRating::NotMature)
+        .validate()
+        .is_err());
+    assert!(QueryOptions::default()
+        .maturity_rating(MaturityRating::Custom(String::new()))
+        .validate()
+        .is_err())
+}
+
+#[tokio::test]
+async fn by_browse_index() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert_result!(crunchy.browse_index(Default::default()).await)
+}
+
+#[tokio::test]
+async fn by_browse_alphabetical() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut result = crunchy
+        .browse_alphabetical("a", Default::default())
+        .await
+        .unwrap();
+    assert_result!(result.next().await.unwrap())
+}
+
 #[tokio::test]
 async fn by_query() {
     let crunchy = SESSION.get().await.unwrap();
@@ -32,3 +198,90 @@ async fn simulcast_seasons() {
 
     assert_result!(crunchy.simulcast_seasons(Locale::en_US).await)
 }
+
+#[tokio::test]
+async fn by_browse_season_tag() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let seasonal_tags = crunchy.seasonal_tags().await.unwrap();
+    assert_result!(crunchy
+        .browse(BrowseOptions::default().season_tag(&seasonal_tags[0].id))
+        .next()
+        .await
+        .unwrap())
+}
+
+#[tokio::test]
+async fn simulcast_calendar() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let simulcast_seasons = crunchy.simulcast_seasons(Locale::en_US).await.unwrap();
+    assert_result!(crunchy.simulcast_calendar(&simulcast_seasons[0]).await)
+}
+
+#[tokio::test]
+async fn search_suggestions() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert_result!(crunchy.search_suggestions("darl").await)
+}
+
+#[tokio::test]
+async fn by_query_not_mature() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut result =
+        crunchy.query_with_options("darling", QueryOptions::default().is_mature(false));
+    assert_result!(result.series.next().await.unwrap())
+}
+
+#[tokio::test]
+async fn by_query_type() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert_result!(crunchy
+        .query_type("darling", QueryType::Series)
+        .next()
+        .await
+        .unwrap())
+}
+
+#[tokio::test]
+async fn by_query_incremental() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut results = crunchy.query_incremental("darling", 5, 10);
+    while let Some(batch) = results.next().await {
+        let batch = batch.unwrap();
+        assert!(!batch.items.is_empty());
+        assert!(batch.items.len() <= 5);
+        assert!(batch.offset < 10)
+    }
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn by_query_type_with_options() {
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&[(
+        "GET https://www.crunchyroll.com/content/v2/discover/search?q=darling&type=series&maturity_rating=M2&limit=20&start=0&locale=en-US",
+        r#"{"total":1,"data":[{"type":"series","count":1,"items":[{"id":"GY8VEQ95Y","type":"series","title":"Recorded","series_metadata":{}}]}],"meta":{}}"#,
+    )])
+    .await;
+
+    let mut result = crunchy.query_type_with_options(
+        "darling",
+        QueryType::Series,
+        QueryOptions::default().maturity_rating(MaturityRating::NotMature),
+    );
+    assert_eq!(result.next().await.unwrap().unwrap().id(), "GY8VEQ95Y");
+    assert!(result.next().await.is_none());
+
+    let mut result = crunchy.query_type_with_options(
+        "darling",
+        QueryType::Series,
+        QueryOptions::default()
+            .is_mature(true)
+            .maturity_rating(MaturityRating::NotMature),
+    );
+    assert!(result.next().await.unwrap().is_err())
+}
@@ -1,6 +1,7 @@
 use crate::utils::Store;
 use crate::utils::SESSION;
 use crunchyroll_rs::Season;
+use futures_util::TryStreamExt;
 
 mod utils;
 
@@ -18,5 +19,30 @@ async fn season_from_id() {
 
 #[tokio::test]
 async fn season_episodes() {
-    assert_result!(SEASON.get().await.unwrap().episodes().await)
+    assert_result!(
+        SEASON
+            .get()
+            .await
+            .unwrap()
+            .episodes()
+            .try_collect::<Vec<_>>()
+            .await
+    )
+}
+
+#[tokio::test]
+async fn season_episode() {
+    let episode = SEASON.get().await.unwrap().episode(1).await;
+    assert_result!(episode.as_ref());
+    assert_eq!(episode.unwrap().unwrap().episode_number, Some(1))
+}
+
+#[tokio::test]
+async fn season_extras() {
+    assert_result!(SEASON.get().await.unwrap().extras().await)
+}
+
+#[tokio::test]
+async fn season_mark_watched() {
+    assert_result!(SEASON.get().await.unwrap().mark_watched().await)
 }
@@ -0,0 +1,67 @@
+use crunchyroll_rs::account::Account;
+use crunchyroll_rs::categories::CategoryInformation;
+use crunchyroll_rs::common::{Image, Pagination};
+use crunchyroll_rs::devices::Device;
+use crunchyroll_rs::feed::{HomeFeed, SeriesFeed, SimilarFeed};
+use crunchyroll_rs::list::{
+    Crunchylist, CrunchylistEntry, CrunchylistPreview, Crunchylists, WatchHistoryEntry,
+    WatchlistEntry,
+};
+use crunchyroll_rs::media::{Artist, ArtistPreview, MusicFeed, Review};
+use crunchyroll_rs::profile::{Profile, Profiles};
+use crunchyroll_rs::search::SearchSuggestion;
+use crunchyroll_rs::{
+    Concert, Crunchyroll, Episode, MediaCollection, Movie, MovieListing, MusicVideo, Season, Series,
+};
+
+fn assert_handle<T: Clone + Send + Sync + 'static>() {}
+
+#[test]
+fn handles_are_send_sync() {
+    assert_handle::<Crunchyroll>();
+    assert_handle::<crunchyroll_rs::crunchyroll::WeakCrunchyroll>();
+
+    assert_handle::<Series>();
+    assert_handle::<Season>();
+    assert_handle::<Episode>();
+    assert_handle::<MovieListing>();
+    assert_handle::<Movie>();
+    assert_handle::<MusicVideo>();
+    assert_handle::<Concert>();
+    assert_handle::<Artist>();
+    assert_handle::<ArtistPreview>();
+    assert_handle::<MediaCollection>();
+    assert_handle::<Review>();
+    #[cfg(feature = "streaming")]
+    {
+        use crunchyroll_rs::media::{Stream, StreamData, StreamSegment, Subtitle};
+        assert_handle::<Stream>();
+        assert_handle::<StreamData>();
+        assert_handle::<StreamSegment>();
+        assert_handle::<Subtitle>();
+    }
+    assert_handle::<Image>();
+
+    assert_handle::<Account>();
+    assert_handle::<CategoryInformation>();
+    assert_handle::<Device>();
+    assert_handle::<Profile>();
+    assert_handle::<Profiles>();
+    assert_handle::<SearchSuggestion>();
+    assert_handle::<HomeFeed>();
+    assert_handle::<SeriesFeed>();
+    assert_handle::<SimilarFeed>();
+    assert_handle::<MusicFeed>();
+    assert_handle::<Crunchylists>();
+    assert_handle::<Crunchylist>();
+    assert_handle::<CrunchylistEntry>();
+    assert_handle::<CrunchylistPreview>();
+    assert_handle::<WatchlistEntry>();
+    assert_handle::<WatchHistoryEntry>();
+}
+
+#[test]
+fn pagination_is_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Pagination<Series>>();
+}
@@ -1,7 +1,9 @@
 use crate::utils::Store;
 use crate::utils::SESSION;
-use crunchyroll_rs::Series;
-use futures_util::StreamExt;
+use crunchyroll_rs::common::Image;
+use crunchyroll_rs::media::{ExtendedMaturityRatings, ImageVariants, MinSize};
+use crunchyroll_rs::{Locale, MediaCollection, Series};
+use futures_util::{StreamExt, TryStreamExt};
 
 mod utils;
 
@@ -20,7 +22,52 @@ async fn series_from_id() {
 
 #[tokio::test]
 async fn series_seasons() {
-    assert_result!(SERIES.get().await.unwrap().seasons().await)
+    assert_result!(
+        SERIES
+            .get()
+            .await
+            .unwrap()
+            .seasons()
+            .try_collect::<Vec<_>>()
+            .await
+    )
+}
+
+#[tokio::test]
+async fn series_aggregate() {
+    assert_result!(SERIES.get().await.unwrap().aggregate().await)
+}
+
+#[tokio::test]
+async fn series_aggregate_with_episodes() {
+    assert_result!(SERIES.get().await.unwrap().aggregate_with_episodes().await)
+}
+
+#[tokio::test]
+async fn series_season_groups() {
+    assert_result!(SERIES.get().await.unwrap().season_groups().await)
+}
+
+#[tokio::test]
+async fn series_seasons_with_audio() {
+    assert_result!(
+        SERIES
+            .get()
+            .await
+            .unwrap()
+            .seasons_with_audio(Locale::ja_JP)
+            .await
+    )
+}
+
+#[tokio::test]
+async fn series_upcoming() {
+    assert_result!(SERIES.get().await.unwrap().upcoming().await)
+}
+
+#[tokio::test]
+async fn series_extra_videos() {
+    assert_result!(SERIES.get().await.unwrap().extra_videos().await)
 }
 
 #[tokio::test]
@@ -28,7 +75,181 @@ async fn series_featured_music() {
     assert_result!(SERIES.get().await.unwrap().featured_music().await)
 }
 
+#[tokio::test]
+async fn series_related_music() {
+    assert_result!(SERIES.get().await.unwrap().related_music().await)
+}
+
 #[tokio::test]
 async fn series_similar() {
     assert_result!(SERIES.get().await.unwrap().similar().next().await.unwrap())
 }
+
+#[tokio::test]
+async fn series_poster_download() {
+    let series = SERIES.get().await.unwrap();
+    let poster = series.images.poster_tall.largest().unwrap();
+    assert_result!(poster.download(SESSION.get().await.unwrap()).await)
+}
+
+#[tokio::test]
+async fn series_poster_download_min_size() {
+    let series = SERIES.get().await.unwrap();
+    let result = series
+        .images
+        .poster_tall
+        .download(SESSION.get().await.unwrap(), MinSize::width(480))
+        .await;
+    assert_result!(result)
+}
+
+#[test]
+fn image_at_least() {
+    let images: Vec<Image> = [(60, 90), (480, 720), (240, 360), (1200, 1800)]
+        .into_iter()
+        .map(|(width, height)| Image {
+            width,
+            height,
+            ..Default::default()
+        })
+        .collect();
+
+    let best = images.at_least(MinSize::new(200, 400)).unwrap();
+    assert_eq!((best.width, best.height), (480, 720));
+    let best = images.at_least(MinSize::height(90)).unwrap();
+    assert_eq!((best.width, best.height), (60, 90));
+    let best = images.at_least(300).unwrap();
+    assert_eq!((best.width, best.height), (480, 720));
+    let best = images.at_least(MinSize::width(4000)).unwrap();
+    assert_eq!((best.width, best.height), (1200, 1800))
+}
+
+#[tokio::test]
+async fn series_episode() {
+    let episode = SERIES.get().await.unwrap().episode(1, 2).await;
+    assert_result!(episode.as_ref());
+    let episode = episode.unwrap().unwrap();
+    assert_eq!(
+        (episode.season_number, episode.episode_number),
+        (1, Some(2))
+    )
+}
+
+#[tokio::test]
+async fn series_with_locale() {
+    let crunchy = SESSION.get().await.unwrap();
+    let result = crunchy
+        .media_with_locale::<Series>(&SERIES.get().await.unwrap().id, Locale::ja_JP)
+        .await;
+    assert_result!(result)
+}
+
+#[tokio::test]
+async fn series_localized_metadata() {
+    let series = SERIES.get().await.unwrap().clone();
+    let result = MediaCollection::from(series)
+        .localized_metadata(vec![Locale::en_US, Locale::de_DE])
+        .await;
+    assert_result!(result)
+}
+
+#[tokio::test]
+async fn series_awards() {
+    let series = SERIES.get().await.unwrap();
+    for award in &series.awards {
+        assert!(!award.text.is_empty())
+    }
+}
+
+#[tokio::test]
+async fn series_preferred_title() {
+    assert_result!(
+        SERIES
+            .get()
+            .await
+            .unwrap()
+            .preferred_title(&[Locale::ja_JP, Locale::en_US])
+            .await
+    )
+}
+
+#[tokio::test]
+async fn series_search_episodes() {
+    assert_result!(SERIES.get().await.unwrap().search_episodes("darling").await)
+}
+
+#[tokio::test]
+async fn series_trailers() {
+    assert_result!(SERIES.get().await.unwrap().trailers().await)
+}
+
+#[tokio::test]
+async fn series_main_seasons() {
+    assert_result!(SERIES.get().await.unwrap().main_seasons().await)
+}
+
+#[tokio::test]
+async fn series_has_audio() {
+    assert_result!(SERIES.get().await.unwrap().has_audio(&Locale::ja_JP).await)
+}
+
+#[test]
+fn extended_maturity_ratings() {
+    let current: ExtendedMaturityRatings =
+        serde_json::from_str(r#"{"system":"TV","rating":"14","level":"M2"}"#).unwrap();
+    assert!(current.regions.is_empty());
+    assert_eq!(current.get("US").unwrap().rating, "14");
+
+    let regions: ExtendedMaturityRatings = serde_json::from_str(
+        r#"{"US":{"system":"TV","rating":"14","level":"M2"},"BR":{"system":"DJCTQ","rating":"16","level":"M3"}}"#,
+    )
+    .unwrap();
+    assert!(regions.current.is_none());
+    assert_eq!(regions.get("BR").unwrap().system, "DJCTQ");
+    assert!(regions.get("DE").is_none());
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn series_episode_across_cours() {
+    async fn episode(second_cour_numbers: &[u32], number: u32) -> Option<u32> {
+        let episodes = |season: &str, numbers: &[u32]| {
+            let episodes: Vec<_> = numbers
+                .iter()
+                .map(|n| serde_json::json!({"id": format!("{season}E{n}"), "episode_number": n}))
+                .collect();
+            serde_json::json!({"total": episodes.len(), "data": episodes, "meta": {}}).to_string()
+        };
+        let (crunchy, _fixture) = crate::utils::replay::replay_client(&[
+            (
+                "GET https://www.crunchyroll.com/content/v2/cms/series/GY8VEQ95Y/seasons?locale=en-US",
+                r#"{"total":2,"data":[{"id":"S1","season_number":1,"season_sequence_number":1,"identifier":"GY8VEQ95Y|S1"},{"id":"S2","season_number":1,"season_sequence_number":2,"identifier":"GY8VEQ95Y|S1C2"}],"meta":{}}"#,
+            ),
+            (
+                "GET https://www.crunchyroll.com/content/v2/cms/seasons/S1/episodes?locale=en-US",
+                &episodes("S1", &[1, 2]),
+            ),
+            (
+                "GET https://www.crunchyroll.com/content/v2/cms/seasons/S2/episodes?locale=en-US",
+                &episodes("S2", second_cour_numbers),
+            ),
+        ])
+        .await;
+        let mut series: Series =
+            serde_json::from_value(serde_json::json!({"id": "GY8VEQ95Y"})).unwrap();
+        crunchy.attach(&mut series).await;
+
+        series
+            .episode(1, number)
+            .await
+            .unwrap()
+            .map(|e| e.episode_number.unwrap())
+    }
+
+    // the numbering restarts in the second cour, so the third episode is its first one
+    assert_eq!(episode(&[1, 2], 3).await, Some(1));
+    assert_eq!(episode(&[1, 2], 5).await, None);
+    // the third episode is missing, the fourth one must not be returned instead
+    assert_eq!(episode(&[4, 5], 3).await, None);
+    assert_eq!(episode(&[4, 5], 4).await, Some(4));
+}
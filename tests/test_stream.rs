@@ -1,3 +1,5 @@
+#![cfg(feature = "streaming")]
+
 use crate::utils::Store;
 use crate::utils::SESSION;
 use crunchyroll_rs::media::{Media, Stream, StreamData, StreamSegment};
@@ -57,7 +59,7 @@ async fn process_segments() {
     // if the test passes, it's unlikely that some error will occur when streaming all segments (
     // and if it does, hopefully someone using this in production will report it)
     for _ in 0..10 {
-        sink.write(
+        sink.write_all(
             &segments
                 .choose(&mut rand::thread_rng())
                 .unwrap()
@@ -74,3 +76,62 @@ async fn process_segments() {
 async fn stream_versions_drm() {
     assert_result!(STREAM_DRM.get().await.unwrap().versions().await)
 }*/
+
+#[tokio::test]
+async fn process_segment_with_timeout() {
+    let segments = STREAM_SEGMENTS.get().await.unwrap();
+
+    let result = segments[0]
+        .data_with_timeout(std::time::Duration::from_millis(1))
+        .await;
+    assert!(result.unwrap_err().is_retryable());
+    assert_result!(
+        segments[0]
+            .data_with_timeout(std::time::Duration::from_secs(60))
+            .await
+    )
+}
+
+#[cfg(all(
+    unix,
+    feature = "heartbeat",
+    feature = "replay",
+    not(feature = "__test_strict")
+))]
+#[tokio::test]
+async fn stream_heartbeat() {
+    use crate::utils::replay::{replay_client, HeldResponse};
+
+    let (crunchy, fixture) = replay_client(&[
+        (
+            "GET https://cr-play-service.prd.crunchyrollsvc.com/v1/GRDKJZ81Y/web/chrome/play",
+            r#"{"url":"https://example.com/manifest.mpd","token":"REDACTED","session":{"renewSeconds":1,"noNetworkRetryIntervalSeconds":30,"noNetworkTimeoutSeconds":30,"maximumPauseSeconds":30,"endOfVideoUnloadSeconds":30,"sessionExpirationSeconds":30,"usesStreamLimits":true}}"#,
+        ),
+        (
+            "PATCH https://cr-play-service.prd.crunchyrollsvc.com/v1/token/GRDKJZ81Y/REDACTED/keepAlive?playhead=10",
+            "",
+        ),
+        (
+            "POST https://www.crunchyroll.com/content/v2/account/playheads?locale=en-US",
+            "{}",
+        ),
+    ])
+    .await;
+    let keep_alive = HeldResponse::new(
+        &fixture,
+        "PATCH https://cr-play-service.prd.crunchyrollsvc.com/v1/token/GRDKJZ81Y/REDACTED/keepAlive?playhead=10",
+    );
+    let mut episode: Episode =
+        serde_json::from_value(serde_json::json!({"id": "GRDKJZ81Y"})).unwrap();
+    crunchy.attach(&mut episode).await;
+
+    let heartbeat = episode.stream().await.unwrap().heartbeat();
+    heartbeat.set_position(10);
+    // returns once the heartbeat kept the session alive with the current position
+    keep_alive.release().await;
+
+    // the background task doesn't report while paused, so stopping has to report the position
+    heartbeat.set_paused(true);
+    heartbeat.set_position(20);
+    heartbeat.stop().await.unwrap()
+}
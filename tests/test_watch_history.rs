@@ -18,3 +18,12 @@ async fn clear_watch_history() {
     let crunchy = SESSION.get().await.unwrap();
     assert_result!(crunchy.clear_watch_history().await)
 }
+
+#[tokio::test]
+async fn remove_watch_history_entry() {
+    let crunchy = SESSION.get().await.unwrap();
+    if let Some(entry) = crunchy.watch_history().next().await {
+        let result = entry.unwrap().remove().await;
+        assert_result!(result)
+    }
+}
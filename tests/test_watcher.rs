@@ -0,0 +1,76 @@
+#![cfg(feature = "watcher")]
+
+use crate::utils::SESSION;
+use crunchyroll_rs::watcher::Watcher;
+
+mod utils;
+
+#[tokio::test]
+async fn watcher_check() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    let mut watcher = Watcher::new(crunchy).series("GY8VEQ95Y");
+
+    // the first check only records the current state
+    let result = watcher.check().await;
+    assert_result!(result.as_ref());
+    let result = result.unwrap();
+    assert!(result.events.is_empty());
+    assert!(result.errors.is_empty());
+
+    assert_result!(watcher.check().await)
+}
+
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+#[tokio::test]
+async fn watcher_check_series_error() {
+    let (crunchy, _fixture) = crate::utils::replay::replay_client(&[
+        (
+            "GET https://www.crunchyroll.com/content/v2/cms/series/SERIES/seasons?locale=en-US",
+            r#"{"total":1,"data":[{"id":"SEASON","number_of_episodes":1}],"meta":{}}"#,
+        ),
+        (
+            "GET https://www.crunchyroll.com/content/v2/cms/seasons/SEASON/episodes?locale=en-US",
+            r#"{"total":1,"data":[{"id":"EPISODE"}],"meta":{}}"#,
+        ),
+    ])
+    .await;
+    // responses of `MISSING` aren't recorded, so checking it fails
+    let mut watcher = Watcher::new(&crunchy).series("MISSING").series("SERIES");
+
+    let result = watcher.check().await.unwrap();
+    assert!(result.events.is_empty());
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].0, "MISSING");
+
+    let result = watcher.check().await.unwrap();
+    assert!(result.events.is_empty());
+    assert_eq!(result.errors.len(), 1)
+}
+
+#[cfg(feature = "rss")]
+#[test]
+fn episode_feed() {
+    use crunchyroll_rs::watcher::{EpisodeFeed, WatcherEvent};
+    use crunchyroll_rs::Episode;
+
+    let mut feed = EpisodeFeed::new("Test & feed").max_items(2);
+    for i in 0..3 {
+        let mut episode = Episode::default();
+        episode.id = format!("ID{i}");
+        episode.title = format!("<Episode {i}>");
+        feed.extend([WatcherEvent::NewEpisode(Box::new(episode))])
+    }
+
+    assert_eq!(
+        feed.episodes().map(|e| e.id.as_str()).collect::<Vec<_>>(),
+        vec!["ID2", "ID1"]
+    );
+    let rss = feed.to_rss();
+    assert!(rss.contains("<title>Test &amp; feed</title>"));
+    assert!(rss.contains("&lt;Episode 2&gt;"));
+    assert!(!rss.contains("ID0"));
+    let atom = feed.to_atom();
+    assert_eq!(atom.matches("<entry>").count(), 2);
+    assert!(atom.contains("<author><name>Crunchyroll</name></author>"))
+}
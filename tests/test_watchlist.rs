@@ -1,6 +1,7 @@
 use crate::utils::{Store, SESSION};
 use crunchyroll_rs::list::WatchlistOptions;
 use crunchyroll_rs::Series;
+use futures_util::StreamExt;
 
 mod utils;
 
@@ -15,7 +16,9 @@ static SERIES: Store<Series> = Store::new(|| {
 #[tokio::test]
 async fn watchlist() {
     let crunchy = SESSION.get().await.unwrap();
-    assert_result!(crunchy.watchlist(WatchlistOptions::default()).await)
+    if let Some(entry) = crunchy.watchlist(WatchlistOptions::default()).next().await {
+        assert_result!(entry)
+    }
 }
 
 #[tokio::test]
@@ -40,3 +43,18 @@ async fn into_watchlist_entry() {
     let series = SERIES.get().await.unwrap();
     assert_result!(series.into_watchlist_entry().await);
 }
+
+#[tokio::test]
+async fn watchlist_entries() {
+    let crunchy = SESSION.get().await.unwrap();
+    let series = SERIES.get().await.unwrap();
+    assert_result!(crunchy.watchlist_entries(&[&series.id]).await)
+}
+
+#[tokio::test]
+async fn watchlist_status() {
+    let crunchy = SESSION.get().await.unwrap();
+    let series = SERIES.get().await.unwrap();
+    let status = crunchy.watchlist_status(&[&series.id]).await.unwrap();
+    assert_eq!(status.len(), 1)
+}
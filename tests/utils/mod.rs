@@ -1,8 +1,10 @@
 #![allow(unused_imports)]
 
 pub mod macros;
+#[cfg(all(feature = "replay", not(feature = "__test_strict")))]
+pub mod replay;
 pub mod session;
 pub mod store;
 
-pub use session::SESSION;
+pub use session::{login_with_session, SESSION};
 pub use store::Store;
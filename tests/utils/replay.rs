@@ -0,0 +1,112 @@
+#![allow(dead_code)]
+
+use crunchyroll_rs::cache::{CacheStorage, CachedResponse, DiskCacheStorage};
+use crunchyroll_rs::crunchyroll::CrunchyrollBuilder;
+use crunchyroll_rs::{Crunchyroll, Locale};
+use std::path::PathBuf;
+
+/// A temporary recording directory with the given responses. The directory is removed when this
+/// struct is dropped.
+pub struct ReplayFixture {
+    pub directory: PathBuf,
+}
+
+impl ReplayFixture {
+    /// Creates a recording of a session without any account benefits which replays `responses`.
+    /// Each entry is a `(<method> <url>, <body>)` pair.
+    pub async fn new(responses: &[(&str, &str)]) -> Self {
+        Self::with_benefits(&[], responses).await
+    }
+
+    /// Like [`ReplayFixture::new`], but the session has the given account benefits.
+    pub async fn with_benefits(benefits: &[&str], responses: &[(&str, &str)]) -> Self {
+        let directory =
+            std::env::temp_dir().join(format!("crunchyroll-rs-replay-{}", rand::random::<u32>()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(
+            directory.join("session.json"),
+            serde_json::json!({"account_id": "account", "benefits": benefits, "bucket": ""})
+                .to_string(),
+        )
+        .unwrap();
+
+        let storage = DiskCacheStorage::new(&directory);
+        for (request, body) in responses {
+            storage
+                .set(
+                    request,
+                    CachedResponse {
+                        body: body.as_bytes().to_vec(),
+                        etag: None,
+                        last_modified: None,
+                        fresh_until: None,
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        Self { directory }
+    }
+
+    /// Logs in with the recording, using `builder` to configure the client.
+    pub async fn login(&self, builder: CrunchyrollBuilder) -> Crunchyroll {
+        builder.login_with_recording(&self.directory).await.unwrap()
+    }
+}
+
+impl Drop for ReplayFixture {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.directory);
+    }
+}
+
+/// Creates a [`ReplayFixture`] with `responses` and logs in with the `en-US` locale. The fixture
+/// must be kept alive as long as the client is used.
+pub async fn replay_client(responses: &[(&str, &str)]) -> (Crunchyroll, ReplayFixture) {
+    let fixture = ReplayFixture::new(responses).await;
+    let crunchy = fixture
+        .login(Crunchyroll::builder().locale(Locale::en_US))
+        .await;
+    (crunchy, fixture)
+}
+
+/// A recorded response which is replaced by a named pipe, so that replaying it blocks until
+/// [`HeldResponse::release`] is called.
+#[cfg(unix)]
+pub struct HeldResponse {
+    path: std::path::PathBuf,
+    content: Vec<u8>,
+}
+
+#[cfg(unix)]
+impl HeldResponse {
+    pub fn new(fixture: &ReplayFixture, request: &str) -> Self {
+        let (path, content) = std::fs::read_dir(&fixture.directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter_map(|path| Some((path.clone(), std::fs::read(path).ok()?)))
+            .find(|(_, content)| {
+                serde_json::from_slice::<serde_json::Value>(content)
+                    .is_ok_and(|entry| entry["key"] == request)
+            })
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(std::process::Command::new("mkfifo")
+            .arg(&path)
+            .status()
+            .unwrap()
+            .success());
+        Self { path, content }
+    }
+
+    /// Let the blocked replay of the response continue. Waits until the response is requested if
+    /// it wasn't yet.
+    pub async fn release(&self) {
+        let (path, content) = (self.path.clone(), self.content.clone());
+        tokio::task::spawn_blocking(move || std::fs::write(path, content))
+            .await
+            .unwrap()
+            .unwrap()
+    }
+}
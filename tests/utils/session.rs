@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 
 use crate::utils::store::{get_store, has_store, set_store, Store};
-use crunchyroll_rs::crunchyroll::SessionToken;
+use crunchyroll_rs::crunchyroll::{CrunchyrollBuilder, SessionToken};
 use crunchyroll_rs::Crunchyroll;
 
 pub static SESSION: Store<Crunchyroll> = Store::new(|| {
@@ -24,6 +24,18 @@ pub static SESSION: Store<Crunchyroll> = Store::new(|| {
     })
 });
 
+/// Logs in with the session of [`SESSION`], using `builder` to configure the client.
+pub async fn login_with_session(builder: CrunchyrollBuilder) -> Crunchyroll {
+    match SESSION.get().await.unwrap().session_token().await {
+        SessionToken::RefreshToken(refresh_token) => {
+            builder.login_with_refresh_token(refresh_token).await
+        }
+        SessionToken::EtpRt(etp_rt) => builder.login_with_etp_rt(etp_rt).await,
+        SessionToken::Anonymous => builder.login_anonymously().await,
+    }
+    .unwrap()
+}
+
 pub async fn set_session(crunchy: Crunchyroll) -> anyhow::Result<()> {
     match crunchy.session_token().await {
         SessionToken::RefreshToken(refresh_token) => Ok(set_store(